@@ -0,0 +1,94 @@
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+use sealy::ext::batched::{Batch, FromBatchedBytes, ToBatchedBytes};
+
+use crate::{context::PyContext, plaintext::PyPlaintext};
+
+/// A batch of plaintexts, mirroring `sealy::ext::batched::Batch<Plaintext>`.
+#[derive(Debug, Clone)]
+#[pyclass(module = "sealy", name = "Batch")]
+pub struct PyBatch {
+	pub(crate) inner: Batch<sealy::Plaintext>,
+}
+
+#[pymethods]
+impl PyBatch {
+	/// Constructs a batch from a list of plaintexts.
+	#[new]
+	fn new(elements: Vec<PyPlaintext>) -> Self {
+		Self {
+			inner: Batch(elements.into_iter().map(|p| p.inner).collect()),
+		}
+	}
+
+	/// Returns the number of elements in this batch.
+	fn len(&self) -> usize {
+		self.inner.len()
+	}
+
+	/// Returns true if this batch contains no elements.
+	fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+
+	/// Returns a copy of the plaintext at the given index, or `None` if out of range.
+	fn get(
+		&self,
+		index: usize,
+	) -> Option<PyPlaintext> {
+		self.inner.get_cloned(index).map(|inner| PyPlaintext {
+			inner,
+			context: None,
+		})
+	}
+
+	fn __len__(&self) -> usize {
+		self.len()
+	}
+
+	/// Gets the plaintext at the given index. Defining `__getitem__` (without `__iter__`)
+	/// is enough for this class to support Python's `for` loop via the classic
+	/// sequence-iteration protocol, which falls back to repeated `__getitem__` calls
+	/// starting at 0 until `IndexError` is raised.
+	fn __getitem__(
+		&self,
+		index: usize,
+	) -> PyResult<PyPlaintext> {
+		self.get(index).ok_or_else(|| {
+			PyIndexError::new_err(format!("batch index {} out of range", index))
+		})
+	}
+
+	/// Returns each plaintext in the batch serialized to bytes.
+	pub fn as_batched_bytes(&self) -> PyResult<Vec<Vec<u8>>> {
+		self.inner.as_batched_bytes().map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to get batch as batched bytes: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Constructs a batch from a list of per-element byte arrays produced by
+	/// `as_batched_bytes`, deserializing each under `context`.
+	#[staticmethod]
+	pub fn from_batched_bytes(
+		context: &PyContext,
+		batched: Vec<Vec<u8>>,
+	) -> PyResult<Self> {
+		let inner = Batch::from_batched_bytes(&context.inner, &batched).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to create batch from batched bytes: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+
+	fn __repr__(&self) -> String {
+		format!("{:?}", self.inner)
+	}
+}
@@ -0,0 +1,178 @@
+use pyo3::prelude::*;
+use sealy::{FromBytes, ToBytes, Versioned};
+
+use crate::context::PyContext;
+use crate::parameters::PyCompressionType;
+
+/// Class to store a ciphertext element. The data for a ciphertext consists of two or more
+/// polynomials, which are the product of encryption and any following operations performed
+/// on the ciphertext.
+#[derive(Debug, Clone)]
+#[pyclass(module = "sealy", name = "Ciphertext")]
+pub struct PyCiphertext {
+	pub(crate) inner: sealy::Ciphertext,
+}
+
+#[pymethods]
+impl PyCiphertext {
+	/// Constructs an empty ciphertext allocating no memory.
+	#[new]
+	pub fn new() -> PyResult<Self> {
+		let ciphertext = sealy::Ciphertext::new().map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to create ciphertext: {:?}",
+				e
+			))
+		})?;
+		Ok(Self {
+			inner: ciphertext,
+		})
+	}
+
+	/// Constructs a ciphertext from a byte array.
+	#[staticmethod]
+	pub fn from_bytes(
+		context: &PyContext,
+		data: Vec<u8>,
+	) -> PyResult<Self> {
+		let ciphertext = sealy::Ciphertext::from_bytes(&context.inner, &data).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to create ciphertext from bytes: {:?}",
+				e
+			))
+		})?;
+		Ok(Self {
+			inner: ciphertext,
+		})
+	}
+
+	/// Generates a bytearray representation of the ciphertext.
+	pub fn as_bytes(&self) -> PyResult<Vec<u8>> {
+		self.inner.as_bytes().map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to get ciphertext as bytes: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Generates a bytearray representation of the ciphertext, compressed with the given
+	/// codec instead of the library's default.
+	pub fn as_bytes_with_compression(
+		&self,
+		compression: &PyCompressionType,
+	) -> PyResult<Vec<u8>> {
+		self.inner
+			.to_bytes_with_compression(compression.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to get ciphertext as bytes: {:?}",
+					e
+				))
+			})
+	}
+
+	/// Returns the number of polynomials (the size) of this ciphertext.
+	pub fn size(&self) -> usize {
+		self.inner.len()
+	}
+
+	/// Returns the number of primes in the coefficient modulus of the associated encryption
+	/// parameters.
+	pub fn coeff_modulus_size(&self) -> usize {
+		self.inner.coeff_modulus_size()
+	}
+
+	/// Returns the scale of this ciphertext, as set by a CKKS encoder at creation time and
+	/// updated by any subsequent multiply/square/rescale. Meaningless for BFV ciphertexts.
+	pub fn scale(&self) -> f64 {
+		self.inner.scale()
+	}
+
+	/// Returns this ciphertext's current index in the modulus switching chain: 0 at the last
+	/// usable level, counting up toward the first (freshest) level. Two ciphertexts must be at
+	/// the same level (and, for CKKS, the same scale) before `add`/`add_plain` will accept them.
+	pub fn level(
+		&self,
+		context: &PyContext,
+	) -> PyResult<usize> {
+		let parms_id = self.inner.parms_id().map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to get ciphertext parms_id: {:?}",
+				e
+			))
+		})?;
+
+		context
+			.inner
+			.get_context_data(&parms_id)
+			.and_then(|data| data.chain_index())
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to get ciphertext level: {:?}",
+					e
+				))
+			})
+	}
+
+	/// Saves the ciphertext to a file at the given path, prefixed with a small header
+	/// identifying the encryption parameters it was produced under.
+	pub fn save(
+		&self,
+		context: &PyContext,
+		path: &str,
+	) -> PyResult<()> {
+		let bytes = self.inner.to_bytes_versioned(&context.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize ciphertext: {:?}",
+				e
+			))
+		})?;
+
+		std::fs::write(path, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+				"Failed to write ciphertext to {}: {}",
+				path, e
+			))
+		})
+	}
+
+	/// Loads a ciphertext previously written by `save`, rejecting it if it was produced
+	/// under encryption parameters incompatible with `context`.
+	#[staticmethod]
+	pub fn load(
+		context: &PyContext,
+		path: &str,
+	) -> PyResult<Self> {
+		let bytes = std::fs::read(path).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+				"Failed to read ciphertext from {}: {}",
+				path, e
+			))
+		})?;
+
+		let ciphertext =
+			sealy::Ciphertext::from_bytes_versioned(&context.inner, &bytes).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to load ciphertext: {:?}",
+					e
+				))
+			})?;
+
+		Ok(Self {
+			inner: ciphertext,
+		})
+	}
+
+	fn __len__(&self) -> usize {
+		self.size()
+	}
+
+	fn __str__(&self) -> String {
+		format!("{:?}", self.inner)
+	}
+
+	fn __repr__(&self) -> String {
+		format!("{:?}", self.inner)
+	}
+}
@@ -11,6 +11,7 @@ use crate::{PyEncryptionParameters, PySecurityLevel};
 #[pyclass(module = "sealy", name = "Context")]
 pub struct PyContext {
 	pub(crate) inner: sealy::Context,
+	expand_mod_chain: bool,
 }
 
 #[pymethods]
@@ -33,6 +34,7 @@ impl PyContext {
 
 		Ok(Self {
 			inner: context,
+			expand_mod_chain,
 		})
 	}
 
@@ -111,10 +113,9 @@ impl PyContext {
 	}
 
 	pub fn __getnewargs__(&self) -> PyResult<(PyEncryptionParameters, bool, PySecurityLevel)> {
-		let expand_mod_chain = true;
 		let params = self.get_encryption_parameters()?;
 		let security_level = self.get_security_level()?;
 
-		Ok((params, expand_mod_chain, security_level))
+		Ok((params, self.expand_mod_chain, security_level))
 	}
 }
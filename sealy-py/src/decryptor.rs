@@ -45,6 +45,7 @@ impl PyDecryptor {
 		})?;
 		Ok(PyPlaintext {
 			inner: decrypted,
+			context: None,
 		})
 	}
 
@@ -1,6 +1,7 @@
 use pyo3::prelude::*;
+use sealy::Encoder;
 
-use crate::{context::PyContext, plaintext::PyPlaintext};
+use crate::{batch::PyBatch, context::PyContext, plaintext::PyPlaintext};
 
 /// Provides functionality for CRT batching.
 #[derive(Debug)]
@@ -43,6 +44,7 @@ impl PyBFVEncoder {
 		})?;
 		Ok(PyPlaintext {
 			inner: encoded,
+			context: None,
 		})
 	}
 
@@ -74,6 +76,7 @@ impl PyBFVEncoder {
 		})?;
 		Ok(PyPlaintext {
 			inner: encoded,
+			context: None,
 		})
 	}
 
@@ -93,6 +96,140 @@ impl PyBFVEncoder {
 	}
 }
 
+/// Encodes a single integer into the coefficients of a BFV plaintext polynomial, rather
+/// than into CRT batch slots like `BFVEncoder` does.
+#[derive(Debug)]
+#[pyclass(module = "sealy", name = "IntegerEncoder")]
+pub struct PyIntegerEncoder {
+	inner: sealy::IntegerEncoder,
+}
+
+#[pymethods]
+impl PyIntegerEncoder {
+	/// Creates an IntegerEncoder using the given `base`, or the default base of 2 when `base`
+	/// is omitted.
+	#[new]
+	#[pyo3(signature = (ctx, base=None))]
+	pub fn new(
+		ctx: &PyContext,
+		base: Option<u64>,
+	) -> PyResult<Self> {
+		let encoder = match base {
+			Some(base) => sealy::IntegerEncoder::with_base(&ctx.inner, base),
+			None => sealy::IntegerEncoder::new(&ctx.inner),
+		}
+		.map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to create IntegerEncoder: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner: encoder,
+		})
+	}
+
+	/// Encodes a single integer into a plaintext polynomial.
+	pub fn encode(
+		&self,
+		value: i64,
+	) -> PyResult<PyPlaintext> {
+		let encoded = self.inner.encode(value).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to encode value: {:?}",
+				e
+			))
+		})?;
+		Ok(PyPlaintext {
+			inner: encoded,
+			context: None,
+		})
+	}
+
+	/// Decodes a plaintext polynomial back into an integer.
+	pub fn decode(
+		&self,
+		plaintext: &PyPlaintext,
+	) -> PyResult<i64> {
+		self.inner.decode(&plaintext.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to decode plaintext: {:?}",
+				e
+			))
+		})
+	}
+}
+
+/// Encodes a single `f64` into the coefficients of a BFV plaintext polynomial as a fixed-point
+/// value, rather than rounding it into a single slot the way `BFVEncoder`'s float helpers do.
+#[derive(Debug)]
+#[pyclass(module = "sealy", name = "BFVFractionalEncoder")]
+pub struct PyBFVFractionalEncoder {
+	inner: sealy::BFVFractionalEncoder,
+}
+
+#[pymethods]
+impl PyBFVFractionalEncoder {
+	/// Creates a `BFVFractionalEncoder` that expands the integer part of a value in
+	/// `integer_coeff_count` base-`base` digits and the fractional part in
+	/// `fraction_coeff_count` base-`base` digits.
+	#[new]
+	pub fn new(
+		ctx: &PyContext,
+		base: u64,
+		integer_coeff_count: usize,
+		fraction_coeff_count: usize,
+	) -> PyResult<Self> {
+		let encoder = sealy::BFVFractionalEncoder::new(
+			&ctx.inner,
+			base,
+			integer_coeff_count,
+			fraction_coeff_count,
+		)
+		.map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to create BFVFractionalEncoder: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner: encoder,
+		})
+	}
+
+	/// Encodes a floating-point value into a plaintext polynomial.
+	pub fn encode(
+		&self,
+		value: f64,
+	) -> PyResult<PyPlaintext> {
+		let encoded = self.inner.encode(value).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to encode value: {:?}",
+				e
+			))
+		})?;
+		Ok(PyPlaintext {
+			inner: encoded,
+			context: None,
+		})
+	}
+
+	/// Decodes a plaintext polynomial back into a floating-point value.
+	pub fn decode(
+		&self,
+		plaintext: &PyPlaintext,
+	) -> PyResult<f64> {
+		self.inner.decode(&plaintext.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to decode plaintext: {:?}",
+				e
+			))
+		})
+	}
+}
+
 /// To create CKKS plaintexts we need a special encoder: there is no other way
 /// to create them. The BatchEncoder cannot be used with the
 /// CKKS scheme. The CKKSEncoder encodes vectors of real or complex numbers into
@@ -143,6 +280,7 @@ impl PyCKKSEncoder {
 		})?;
 		Ok(PyPlaintext {
 			inner: encoded,
+			context: None,
 		})
 	}
 
@@ -160,3 +298,61 @@ impl PyCKKSEncoder {
 		Ok(decoded)
 	}
 }
+
+/// Wraps a BFV encoder so data longer than a single plaintext's slot count can be encoded in
+/// one call, automatically split across as many plaintexts as needed (and recombined on
+/// decode), mirroring `sealy::ext::batched::BatchEncoder`.
+#[derive(Debug)]
+#[pyclass(module = "sealy", name = "BatchEncoder")]
+pub struct PyBatchEncoder {
+	inner: sealy::ext::batched::encoder::BatchEncoder<i64, sealy::BFVEncoder>,
+}
+
+#[pymethods]
+impl PyBatchEncoder {
+	/// Creates a BatchEncoder, building its own BFVEncoder from `ctx` internally.
+	#[new]
+	pub fn new(ctx: &PyContext) -> PyResult<Self> {
+		let encoder = sealy::BFVEncoder::new(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to create BFVEncoder: {:?}",
+				e
+			))
+		})?;
+		Ok(Self {
+			inner: sealy::ext::batched::encoder::BatchEncoder::new(encoder),
+		})
+	}
+
+	/// Returns the number of slots a single plaintext produced by this encoder can hold.
+	pub fn get_slot_count(&self) -> usize {
+		use sealy::SlotCount;
+
+		self.inner.get_slot_count()
+	}
+
+	/// Encodes the given data into a batch of plaintexts, splitting it into as many
+	/// slot-count-sized chunks as needed.
+	pub fn encode(&self, data: Vec<i64>) -> PyResult<PyBatch> {
+		let encoded = self.inner.encode(&data).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to encode data: {:?}",
+				e
+			))
+		})?;
+		Ok(PyBatch {
+			inner: encoded,
+		})
+	}
+
+	/// Decodes the given batch of plaintexts back into data.
+	pub fn decode(&self, batch: &PyBatch) -> PyResult<Vec<i64>> {
+		let decoded = self.inner.decode(&batch.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to decode data: {:?}",
+				e
+			))
+		})?;
+		Ok(decoded)
+	}
+}
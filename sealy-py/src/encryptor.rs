@@ -44,6 +44,7 @@ impl PyAsymmetricComponents {
 	pub fn get_r(&self) -> PyPlaintext {
 		PyPlaintext {
 			inner: self.inner.r.clone(),
+			context: None,
 		}
 	}
 }
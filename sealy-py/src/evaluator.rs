@@ -230,6 +230,113 @@ impl PyBFVEvaluator {
 			inner: rotated,
 		})
 	}
+
+	/// Swaps the two rows of an encrypted `2`-by-`(N/2)` batched matrix.
+	pub fn rotate_columns(
+		&self,
+		a: &PyCiphertext,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<PyCiphertext> {
+		let rotated = self
+			.inner
+			.rotate_columns(&a.inner, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to rotate columns: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: rotated,
+		})
+	}
+
+	/// Squares a ciphertext. Cheaper than `multiply(a, a)` since the symmetric cross-terms only
+	/// need to be computed once.
+	pub fn square(
+		&self,
+		a: &PyCiphertext,
+	) -> PyResult<PyCiphertext> {
+		let squared = self.inner.square(&a.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to square ciphertext: {:?}",
+				e
+			))
+		})?;
+		Ok(PyCiphertext {
+			inner: squared,
+		})
+	}
+
+	/// Raises a ciphertext to `exponent` using square-and-multiply on its binary expansion,
+	/// relinearizing with `relin_keys` after every squaring/multiplication to keep the result's
+	/// size at 2.
+	pub fn exponentiate(
+		&self,
+		a: &PyCiphertext,
+		exponent: u64,
+		relin_keys: &PyRelinearizationKey,
+	) -> PyResult<PyCiphertext> {
+		let result = self
+			.inner
+			.exponentiate(&a.inner, exponent, &relin_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to exponentiate ciphertext: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: result,
+		})
+	}
+
+	/// Computes the slot-wise dot product of a ciphertext and a plaintext weight vector,
+	/// broadcasting the total across every slot. Multiplies elementwise with `weights`, then
+	/// reduces across slots with a log-depth rotate-and-sum using `galois_keys`.
+	pub fn dot_plain(
+		&self,
+		a: &PyCiphertext,
+		weights: &PyPlaintext,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<PyCiphertext> {
+		let result = self
+			.inner
+			.dot_plain(&a.inner, &weights.inner, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to compute dot product: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: result,
+		})
+	}
+
+	/// Computes the slot-wise dot product of two ciphertexts, broadcasting the total across
+	/// every slot. Multiplies elementwise, relinearizes with `relin_keys`, then reduces across
+	/// slots with a log-depth rotate-and-sum using `galois_keys`.
+	pub fn dot(
+		&self,
+		a: &PyCiphertext,
+		b: &PyCiphertext,
+		relin_keys: &PyRelinearizationKey,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<PyCiphertext> {
+		let result = self
+			.inner
+			.dot_product(&a.inner, &b.inner, &relin_keys.inner, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to compute dot product: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: result,
+		})
+	}
 }
 
 /// An evaluator that contains additional operations specific to the CKKS scheme.
@@ -435,4 +542,241 @@ impl PyCKKSEvaluator {
 			inner: relinearized,
 		})
 	}
+
+	/// Squares a ciphertext. Cheaper than `multiply(a, a)` since the symmetric cross-terms only
+	/// need to be computed once.
+	pub fn square(
+		&self,
+		a: &PyCiphertext,
+	) -> PyResult<PyCiphertext> {
+		let squared = self.inner.square(&a.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to square ciphertext: {:?}",
+				e
+			))
+		})?;
+		Ok(PyCiphertext {
+			inner: squared,
+		})
+	}
+
+	/// Raises a ciphertext to `exponent` using square-and-multiply on its binary expansion,
+	/// relinearizing with `relin_keys` after every squaring/multiplication to keep the result's
+	/// size at 2.
+	pub fn exponentiate(
+		&self,
+		a: &PyCiphertext,
+		exponent: u64,
+		relin_keys: &PyRelinearizationKey,
+	) -> PyResult<PyCiphertext> {
+		let result = self
+			.inner
+			.exponentiate(&a.inner, exponent, &relin_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to exponentiate ciphertext: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: result,
+		})
+	}
+
+	/// Computes the slot-wise dot product of a ciphertext and a plaintext weight vector,
+	/// broadcasting the total across every slot. Multiplies elementwise with `weights`, then
+	/// reduces across slots with a log-depth rotate-and-sum using `galois_keys`.
+	pub fn dot_plain(
+		&self,
+		a: &PyCiphertext,
+		weights: &PyPlaintext,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<PyCiphertext> {
+		let result = self
+			.inner
+			.dot_plain(&a.inner, &weights.inner, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to compute dot product: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: result,
+		})
+	}
+
+	/// Computes the slot-wise dot product of two ciphertexts, broadcasting the total across
+	/// every slot. Multiplies elementwise, relinearizes with `relin_keys`, then reduces across
+	/// slots with a log-depth rotate-and-sum using `galois_keys`.
+	pub fn dot(
+		&self,
+		a: &PyCiphertext,
+		b: &PyCiphertext,
+		relin_keys: &PyRelinearizationKey,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<PyCiphertext> {
+		let result = self
+			.inner
+			.dot_product(&a.inner, &b.inner, &relin_keys.inner, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to compute dot product: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: result,
+		})
+	}
+
+	/// Rotates the encoded slot vector of a ciphertext cyclically by `steps` slots (positive
+	/// left, negative right).
+	pub fn rotate_vector(
+		&self,
+		a: &PyCiphertext,
+		steps: i32,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<PyCiphertext> {
+		let rotated = self
+			.inner
+			.rotate_vector(&a.inner, steps, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to rotate vector: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: rotated,
+		})
+	}
+
+	/// Replaces each slot with its complex conjugate. For slots holding purely real data this
+	/// is a no-op; it matters when a computation has produced genuinely complex intermediate
+	/// values.
+	pub fn complex_conjugate(
+		&self,
+		a: &PyCiphertext,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<PyCiphertext> {
+		let conjugated = self
+			.inner
+			.complex_conjugate(&a.inner, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to conjugate ciphertext: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: conjugated,
+		})
+	}
+
+	/// Rescales a ciphertext down to the next level in the modulus switching chain, dividing
+	/// out the last prime in the current coefficient modulus and restoring the scale from
+	/// `scale^2` back toward its base value. Call this after every `multiply`/`multiply_plain`
+	/// to keep the scale from blowing up across a chain of products.
+	pub fn rescale_to_next(
+		&self,
+		a: &PyCiphertext,
+	) -> PyResult<PyCiphertext> {
+		let rescaled = self.inner.rescale_to_next(&a.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to rescale ciphertext: {:?}",
+				e
+			))
+		})?;
+		Ok(PyCiphertext {
+			inner: rescaled,
+		})
+	}
+
+	/// Switches a ciphertext down to the next level in the modulus switching chain without
+	/// rescaling (the scale is left unchanged). Use this to drop a ciphertext by one level to
+	/// match an operand that's already been rescaled.
+	pub fn mod_switch_to_next(
+		&self,
+		a: &PyCiphertext,
+	) -> PyResult<PyCiphertext> {
+		let switched = self.inner.mod_switch_to_next(&a.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to mod-switch ciphertext: {:?}",
+				e
+			))
+		})?;
+		Ok(PyCiphertext {
+			inner: switched,
+		})
+	}
+
+	/// Switches a ciphertext down to the modulus switching chain level reported by
+	/// [`PyCiphertext::level`], so it can be combined with another operand already at that
+	/// level. `target_level` must name a level at or below `a`'s current one.
+	pub fn mod_switch_to(
+		&self,
+		context: &PyContext,
+		a: &PyCiphertext,
+		target_level: usize,
+	) -> PyResult<PyCiphertext> {
+		let parms_id = target_parms_id(&context.inner, target_level)?;
+
+		let switched = self.inner.mod_switch_to(&a.inner, &parms_id).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to mod-switch ciphertext: {:?}",
+				e
+			))
+		})?;
+		Ok(PyCiphertext {
+			inner: switched,
+		})
+	}
+}
+
+/// Walks the modulus switching chain from its first (freshest) level down to the context data
+/// whose [`sealy::ContextData::chain_index`] equals `target_level`, returning its parms_id.
+fn target_parms_id(
+	context: &sealy::Context,
+	target_level: usize,
+) -> PyResult<Vec<u64>> {
+	let mut current = context.get_first_context_data().map_err(|e| {
+		PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+			"Failed to get context data: {:?}",
+			e
+		))
+	})?;
+
+	loop {
+		let chain_index = current.chain_index().map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to get chain index: {:?}",
+				e
+			))
+		})?;
+
+		if chain_index == target_level {
+			return current.parms_id().map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to get parms_id: {:?}",
+					e
+				))
+			});
+		}
+
+		current = current
+			.next_context_data()
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to get next context data: {:?}",
+					e
+				))
+			})?
+			.ok_or_else(|| {
+				PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+					"No level {} in the modulus switching chain",
+					target_level
+				))
+			})?;
+	}
 }
@@ -1,3 +1,4 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use sealy::{FromBytes, ToBytes};
 
@@ -8,6 +9,7 @@ use crate::context::PyContext;
 #[pyclass(module = "sealy", name = "PublicKey")]
 pub struct PyPublicKey {
 	pub(crate) inner: sealy::PublicKey,
+	context: Option<Py<PyContext>>,
 }
 
 #[pymethods]
@@ -23,6 +25,7 @@ impl PyPublicKey {
 		})?;
 		Ok(Self {
 			inner: pk,
+			context: None,
 		})
 	}
 
@@ -39,17 +42,52 @@ impl PyPublicKey {
 	/// Creates a new public key from a byte array.
 	#[staticmethod]
 	pub fn from_bytes(
+		py: Python<'_>,
+		context: Py<PyContext>,
+		bytes: Vec<u8>,
+	) -> PyResult<Self> {
+		let pk = {
+			let ctx_ref = context.borrow(py);
+			sealy::PublicKey::from_bytes(&ctx_ref.inner, &bytes).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to create public key from bytes: {:?}",
+					e
+				))
+			})?
+		};
+		Ok(Self {
+			inner: pk,
+			context: Some(context),
+		})
+	}
+
+	/// Returns the public key in SEAL's compact seeded form. Not yet supported by this binding;
+	/// see `sealy::PublicKey::as_bytes_compressed`'s docs for why.
+	pub fn as_bytes_compressed(&self) -> PyResult<Vec<u8>> {
+		self.inner.as_bytes_compressed().map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to get public key as compressed bytes: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Creates a new public key from a byte array produced by `as_bytes_compressed`. Not yet
+	/// supported by this binding; see `sealy::PublicKey::from_bytes_compressed`'s docs for why.
+	#[staticmethod]
+	pub fn from_bytes_compressed(
 		context: &PyContext,
 		bytes: Vec<u8>,
 	) -> PyResult<Self> {
-		let pk = sealy::PublicKey::from_bytes(&context.inner, &bytes).map_err(|e| {
+		let pk = sealy::PublicKey::from_bytes_compressed(&context.inner, &bytes).map_err(|e| {
 			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-				"Failed to create public key from bytes: {:?}",
+				"Failed to create public key from compressed bytes: {:?}",
 				e
 			))
 		})?;
 		Ok(Self {
 			inner: pk,
+			context: None,
 		})
 	}
 
@@ -59,6 +97,27 @@ impl PyPublicKey {
 	) -> bool {
 		self.inner == other.inner
 	}
+
+	/// Supports `pickle`/`copy.deepcopy` by reducing to a `(PublicKey.from_bytes, (context,
+	/// bytes))` call, mirroring `as_bytes`/`from_bytes`. Only works for a key that was itself
+	/// built `from_bytes` or produced by a `KeyGenerator`, since that's the only time this
+	/// binding has a `Context` to hand back; a key constructed via the bare `PublicKey()`
+	/// constructor has none to offer.
+	fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Py<PyContext>, Vec<u8>))> {
+		let context = self.context.clone().ok_or_else(|| {
+			PyValueError::new_err(
+				"cannot pickle a PublicKey that wasn't built via PublicKey.from_bytes(...) or a \
+				 KeyGenerator, since its originating Context isn't retained",
+			)
+		})?;
+		let bytes = self.as_bytes()?;
+		let ctor = py
+			.get_type::<PyPublicKey>()
+			.getattr("from_bytes")?
+			.unbind();
+
+		Ok((ctor, (context, bytes)))
+	}
 }
 
 /// Class to store a secret key.
@@ -66,6 +125,7 @@ impl PyPublicKey {
 #[pyclass(module = "sealy", name = "SecretKey")]
 pub struct PySecretKey {
 	pub(crate) inner: sealy::SecretKey,
+	context: Option<Py<PyContext>>,
 }
 
 #[pymethods]
@@ -81,6 +141,7 @@ impl PySecretKey {
 		})?;
 		Ok(Self {
 			inner: sk,
+			context: None,
 		})
 	}
 
@@ -97,17 +158,22 @@ impl PySecretKey {
 	/// Creates a new secret key from a byte array.
 	#[staticmethod]
 	pub fn from_bytes(
-		context: &PyContext,
+		py: Python<'_>,
+		context: Py<PyContext>,
 		bytes: Vec<u8>,
 	) -> PyResult<Self> {
-		let sk = sealy::SecretKey::from_bytes(&context.inner, &bytes).map_err(|e| {
-			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-				"Failed to create secret key from bytes: {:?}",
-				e
-			))
-		})?;
+		let sk = {
+			let ctx_ref = context.borrow(py);
+			sealy::SecretKey::from_bytes(&ctx_ref.inner, &bytes).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to create secret key from bytes: {:?}",
+					e
+				))
+			})?
+		};
 		Ok(Self {
 			inner: sk,
+			context: Some(context),
 		})
 	}
 
@@ -125,6 +191,29 @@ impl PySecretKey {
 	) -> bool {
 		self.inner == other.inner
 	}
+
+	/// Supports `pickle`/`copy.deepcopy` the same way as `PyPublicKey::__reduce__`; see its docs.
+	///
+	/// Note this round-trips through `SecretKey.from_bytes`, which this binding currently calls
+	/// with whatever compression `sealy::SecretKey::as_bytes` uses by default (`ZStd`), not the
+	/// uncompressed, constant-time representation `sealy::SecretKey`'s opt-in `secret-serde`
+	/// feature produces for native `serde`; pickling a secret key is consequently not subject to
+	/// that feature gate here.
+	fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Py<PyContext>, Vec<u8>))> {
+		let context = self.context.clone().ok_or_else(|| {
+			PyValueError::new_err(
+				"cannot pickle a SecretKey that wasn't built via SecretKey.from_bytes(...) or a \
+				 KeyGenerator, since its originating Context isn't retained",
+			)
+		})?;
+		let bytes = self.as_bytes()?;
+		let ctor = py
+			.get_type::<PySecretKey>()
+			.getattr("from_bytes")?
+			.unbind();
+
+		Ok((ctor, (context, bytes)))
+	}
 }
 
 /// Class to store relinearization keys.
@@ -132,6 +221,7 @@ impl PySecretKey {
 #[pyclass(module = "sealy", name = "RelinearizationKey")]
 pub struct PyRelinearizationKey {
 	pub(crate) inner: sealy::RelinearizationKey,
+	context: Option<Py<PyContext>>,
 }
 
 #[pymethods]
@@ -146,6 +236,7 @@ impl PyRelinearizationKey {
 		})?;
 		Ok(Self {
 			inner: rk,
+			context: None,
 		})
 	}
 
@@ -162,17 +253,54 @@ impl PyRelinearizationKey {
 	/// Creates a new relinearization keys from a byte array.
 	#[staticmethod]
 	pub fn from_bytes(
-		context: &PyContext,
+		py: Python<'_>,
+		context: Py<PyContext>,
 		bytes: Vec<u8>,
 	) -> PyResult<Self> {
-		let rk = sealy::RelinearizationKey::from_bytes(&context.inner, &bytes).map_err(|e| {
+		let rk = {
+			let ctx_ref = context.borrow(py);
+			sealy::RelinearizationKey::from_bytes(&ctx_ref.inner, &bytes).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to create relinearization keys from bytes: {:?}",
+					e
+				))
+			})?
+		};
+		Ok(Self {
+			inner: rk,
+			context: Some(context),
+		})
+	}
+
+	/// Returns the key in SEAL's compact seeded form. Not yet supported by this binding; see
+	/// `sealy::RelinearizationKey::as_bytes_compressed`'s docs for why.
+	pub fn as_bytes_compressed(&self) -> PyResult<Vec<u8>> {
+		self.inner.as_bytes_compressed().map_err(|e| {
 			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-				"Failed to create relinearization keys from bytes: {:?}",
+				"Failed to get relinearization keys as compressed bytes: {:?}",
 				e
 			))
-		})?;
+		})
+	}
+
+	/// Creates a new relinearization keys from a byte array produced by `as_bytes_compressed`.
+	/// Not yet supported by this binding; see
+	/// `sealy::RelinearizationKey::from_bytes_compressed`'s docs for why.
+	#[staticmethod]
+	pub fn from_bytes_compressed(
+		context: &PyContext,
+		bytes: Vec<u8>,
+	) -> PyResult<Self> {
+		let rk =
+			sealy::RelinearizationKey::from_bytes_compressed(&context.inner, &bytes).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to create relinearization keys from compressed bytes: {:?}",
+					e
+				))
+			})?;
 		Ok(Self {
 			inner: rk,
+			context: None,
 		})
 	}
 
@@ -182,6 +310,24 @@ impl PyRelinearizationKey {
 	) -> bool {
 		self.inner == other.inner
 	}
+
+	/// Supports `pickle`/`copy.deepcopy` the same way as `PyPublicKey::__reduce__`; see its docs.
+	fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Py<PyContext>, Vec<u8>))> {
+		let context = self.context.clone().ok_or_else(|| {
+			PyValueError::new_err(
+				"cannot pickle a RelinearizationKey that wasn't built via \
+				 RelinearizationKey.from_bytes(...) or a KeyGenerator, since its originating \
+				 Context isn't retained",
+			)
+		})?;
+		let bytes = self.as_bytes()?;
+		let ctor = py
+			.get_type::<PyRelinearizationKey>()
+			.getattr("from_bytes")?
+			.unbind();
+
+		Ok((ctor, (context, bytes)))
+	}
 }
 
 /// Class to store Galois keys.
@@ -189,6 +335,7 @@ impl PyRelinearizationKey {
 #[pyclass(module = "sealy", name = "GaloisKey")]
 pub struct PyGaloisKey {
 	pub(crate) inner: sealy::GaloisKey,
+	context: Option<Py<PyContext>>,
 }
 
 #[pymethods]
@@ -203,6 +350,7 @@ impl PyGaloisKey {
 		})?;
 		Ok(Self {
 			inner: gk,
+			context: None,
 		})
 	}
 
@@ -219,17 +367,52 @@ impl PyGaloisKey {
 	/// Creates a new Galois keys from a byte array.
 	#[staticmethod]
 	pub fn from_bytes(
+		py: Python<'_>,
+		context: Py<PyContext>,
+		bytes: Vec<u8>,
+	) -> PyResult<Self> {
+		let gk = {
+			let ctx_ref = context.borrow(py);
+			sealy::GaloisKey::from_bytes(&ctx_ref.inner, &bytes).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to create Galois keys from bytes: {:?}",
+					e
+				))
+			})?
+		};
+		Ok(Self {
+			inner: gk,
+			context: Some(context),
+		})
+	}
+
+	/// Returns the key in SEAL's compact seeded form. Not yet supported by this binding; see
+	/// `sealy::GaloisKey::as_bytes_compressed`'s docs for why.
+	pub fn as_bytes_compressed(&self) -> PyResult<Vec<u8>> {
+		self.inner.as_bytes_compressed().map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to get Galois keys as compressed bytes: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Creates a new Galois keys from a byte array produced by `as_bytes_compressed`. Not yet
+	/// supported by this binding; see `sealy::GaloisKey::from_bytes_compressed`'s docs for why.
+	#[staticmethod]
+	pub fn from_bytes_compressed(
 		context: &PyContext,
 		bytes: Vec<u8>,
 	) -> PyResult<Self> {
-		let gk = sealy::GaloisKey::from_bytes(&context.inner, &bytes).map_err(|e| {
+		let gk = sealy::GaloisKey::from_bytes_compressed(&context.inner, &bytes).map_err(|e| {
 			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-				"Failed to create Galois keys from bytes: {:?}",
+				"Failed to create Galois keys from compressed bytes: {:?}",
 				e
 			))
 		})?;
 		Ok(Self {
 			inner: gk,
+			context: None,
 		})
 	}
 
@@ -239,6 +422,23 @@ impl PyGaloisKey {
 	) -> bool {
 		self.inner == other.inner
 	}
+
+	/// Supports `pickle`/`copy.deepcopy` the same way as `PyPublicKey::__reduce__`; see its docs.
+	fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Py<PyContext>, Vec<u8>))> {
+		let context = self.context.clone().ok_or_else(|| {
+			PyValueError::new_err(
+				"cannot pickle a GaloisKey that wasn't built via GaloisKey.from_bytes(...) or a \
+				 KeyGenerator, since its originating Context isn't retained",
+			)
+		})?;
+		let bytes = self.as_bytes()?;
+		let ctor = py
+			.get_type::<PyGaloisKey>()
+			.getattr("from_bytes")?
+			.unbind();
+
+		Ok((ctor, (context, bytes)))
+	}
 }
 
 /// Generates matching secret key and public key.
@@ -246,21 +446,29 @@ impl PyGaloisKey {
 #[pyclass(module = "sealy", name = "KeyGenerator")]
 pub struct PyKeyGenerator {
 	inner: sealy::KeyGenerator,
+	ctx: Py<PyContext>,
 }
 
 #[pymethods]
 impl PyKeyGenerator {
 	/// Creates a KeyGenerator initialized with the specified sealy::Context.
 	#[new]
-	pub fn new(ctx: &PyContext) -> PyResult<Self> {
-		let gen = sealy::KeyGenerator::new(&ctx.inner).map_err(|e| {
-			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-				"Failed to create key generator: {:?}",
-				e
-			))
-		})?;
+	pub fn new(
+		py: Python<'_>,
+		ctx: Py<PyContext>,
+	) -> PyResult<Self> {
+		let gen = {
+			let ctx_ref = ctx.borrow(py);
+			sealy::KeyGenerator::new(&ctx_ref.inner).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to create key generator: {:?}",
+					e
+				))
+			})?
+		};
 		Ok(Self {
 			inner: gen,
+			ctx,
 		})
 	}
 
@@ -268,19 +476,24 @@ impl PyKeyGenerator {
 	/// sealy::Context and specified previously secret key.
 	#[staticmethod]
 	pub fn from_secret_key(
-		ctx: &PyContext,
+		py: Python<'_>,
+		ctx: Py<PyContext>,
 		secret_key: &PySecretKey,
 	) -> PyResult<Self> {
-		let gen = sealy::KeyGenerator::new_from_secret_key(&ctx.inner, &secret_key.inner).map_err(
-			|e| {
-				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-					"Failed to create key generator from secret key: {:?}",
-					e
-				))
-			},
-		)?;
+		let gen = {
+			let ctx_ref = ctx.borrow(py);
+			sealy::KeyGenerator::new_from_secret_key(&ctx_ref.inner, &secret_key.inner).map_err(
+				|e| {
+					PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+						"Failed to create key generator from secret key: {:?}",
+						e
+					))
+				},
+			)?
+		};
 		Ok(Self {
 			inner: gen,
+			ctx,
 		})
 	}
 
@@ -289,6 +502,7 @@ impl PyKeyGenerator {
 		let sk = self.inner.secret_key();
 		PySecretKey {
 			inner: sk,
+			context: Some(self.ctx.clone()),
 		}
 	}
 
@@ -297,6 +511,7 @@ impl PyKeyGenerator {
 		let pk = self.inner.create_public_key();
 		PyPublicKey {
 			inner: pk,
+			context: Some(self.ctx.clone()),
 		}
 	}
 
@@ -310,6 +525,7 @@ impl PyKeyGenerator {
 		})?;
 		Ok(PyRelinearizationKey {
 			inner: rk,
+			context: Some(self.ctx.clone()),
 		})
 	}
 
@@ -324,6 +540,25 @@ impl PyKeyGenerator {
 
 		Ok(PyGaloisKey {
 			inner: gk,
+			context: Some(self.ctx.clone()),
+		})
+	}
+
+	/// Generates Galois keys restricted to the given rotation steps, rather than the full
+	/// Galois group `create_galois_key` produces. Most circuits only rotate by a handful of
+	/// steps, so a key tailored to just those steps can be an order of magnitude smaller to
+	/// serialize and faster to generate.
+	pub fn create_galois_keys_from_steps(&self, steps: Vec<i32>) -> PyResult<PyGaloisKey> {
+		let gk = self.inner.create_galois_keys_from_steps(&steps).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to create Galois keys from steps: {:?}",
+				e
+			))
+		})?;
+
+		Ok(PyGaloisKey {
+			inner: gk,
+			context: Some(self.ctx.clone()),
 		})
 	}
 }
@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 
+mod batch;
 mod ciphertext;
 mod context;
 mod decryptor;
@@ -7,29 +8,38 @@ mod encoder;
 mod encryptor;
 mod evaluator;
 mod keys;
+mod maybe_encrypted;
 mod memory;
 mod parameters;
 mod plaintext;
 mod poly_array;
+mod signed_ciphertext;
 mod tensor;
 
+use crate::batch::PyBatch;
 use crate::ciphertext::PyCiphertext;
 use crate::context::PyContext;
 use crate::decryptor::PyDecryptor;
-use crate::encoder::{PyBFVEncoder, PyCKKSEncoder};
+use crate::encoder::{
+	PyBFVEncoder, PyBFVFractionalEncoder, PyBatchEncoder, PyCKKSEncoder, PyIntegerEncoder,
+};
 use crate::encryptor::{PyAsymmetricComponents, PyEncryptor};
 use crate::evaluator::{PyBFVEvaluator, PyCKKSEvaluator};
 use crate::keys::{PyGaloisKey, PyKeyGenerator, PyPublicKey, PyRelinearizationKey, PySecretKey};
+use crate::maybe_encrypted::PyMaybeEncrypted;
 use crate::memory::PyMemoryPool;
 use crate::parameters::{
-	PyCoefficientModulus, PyDegreeType, PyEncryptionParameters, PyModulus, PyPlainModulus,
-	PySchemeType, PySecurityLevel,
+	PyCoefficientModulus, PyCompressionType, PyDegreeType, PyEncryptionParameters, PyModulus,
+	PyPlainModulus, PySchemeType, PySecurityLevel,
 };
 use crate::plaintext::PyPlaintext;
 use crate::poly_array::PyPolynomialArray;
+use crate::signed_ciphertext::{
+	PyEd25519KeyPair, PyEd25519PublicKey, PySignedCiphertext, PySignedCiphertextBatchArray,
+};
 use crate::tensor::{
-	PyCKKSTensorEncoder, PyCKKSTensorEvaluator, PyCiphertextTensor, PyPlaintextTensor,
-	PyTensorDecryptor, PyTensorEncryptor,
+	PyBFVTensorEncoder, PyCKKSTensorEncoder, PyCKKSTensorEvaluator, PyCiphertextTensor,
+	PyPlaintextTensor, PyTensorDecryptor, PyTensorEncryptor,
 };
 
 /// A Python module implemented in Rust. The name of this function must match
@@ -38,6 +48,7 @@ use crate::tensor::{
 #[pymodule]
 fn sealy(m: &Bound<'_, PyModule>) -> PyResult<()> {
 	m.add_class::<PySchemeType>()?;
+	m.add_class::<PyCompressionType>()?;
 	m.add_class::<PyDegreeType>()?;
 	m.add_class::<PySecurityLevel>()?;
 	m.add_class::<PyModulus>()?;
@@ -56,19 +67,29 @@ fn sealy(m: &Bound<'_, PyModule>) -> PyResult<()> {
 	m.add_class::<PyPolynomialArray>()?;
 	m.add_class::<PyBFVEncoder>()?;
 	m.add_class::<PyCKKSEncoder>()?;
+	m.add_class::<PyIntegerEncoder>()?;
+	m.add_class::<PyBFVFractionalEncoder>()?;
+	m.add_class::<PyBatchEncoder>()?;
 	m.add_class::<PyAsymmetricComponents>()?;
 	m.add_class::<PyEncryptor>()?;
 	m.add_class::<PyDecryptor>()?;
 	m.add_class::<PyBFVEvaluator>()?;
 	m.add_class::<PyCKKSEvaluator>()?;
+	m.add_class::<PyEd25519KeyPair>()?;
+	m.add_class::<PyEd25519PublicKey>()?;
+	m.add_class::<PySignedCiphertext>()?;
+	m.add_class::<PySignedCiphertextBatchArray>()?;
+	m.add_class::<PyMaybeEncrypted>()?;
 
 	// Batch operations: maybe will be drepecated.
 	m.add_class::<PyPlaintextTensor>()?;
 	m.add_class::<PyCiphertextTensor>()?;
 	m.add_class::<PyCKKSTensorEncoder>()?;
+	m.add_class::<PyBFVTensorEncoder>()?;
 	m.add_class::<PyCKKSTensorEvaluator>()?;
 	m.add_class::<PyTensorEncryptor>()?;
 	m.add_class::<PyTensorDecryptor>()?;
+	m.add_class::<PyBatch>()?;
 
 	Ok(())
 }
@@ -0,0 +1,127 @@
+use pyo3::prelude::*;
+
+use crate::context::PyContext;
+use crate::tensor::{PyCiphertextTensor, PyPlaintextTensor, PyTensorDecryptor, PyTensorEncryptor};
+
+/// A tensor that is either still cleartext or already encrypted, letting a pipeline pass a
+/// value through stages that only conditionally encrypt it without the caller tracking the
+/// state by hand.
+#[pyclass(module = "sealy", name = "MaybeEncrypted")]
+pub struct PyMaybeEncrypted {
+	inner: sealy::MaybeEncrypted,
+}
+
+#[pymethods]
+impl PyMaybeEncrypted {
+	/// Wraps an already-cleartext tensor.
+	#[staticmethod]
+	pub fn from_plaintext(tensor: PyPlaintextTensor) -> Self {
+		Self {
+			inner: sealy::MaybeEncrypted::Plain(tensor.inner),
+		}
+	}
+
+	/// Wraps an already-encrypted tensor.
+	#[staticmethod]
+	pub fn from_ciphertext(tensor: PyCiphertextTensor) -> Self {
+		Self {
+			inner: sealy::MaybeEncrypted::Cipher(tensor.inner),
+		}
+	}
+
+	/// Returns whether this value currently holds ciphertexts rather than cleartext.
+	pub fn is_encrypted(&self) -> bool {
+		self.inner.is_encrypted()
+	}
+
+	/// Encrypts the wrapped tensor in place with `encryptor`. A no-op if it is already
+	/// encrypted.
+	pub fn encrypt_in_place(
+		&mut self,
+		encryptor: &PyTensorEncryptor,
+	) -> PyResult<()> {
+		self.inner.encrypt_in_place(&encryptor.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to encrypt value in place: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Decrypts the wrapped tensor in place with `decryptor`. A no-op if it is already
+	/// cleartext.
+	pub fn decrypt_in_place(
+		&mut self,
+		decryptor: &PyTensorDecryptor,
+	) -> PyResult<()> {
+		self.inner.decrypt_in_place(&decryptor.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to decrypt value in place: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Returns the wrapped ciphertext tensor, so it can be passed to a
+	/// `CKKSTensorEvaluator`. Raises if this value has not been encrypted yet.
+	pub fn as_ciphertext(&self) -> PyResult<PyCiphertextTensor> {
+		self.inner
+			.as_ciphertext()
+			.map(|tensor| PyCiphertextTensor {
+				inner: tensor.clone(),
+			})
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Value is not encrypted: {:?}",
+					e
+				))
+			})
+	}
+
+	/// Returns the wrapped plaintext tensor. Raises if this value has already been encrypted.
+	pub fn as_plaintext(&self) -> PyResult<PyPlaintextTensor> {
+		self.inner
+			.as_plaintext()
+			.map(|tensor| PyPlaintextTensor {
+				inner: tensor.clone(),
+			})
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Value is already encrypted: {:?}",
+					e
+				))
+			})
+	}
+
+	/// Serializes this value, tagging which variant is present so `from_bytes` can restore
+	/// the same state without the caller tracking it separately.
+	pub fn as_bytes(
+		&self,
+		ctx: &PyContext,
+	) -> PyResult<Vec<u8>> {
+		self.inner.as_bytes(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize value: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Deserializes a value previously produced by `as_bytes`.
+	#[staticmethod]
+	pub fn from_bytes(
+		ctx: &PyContext,
+		bytes: &[u8],
+	) -> PyResult<Self> {
+		let inner = sealy::MaybeEncrypted::from_bytes(&ctx.inner, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to deserialize value: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+}
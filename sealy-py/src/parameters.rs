@@ -1,6 +1,63 @@
 use pyo3::prelude::*;
 use sealy::{FromBytes, ToBytes};
 
+/// The compression codec used when serializing a ciphertext, plaintext, or key.
+#[pyclass(module = "sealy", name = "CompressionType")]
+#[derive(Debug, Clone, Copy)]
+pub struct PyCompressionType {
+	pub(crate) inner: sealy::CompressionType,
+}
+
+#[pymethods]
+impl PyCompressionType {
+	#[new]
+	pub fn new(val: u8) -> Self {
+		Self {
+			inner: sealy::CompressionType::from_u8(val),
+		}
+	}
+
+	#[staticmethod]
+	pub fn none() -> Self {
+		Self {
+			inner: sealy::CompressionType::None,
+		}
+	}
+
+	#[staticmethod]
+	pub fn zlib() -> Self {
+		Self {
+			inner: sealy::CompressionType::ZLib,
+		}
+	}
+
+	#[staticmethod]
+	pub fn zstd() -> Self {
+		Self {
+			inner: sealy::CompressionType::ZStd,
+		}
+	}
+
+	fn __str__(&self) -> String {
+		format!("{:?}", self.inner)
+	}
+
+	fn __repr__(&self) -> String {
+		format!("{:?}", self.inner)
+	}
+
+	fn __eq__(
+		&self,
+		other: &PyCompressionType,
+	) -> bool {
+		self.inner == other.inner
+	}
+
+	fn __getnewargs__(&self) -> PyResult<(u8,)> {
+		Ok((self.inner as u8,))
+	}
+}
+
 #[pyclass(module = "sealy", name = "SchemeType")]
 #[derive(Debug, Clone)]
 pub struct PySchemeType {
@@ -103,8 +160,8 @@ impl PyEncryptionParameters {
 			.collect()
 	}
 
-	pub fn get_parms_id(&self) -> u64 {
-		self.inner.get_parms_id()
+	pub fn get_parms_id(&self) -> Vec<u64> {
+		<[u64; 4]>::from(self.inner.parms_id()).to_vec()
 	}
 
 	pub fn set_coefficient_modulus(
@@ -256,6 +313,40 @@ impl PyCoefficientModulus {
 	) -> u32 {
 		sealy::CoefficientModulusFactory::max_bit_count(degree.inner.into(), security_level.inner)
 	}
+
+	/// Recommends the smallest polynomial modulus degree (and a matching coefficient modulus
+	/// chain) able to support `multiplicative_depth` levels of multiplication at roughly
+	/// `precision_bits` of precision per level, under `security_level`.
+	#[staticmethod]
+	pub fn recommend(
+		multiplicative_depth: usize,
+		precision_bits: u32,
+		security_level: PySecurityLevel,
+	) -> PyResult<(PyDegreeType, Vec<PyModulus>)> {
+		let (degree, modulus) = sealy::CoefficientModulusFactory::recommend(
+			multiplicative_depth,
+			precision_bits,
+			security_level.inner,
+		)
+		.map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyException, _>(format!(
+				"Error recommending CoefficientModulus: {}",
+				e
+			))
+		})?;
+
+		Ok((
+			PyDegreeType {
+				inner: degree,
+			},
+			modulus
+				.into_iter()
+				.map(|m| PyModulus {
+					inner: m,
+				})
+				.collect(),
+		))
+	}
 }
 
 #[pyclass(module = "sealy", name = "PlainModulus")]
@@ -327,6 +418,14 @@ impl PyModulus {
 	) -> bool {
 		self.inner == other.inner
 	}
+
+	/// Supports `pickle`/`copy.deepcopy`: a modulus is fully determined by its value, so
+	/// reconstructing via `Modulus(value)` (like `PySchemeType::__getnewargs__`) is enough —
+	/// no separate `__getstate__`/`__setstate__` round trip through `ToBytes`/`FromBytes` is
+	/// needed, since `sealy::Modulus` doesn't implement those traits in the first place.
+	fn __getnewargs__(&self) -> PyResult<(u64,)> {
+		Ok((self.get_value(),))
+	}
 }
 
 #[pyclass(module = "sealy", name = "DegreeType")]
@@ -1,16 +1,19 @@
-use std::hash::Hash;
+use std::ffi::CString;
+use std::hash::{Hash, Hasher};
 
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
-use sealy::{FromBytes, ToBytes};
+use sealy::{FromBytes, ToBytes, Versioned};
 
 use crate::{context::PyContext, memory::PyMemoryPool};
 
 /// Class to store a plaintext element. The data for the plaintext is
 /// a polynomial with coefficients modulo the plaintext modulus.
-#[derive(Debug, Clone, PartialEq, Hash)]
+#[derive(Debug, Clone)]
 #[pyclass(module = "sealy", name = "Plaintext")]
 pub struct PyPlaintext {
 	pub(crate) inner: sealy::Plaintext,
+	pub(crate) context: Option<Py<PyContext>>,
 }
 
 #[pymethods]
@@ -26,6 +29,7 @@ impl PyPlaintext {
 		})?;
 		Ok(Self {
 			inner: plaintext,
+			context: None,
 		})
 	}
 
@@ -40,23 +44,29 @@ impl PyPlaintext {
 		})?;
 		Ok(Self {
 			inner: plaintext,
+			context: None,
 		})
 	}
 
 	/// Constructs a plaintext from a byte array.
 	#[staticmethod]
 	pub fn from_bytes(
-		context: &PyContext,
+		py: Python<'_>,
+		context: Py<PyContext>,
 		data: Vec<u8>,
 	) -> PyResult<Self> {
-		let plaintext = sealy::Plaintext::from_bytes(&context.inner, &data).map_err(|e| {
-			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-				"Failed to create plaintext from bytes: {:?}",
-				e
-			))
-		})?;
+		let plaintext = {
+			let ctx_ref = context.borrow(py);
+			sealy::Plaintext::from_bytes(&ctx_ref.inner, &data).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to create plaintext from bytes: {:?}",
+					e
+				))
+			})?
+		};
 		Ok(Self {
 			inner: plaintext,
+			context: Some(context),
 		})
 	}
 
@@ -72,8 +82,17 @@ impl PyPlaintext {
 
 	/// Constructs a plaintext from a given hexadecimal string describing the
 	/// plaintext polynomial.
+	///
+	/// `sealy::Plaintext::from_hex_string` hands `hex_str` to SEAL through a `CString`, and
+	/// panics if it contains an embedded null byte. This binding checks for that case itself
+	/// first, so a malformed string from Python surfaces as a catchable `ValueError` instead
+	/// of aborting the interpreter.
 	#[staticmethod]
 	pub fn from_hex_string(hex_str: &str) -> PyResult<Self> {
+		CString::new(hex_str).map_err(|_| {
+			PyValueError::new_err("hex string must not contain an embedded null byte")
+		})?;
+
 		let plaintext = sealy::Plaintext::from_hex_string(hex_str).map_err(|e| {
 			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
 				"Failed to create plaintext with hex string: {:?}",
@@ -82,9 +101,16 @@ impl PyPlaintext {
 		})?;
 		Ok(Self {
 			inner: plaintext,
+			context: None,
 		})
 	}
 
+	/// Returns this plaintext's polynomial as a hexadecimal string, in the same format
+	/// accepted by `from_hex_string`.
+	pub fn to_hex_string(&self) -> String {
+		self.inner.to_hex_string()
+	}
+
 	/// Gets the coefficient at the given location. Coefficients are ordered
 	/// from lowest to highest degree, with the first value being the constant
 	/// coefficient.
@@ -124,10 +150,96 @@ impl PyPlaintext {
 		self.inner.is_ntt_form()
 	}
 
+	/// Saves the plaintext to a file at the given path, prefixed with a small header
+	/// identifying the encryption parameters it was produced under.
+	pub fn save(
+		&self,
+		context: &PyContext,
+		path: &str,
+	) -> PyResult<()> {
+		let bytes = self.inner.to_bytes_versioned(&context.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize plaintext: {:?}",
+				e
+			))
+		})?;
+
+		std::fs::write(path, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+				"Failed to write plaintext to {}: {}",
+				path, e
+			))
+		})
+	}
+
+	/// Loads a plaintext previously written by `save`, rejecting it if it was produced
+	/// under encryption parameters incompatible with `context`.
+	#[staticmethod]
+	pub fn load(
+		py: Python<'_>,
+		context: Py<PyContext>,
+		path: &str,
+	) -> PyResult<Self> {
+		let bytes = std::fs::read(path).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+				"Failed to read plaintext from {}: {}",
+				path, e
+			))
+		})?;
+
+		let plaintext = {
+			let ctx_ref = context.borrow(py);
+			sealy::Plaintext::from_bytes_versioned(&ctx_ref.inner, &bytes).map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to load plaintext: {:?}",
+					e
+				))
+			})?
+		};
+
+		Ok(Self {
+			inner: plaintext,
+			context: Some(context),
+		})
+	}
+
 	fn __len__(&self) -> usize {
 		self.size()
 	}
 
+	/// Gets the coefficient at the given index, equivalent to `get_coefficient`.
+	fn __getitem__(
+		&self,
+		index: usize,
+	) -> PyResult<u64> {
+		if index >= self.size() {
+			return Err(PyIndexError::new_err(format!(
+				"plaintext coefficient index {} out of range",
+				index
+			)));
+		}
+
+		Ok(self.get_coefficient(index))
+	}
+
+	/// Sets the coefficient at the given index, equivalent to `set_coefficient`.
+	fn __setitem__(
+		&mut self,
+		index: usize,
+		value: u64,
+	) -> PyResult<()> {
+		if index >= self.size() {
+			return Err(PyIndexError::new_err(format!(
+				"plaintext coefficient index {} out of range",
+				index
+			)));
+		}
+
+		self.set_coefficient(index, value);
+
+		Ok(())
+	}
+
 	fn __eq__(
 		&self,
 		other: &PyPlaintext,
@@ -135,6 +247,12 @@ impl PyPlaintext {
 		self.inner == other.inner
 	}
 
+	fn __hash__(&self) -> u64 {
+		let mut hasher = std::collections::hash_map::DefaultHasher::new();
+		self.inner.hash(&mut hasher);
+		hasher.finish()
+	}
+
 	fn __str__(&self) -> String {
 		format!("{:?}", self.inner)
 	}
@@ -142,4 +260,25 @@ impl PyPlaintext {
 	fn __repr__(&self) -> String {
 		format!("{:?}", self.inner)
 	}
+
+	/// Supports `pickle`/`copy.deepcopy` the same way as `PyPublicKey::__reduce__`; see its docs.
+	/// Only works for a plaintext that was itself built via `Plaintext.from_bytes(...)` or
+	/// `Plaintext.load(...)`, since that's the only time this binding has a `Context` to hand
+	/// back; a plaintext constructed via the bare `Plaintext()`/`with_pool`/`from_hex_string`
+	/// constructors has none to offer.
+	fn __reduce__(&self, py: Python<'_>) -> PyResult<(PyObject, (Py<PyContext>, Vec<u8>))> {
+		let context = self.context.clone().ok_or_else(|| {
+			PyValueError::new_err(
+				"cannot pickle a Plaintext that wasn't built via Plaintext.from_bytes(...) or \
+				 Plaintext.load(...), since its originating Context isn't retained",
+			)
+		})?;
+		let bytes = self.as_bytes()?;
+		let ctor = py
+			.get_type::<PyPlaintext>()
+			.getattr("from_bytes")?
+			.unbind();
+
+		Ok((ctor, (context, bytes)))
+	}
 }
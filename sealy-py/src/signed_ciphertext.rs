@@ -0,0 +1,212 @@
+use pyo3::prelude::*;
+
+use crate::ciphertext::PyCiphertext;
+use crate::context::PyContext;
+use crate::tensor::PyCiphertextTensor;
+
+/// An Ed25519 keypair used to sign and verify `SignedCiphertext`/`SignedCiphertextBatch`
+/// containers.
+#[pyclass(module = "sealy", name = "Ed25519KeyPair")]
+pub struct PyEd25519KeyPair {
+	inner: sealy::Ed25519KeyPair,
+}
+
+#[pymethods]
+impl PyEd25519KeyPair {
+	/// Generates a fresh keypair from the OS RNG.
+	#[staticmethod]
+	pub fn generate() -> Self {
+		Self {
+			inner: sealy::Ed25519KeyPair::generate(),
+		}
+	}
+
+	/// Reconstructs a keypair from a 32-byte seed.
+	#[staticmethod]
+	pub fn from_seed(seed: [u8; 32]) -> Self {
+		Self {
+			inner: sealy::Ed25519KeyPair::from_seed(&seed),
+		}
+	}
+
+	/// Returns the public half of this keypair, safe to share with whoever needs to verify
+	/// ciphertexts signed by it.
+	pub fn public_key(&self) -> PyEd25519PublicKey {
+		PyEd25519PublicKey {
+			inner: self.inner.public_key(),
+		}
+	}
+}
+
+/// The public half of an [`PyEd25519KeyPair`], used to verify a signed ciphertext without
+/// holding the private signing key.
+#[derive(Clone)]
+#[pyclass(module = "sealy", name = "Ed25519PublicKey")]
+pub struct PyEd25519PublicKey {
+	inner: sealy::Ed25519PublicKey,
+}
+
+#[pymethods]
+impl PyEd25519PublicKey {
+	/// Reconstructs a public key from its 32-byte encoding.
+	#[staticmethod]
+	pub fn from_bytes(bytes: [u8; 32]) -> PyResult<Self> {
+		let inner = sealy::Ed25519PublicKey::from_bytes(&bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to parse Ed25519 public key: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+
+	/// Returns the 32-byte encoding of this public key.
+	pub fn as_bytes(&self) -> [u8; 32] {
+		self.inner.as_bytes()
+	}
+}
+
+/// A ciphertext bound to a detached Ed25519 signature, so a recipient can verify it was
+/// produced by a trusted party before spending CPU on homomorphic evaluation.
+#[pyclass(module = "sealy", name = "SignedCiphertext")]
+pub struct PySignedCiphertext {
+	inner: sealy::SignedCiphertext,
+}
+
+#[pymethods]
+impl PySignedCiphertext {
+	/// Signs `ciphertext` under `keypair`.
+	#[staticmethod]
+	pub fn sign(
+		keypair: &PyEd25519KeyPair,
+		ciphertext: &PyCiphertext,
+	) -> PyResult<Self> {
+		let inner = sealy::SignedCiphertext::sign(&keypair.inner, &ciphertext.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to sign ciphertext: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+
+	/// Verifies this container's signature under `public_key` and, once it checks out,
+	/// deserializes the ciphertext under `context`.
+	pub fn verify(
+		&self,
+		public_key: &PyEd25519PublicKey,
+		context: &PyContext,
+	) -> PyResult<PyCiphertext> {
+		let inner = self
+			.inner
+			.verify(&public_key.inner, &context.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to verify signed ciphertext: {:?}",
+					e
+				))
+			})?;
+
+		Ok(PyCiphertext {
+			inner,
+		})
+	}
+
+	/// Serializes this container into a single transportable blob.
+	pub fn as_bytes(&self) -> Vec<u8> {
+		self.inner.as_bytes()
+	}
+
+	/// Deserializes a blob produced by `as_bytes`, without verifying it — call `verify` on the
+	/// result before trusting its contents.
+	#[staticmethod]
+	pub fn from_bytes(data: Vec<u8>) -> PyResult<Self> {
+		let inner = sealy::SignedCiphertext::from_bytes(&data).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to parse signed ciphertext: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+}
+
+/// The batched counterpart to [`PySignedCiphertext`]: a whole [`PyCiphertextTensor`], signed as
+/// a single message.
+#[pyclass(module = "sealy", name = "SignedCiphertextBatchArray")]
+pub struct PySignedCiphertextBatchArray {
+	inner: sealy::SignedCiphertextBatch,
+}
+
+#[pymethods]
+impl PySignedCiphertextBatchArray {
+	/// Signs every ciphertext in `batch` under `keypair`, as a single message.
+	#[staticmethod]
+	pub fn sign(
+		keypair: &PyEd25519KeyPair,
+		batch: &PyCiphertextTensor,
+	) -> PyResult<Self> {
+		let inner = sealy::SignedCiphertextBatch::sign(&keypair.inner, &batch.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to sign ciphertext batch: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+
+	/// Verifies this container's signature under `public_key` and, once it checks out,
+	/// deserializes the batch under `context`.
+	pub fn verify(
+		&self,
+		public_key: &PyEd25519PublicKey,
+		context: &PyContext,
+	) -> PyResult<PyCiphertextTensor> {
+		let inner = self
+			.inner
+			.verify(&public_key.inner, &context.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to verify signed ciphertext batch: {:?}",
+					e
+				))
+			})?;
+
+		Ok(PyCiphertextTensor {
+			inner,
+		})
+	}
+
+	/// Serializes this container into a single transportable blob.
+	pub fn as_bytes(&self) -> Vec<u8> {
+		self.inner.as_bytes()
+	}
+
+	/// Deserializes a blob produced by `as_bytes`, without verifying it — call `verify` on the
+	/// result before trusting its contents.
+	#[staticmethod]
+	pub fn from_bytes(data: Vec<u8>) -> PyResult<Self> {
+		let inner = sealy::SignedCiphertextBatch::from_bytes(&data).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to parse signed ciphertext batch: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+}
@@ -1,17 +1,17 @@
 use crate::{
 	ciphertext::PyCiphertext,
 	context::PyContext,
-	keys::{PyPublicKey, PyRelinearizationKey, PySecretKey},
+	keys::{PyGaloisKey, PyPublicKey, PyRelinearizationKey, PySecretKey},
 	plaintext::PyPlaintext,
 	PyCKKSEvaluator,
 };
 use pyo3::prelude::*;
-use sealy::{Evaluator, FromChunk, ToChunk};
+use sealy::{Evaluator, FromChunk, ProtoChunks, ToChunk, VersionedChunks};
 
 #[derive(Debug, Clone)]
 #[pyclass(module = "sealy", name = "PlaintextTensor")]
 pub struct PyPlaintextTensor {
-	inner: sealy::Tensor<sealy::Plaintext>,
+	pub(crate) inner: sealy::Tensor<sealy::Plaintext>,
 }
 
 #[pymethods]
@@ -24,12 +24,130 @@ impl PyPlaintextTensor {
 			inner: batch,
 		})
 	}
+
+	/// Saves the tensor to a file at the given path, prefixed with a small header
+	/// identifying the encryption parameters each plaintext was produced under.
+	pub fn save(
+		&self,
+		ctx: &PyContext,
+		path: &str,
+	) -> PyResult<()> {
+		let bytes = self.inner.to_bytes_versioned(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize plaintext tensor: {:?}",
+				e
+			))
+		})?;
+
+		std::fs::write(path, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+				"Failed to write plaintext tensor to {}: {}",
+				path, e
+			))
+		})
+	}
+
+	/// Loads a tensor previously written by `save`, rejecting it if any plaintext was
+	/// produced under encryption parameters incompatible with `ctx`.
+	#[staticmethod]
+	pub fn load(
+		ctx: &PyContext,
+		path: &str,
+	) -> PyResult<Self> {
+		let bytes = std::fs::read(path).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+				"Failed to read plaintext tensor from {}: {}",
+				path, e
+			))
+		})?;
+
+		let inner = sealy::Tensor::from_bytes_versioned(&ctx.inner, &bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to load plaintext tensor: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+
+	/// Serializes the tensor into a single self-describing byte string: a small header
+	/// (magic bytes, scheme, and parameter fingerprint) followed by each plaintext as a
+	/// length-prefixed, header-tagged chunk. Unlike `save`, this returns the bytes directly
+	/// so callers can hand the whole tensor to any byte-oriented sink (a file opened by the
+	/// caller, a Redis value, an HTTP body) instead of a path on disk.
+	pub fn to_framed_bytes(
+		&self,
+		ctx: &PyContext,
+	) -> PyResult<Vec<u8>> {
+		self.inner.to_bytes_versioned(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize plaintext tensor: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Deserializes a tensor previously produced by `to_framed_bytes`, rejecting it if any
+	/// plaintext was produced under encryption parameters incompatible with `ctx`.
+	#[staticmethod]
+	pub fn from_framed_bytes(
+		ctx: &PyContext,
+		bytes: &[u8],
+	) -> PyResult<Self> {
+		let inner = sealy::Tensor::from_bytes_versioned(&ctx.inner, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to deserialize plaintext tensor: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+
+	/// Serializes the tensor into a single protobuf-encoded message carrying a header
+	/// (scheme, polynomial modulus degree, coefficient modulus size, NTT-form flag, element
+	/// count) followed by the raw plaintext bytes, for interop with non-Python consumers.
+	pub fn to_proto_bytes(
+		&self,
+		ctx: &PyContext,
+	) -> PyResult<Vec<u8>> {
+		self.inner.to_proto_bytes(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize plaintext tensor to protobuf: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Deserializes a tensor previously produced by `to_proto_bytes`, rejecting it if its
+	/// header is incompatible with `ctx`.
+	#[staticmethod]
+	pub fn from_proto_bytes(
+		ctx: &PyContext,
+		bytes: &[u8],
+	) -> PyResult<Self> {
+		let inner = sealy::Tensor::from_proto_bytes(&ctx.inner, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to deserialize plaintext tensor from protobuf: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
 }
 
 #[derive(Debug, Clone)]
 #[pyclass(module = "sealy", name = "CiphertextTensor")]
 pub struct PyCiphertextTensor {
-	inner: sealy::Tensor<sealy::Ciphertext>,
+	pub(crate) inner: sealy::Tensor<sealy::Ciphertext>,
 }
 
 #[pymethods]
@@ -54,6 +172,25 @@ impl PyCiphertextTensor {
 		Ok(bytes)
 	}
 
+	/// Converts the batch array to a list of byte arrays, each ciphertext compressed with the
+	/// given codec instead of the library's default. Useful when shipping a whole batch over
+	/// the network, where the compression/latency tradeoff matters per call.
+	pub fn to_bytes_chunk_with_compression(
+		&self,
+		compression: &crate::parameters::PyCompressionType,
+	) -> PyResult<Vec<Vec<u8>>> {
+		let bytes = self
+			.inner
+			.to_chunk_with_compression(compression.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to get ciphertext batch as bytes: {:?}",
+					e
+				))
+			})?;
+		Ok(bytes)
+	}
+
 	/// Creates a new ciphertext batch array from a list of byte arrays.
 	#[staticmethod]
 	pub fn from_bytes_chunk(
@@ -70,12 +207,161 @@ impl PyCiphertextTensor {
 			inner: batch,
 		})
 	}
+
+	/// Returns the scale of the tensor's first ciphertext, as set by a CKKS encoder at creation
+	/// time and updated by any subsequent multiply/square/rescale. Every element of a tensor
+	/// produced by this crate's CKKS tensor operations shares the same scale, so the first
+	/// element stands in for the whole tensor. Meaningless for BFV ciphertexts.
+	pub fn get_scale(&self) -> PyResult<f64> {
+		let first = self.inner.first().ok_or_else(|| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+				"Cannot get the scale of an empty ciphertext tensor".to_string(),
+			)
+		})?;
+		Ok(first.scale())
+	}
+
+	/// Returns the parms ID of the tensor's first ciphertext, identifying its current position
+	/// in the modulus switching chain. Every element of a tensor produced by this crate's CKKS
+	/// tensor operations shares the same parms ID, so the first element stands in for the whole
+	/// tensor.
+	pub fn get_parms_id(&self) -> PyResult<Vec<u64>> {
+		let first = self.inner.first().ok_or_else(|| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+				"Cannot get the parms_id of an empty ciphertext tensor".to_string(),
+			)
+		})?;
+		first.parms_id().map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to get ciphertext tensor parms_id: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Serializes the tensor into a single self-describing byte string: a small header
+	/// (magic bytes, scheme, and parameter fingerprint) followed by each ciphertext as a
+	/// length-prefixed, header-tagged chunk. Unlike `save`, this returns the bytes directly
+	/// so callers can hand the whole tensor to any byte-oriented sink (a file opened by the
+	/// caller, a Redis value, an HTTP body) instead of a path on disk.
+	pub fn to_framed_bytes(
+		&self,
+		ctx: &PyContext,
+	) -> PyResult<Vec<u8>> {
+		self.inner.to_bytes_versioned(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize ciphertext tensor: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Deserializes a tensor previously produced by `to_framed_bytes`, rejecting it if any
+	/// ciphertext was produced under encryption parameters incompatible with `ctx`.
+	#[staticmethod]
+	pub fn from_framed_bytes(
+		ctx: &PyContext,
+		bytes: &[u8],
+	) -> PyResult<Self> {
+		let inner = sealy::Tensor::from_bytes_versioned(&ctx.inner, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to deserialize ciphertext tensor: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+
+	/// Serializes the tensor into a single protobuf-encoded message carrying a header
+	/// (scheme, polynomial modulus degree, coefficient modulus size, NTT-form flag, element
+	/// count) followed by the raw ciphertext bytes, for interop with non-Python consumers.
+	pub fn to_proto_bytes(
+		&self,
+		ctx: &PyContext,
+	) -> PyResult<Vec<u8>> {
+		self.inner.to_proto_bytes(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize ciphertext tensor to protobuf: {:?}",
+				e
+			))
+		})
+	}
+
+	/// Deserializes a tensor previously produced by `to_proto_bytes`, rejecting it if its
+	/// header is incompatible with `ctx`.
+	#[staticmethod]
+	pub fn from_proto_bytes(
+		ctx: &PyContext,
+		bytes: &[u8],
+	) -> PyResult<Self> {
+		let inner = sealy::Tensor::from_proto_bytes(&ctx.inner, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to deserialize ciphertext tensor from protobuf: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
+
+	/// Saves the tensor to a file at the given path, prefixed with a small header
+	/// identifying the encryption parameters each ciphertext was produced under.
+	pub fn save(
+		&self,
+		ctx: &PyContext,
+		path: &str,
+	) -> PyResult<()> {
+		let bytes = self.inner.to_bytes_versioned(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to serialize ciphertext tensor: {:?}",
+				e
+			))
+		})?;
+
+		std::fs::write(path, bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+				"Failed to write ciphertext tensor to {}: {}",
+				path, e
+			))
+		})
+	}
+
+	/// Loads a tensor previously written by `save`, rejecting it if any ciphertext was
+	/// produced under encryption parameters incompatible with `ctx`.
+	#[staticmethod]
+	pub fn load(
+		ctx: &PyContext,
+		path: &str,
+	) -> PyResult<Self> {
+		let bytes = std::fs::read(path).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyIOError, _>(format!(
+				"Failed to read ciphertext tensor from {}: {}",
+				path, e
+			))
+		})?;
+
+		let inner = sealy::Tensor::from_bytes_versioned(&ctx.inner, &bytes).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to load ciphertext tensor: {:?}",
+				e
+			))
+		})?;
+
+		Ok(Self {
+			inner,
+		})
+	}
 }
 
 /// Encryptor that can encrypt multiple messages at once.
 #[pyclass(module = "sealy", name = "TensorEncryptor")]
 pub struct PyTensorEncryptor {
-	inner: sealy::TensorEncryptor<sealy::Asym>,
+	pub(crate) inner: sealy::TensorEncryptor<sealy::Asym>,
 }
 
 #[pymethods]
@@ -120,7 +406,7 @@ impl PyTensorEncryptor {
 /// Decrypts batches of ciphertexts.
 #[pyclass(module = "sealy", name = "TensorDecryptor")]
 pub struct PyTensorDecryptor {
-	inner: sealy::TensorDecryptor,
+	pub(crate) inner: sealy::TensorDecryptor,
 }
 
 #[pymethods]
@@ -190,6 +476,21 @@ impl PyCKKSTensorEncoder {
 		self.inner.get_slot_count()
 	}
 
+	/// Confines every `par_encode_float`/`par_decode_float` call on this encoder to a
+	/// dedicated pool of `num_threads` worker threads instead of the process-wide default. Pass
+	/// `0` to go back to the default pool.
+	pub fn set_parallelism(
+		&mut self,
+		num_threads: usize,
+	) -> PyResult<()> {
+		self.inner.set_parallelism(num_threads).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to set parallelism: {:?}",
+				e
+			))
+		})
+	}
+
 	/// Encodes the given data into a plaintext.
 	///
 	/// # Arguments
@@ -232,6 +533,191 @@ impl PyCKKSTensorEncoder {
 
 		Ok(data)
 	}
+
+	/// Encodes the given data into a plaintext, encoding each chunk of `data` on a separate
+	/// thread. See [`sealy::TensorEncoder::par_encode_f64`].
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	///
+	/// # Returns
+	/// The encoded plaintext.
+	fn par_encode_float(
+		&self,
+		data: Vec<f64>,
+	) -> PyResult<PyPlaintextTensor> {
+		let batch = self.inner.par_encode_f64(&data).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to encode batch: {:?}",
+				e
+			))
+		})?;
+		Ok(PyPlaintextTensor {
+			inner: batch,
+		})
+	}
+
+	/// Decodes the given plaintext into data, decoding each plaintext on a separate thread. See
+	/// [`sealy::TensorEncoder::par_decode_f64`].
+	///
+	/// # Arguments
+	/// * `batch` - The encoded data.
+	///
+	/// # Returns
+	/// The decoded data.
+	fn par_decode_float(
+		&self,
+		batch: PyPlaintextTensor,
+	) -> PyResult<Vec<f64>> {
+		let data = self.inner.par_decode_f64(&batch.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to decode batch: {:?}",
+				e
+			))
+		})?;
+
+		Ok(data)
+	}
+}
+
+/// An encoder that encodes batches of integer/boolean data into BFV plaintexts, and raw bytes
+/// columns passed straight through, dispatching through sealy's `Conversion`-tagged
+/// `MixedTensorEncoder` so mixed-type records can be encoded without juggling `encode_int`
+/// calls against a separate float encoder by hand.
+#[pyclass(module = "sealy", name = "BFVTensorEncoder")]
+pub struct PyBFVTensorEncoder {
+	inner: sealy::MixedTensorEncoder,
+}
+
+#[pymethods]
+impl PyBFVTensorEncoder {
+	/// Creates a new BFVTensorEncoder.
+	#[new]
+	fn new(ctx: &PyContext) -> PyResult<Self> {
+		let encoder = sealy::BFVEncoder::new(&ctx.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to create BFVEncoder: {:?}",
+				e
+			))
+		})?;
+		let inner = sealy::MixedTensorEncoder::bfv(sealy::TensorEncoder::new(encoder));
+		Ok(Self {
+			inner,
+		})
+	}
+
+	/// Returns the number of slots in this encoder produces.
+	fn get_slot_count(&self) -> usize {
+		self.inner.get_slot_count()
+	}
+
+	/// Encodes the given integers into a plaintext.
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	///
+	/// # Returns
+	/// The encoded plaintext.
+	fn encode_int(
+		&self,
+		data: Vec<i64>,
+	) -> PyResult<PyPlaintextTensor> {
+		let encoded = self
+			.inner
+			.encode(sealy::Conversion::Int, &sealy::Column::Int(data))
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to encode batch: {:?}",
+					e
+				))
+			})?;
+		match encoded {
+			sealy::EncodedColumn::Int(tensor) => Ok(PyPlaintextTensor {
+				inner: tensor,
+			}),
+			_ => unreachable!("Conversion::Int always encodes to EncodedColumn::Int"),
+		}
+	}
+
+	/// Decodes the given plaintext into integers.
+	///
+	/// # Arguments
+	/// * `batch` - The encoded data.
+	///
+	/// # Returns
+	/// The decoded data.
+	fn decode_int(
+		&self,
+		batch: PyPlaintextTensor,
+	) -> PyResult<Vec<i64>> {
+		let decoded = self
+			.inner
+			.decode(&sealy::EncodedColumn::Int(batch.inner))
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to decode batch: {:?}",
+					e
+				))
+			})?;
+		match decoded {
+			sealy::Column::Int(values) => Ok(values),
+			_ => unreachable!("EncodedColumn::Int always decodes to Column::Int"),
+		}
+	}
+
+	/// Encodes the given booleans into a plaintext.
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	///
+	/// # Returns
+	/// The encoded plaintext.
+	fn encode_bool(
+		&self,
+		data: Vec<bool>,
+	) -> PyResult<PyPlaintextTensor> {
+		let encoded = self
+			.inner
+			.encode(sealy::Conversion::Bool, &sealy::Column::Bool(data))
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to encode batch: {:?}",
+					e
+				))
+			})?;
+		match encoded {
+			sealy::EncodedColumn::Bool(tensor) => Ok(PyPlaintextTensor {
+				inner: tensor,
+			}),
+			_ => unreachable!("Conversion::Bool always encodes to EncodedColumn::Bool"),
+		}
+	}
+
+	/// Decodes the given plaintext into booleans.
+	///
+	/// # Arguments
+	/// * `batch` - The encoded data.
+	///
+	/// # Returns
+	/// The decoded data.
+	fn decode_bool(
+		&self,
+		batch: PyPlaintextTensor,
+	) -> PyResult<Vec<bool>> {
+		let decoded = self
+			.inner
+			.decode(&sealy::EncodedColumn::Bool(batch.inner))
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to decode batch: {:?}",
+					e
+				))
+			})?;
+		match decoded {
+			sealy::Column::Bool(values) => Ok(values),
+			_ => unreachable!("EncodedColumn::Bool always decodes to Column::Bool"),
+		}
+	}
 }
 
 // An evaluator that evaluates batches of data.
@@ -254,6 +740,21 @@ impl PyCKKSTensorEvaluator {
 		})
 	}
 
+	/// Confines every batch operation on this evaluator to a dedicated pool of `num_threads`
+	/// worker threads instead of the process-wide default. Pass `0` to go back to the
+	/// default pool.
+	pub fn set_parallelism(
+		&mut self,
+		num_threads: usize,
+	) -> PyResult<()> {
+		self.inner.set_parallelism(num_threads).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to set evaluator parallelism: {:?}",
+				e
+			))
+		})
+	}
+
 	/// Negates a batch of ciphertexts.
 	pub fn negate(
 		&self,
@@ -434,4 +935,130 @@ impl PyCKKSTensorEvaluator {
 			inner: relinearized,
 		})
 	}
+
+	/// Collapses the batched slots of a single ciphertext into a running total, so that after
+	/// the reduction every slot holds the sum of the original slots.
+	pub fn sum_slots(
+		&self,
+		a: &PyCiphertext,
+		galois_keys: &PyGaloisKey,
+		slot_count: usize,
+	) -> PyResult<PyCiphertext> {
+		let summed = self
+			.inner
+			.sum_slots(&a.inner, &galois_keys.inner, slot_count)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to sum slots of ciphertext: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: summed,
+		})
+	}
+
+	/// Computes the encrypted inner product of a ciphertext tensor and a plaintext tensor.
+	pub fn inner_product(
+		&self,
+		a: &PyCiphertextTensor,
+		b: &PyPlaintextTensor,
+		galois_keys: &PyGaloisKey,
+		slot_count: usize,
+	) -> PyResult<PyCiphertext> {
+		let product = self
+			.inner
+			.inner_product(&a.inner, &b.inner, &galois_keys.inner, slot_count)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to compute inner product: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertext {
+			inner: product,
+		})
+	}
+
+	/// Rescales every ciphertext in the tensor down to the next coefficient modulus in the
+	/// chain, restoring its scale to roughly what it was before the multiply that grew it.
+	pub fn rescale_to_next(&self, a: &PyCiphertextTensor) -> PyResult<PyCiphertextTensor> {
+		let rescaled = self.inner.rescale_to_next(&a.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to rescale ciphertext tensor: {:?}",
+				e
+			))
+		})?;
+		Ok(PyCiphertextTensor { inner: rescaled })
+	}
+
+	/// Switches every ciphertext in the tensor down to the next coefficient modulus in the
+	/// chain, without rescaling.
+	pub fn mod_switch_to_next(&self, a: &PyCiphertextTensor) -> PyResult<PyCiphertextTensor> {
+		let switched = self.inner.mod_switch_to_next(&a.inner).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to mod-switch ciphertext tensor: {:?}",
+				e
+			))
+		})?;
+		Ok(PyCiphertextTensor { inner: switched })
+	}
+
+	/// Switches every ciphertext in the tensor down to the coefficient modulus identified by
+	/// `parms_id`, without rescaling.
+	pub fn mod_switch_to(
+		&self,
+		a: &PyCiphertextTensor,
+		parms_id: Vec<u64>,
+	) -> PyResult<PyCiphertextTensor> {
+		let switched = self.inner.mod_switch_to(&a.inner, &parms_id).map_err(|e| {
+			PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+				"Failed to mod-switch ciphertext tensor: {:?}",
+				e
+			))
+		})?;
+		Ok(PyCiphertextTensor { inner: switched })
+	}
+
+	/// Rotates the CKKS-encoded slot vector of every ciphertext in the tensor cyclically by
+	/// `steps`.
+	pub fn rotate(
+		&self,
+		a: &PyCiphertextTensor,
+		steps: i32,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<PyCiphertextTensor> {
+		let rotated = self
+			.inner
+			.rotate(&a.inner, steps, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to rotate ciphertext tensor: {:?}",
+					e
+				))
+			})?;
+		Ok(PyCiphertextTensor { inner: rotated })
+	}
+
+	/// Rotates the tensor by every step count in `steps`, returning one rotated tensor per step.
+	pub fn rotate_many(
+		&self,
+		a: &PyCiphertextTensor,
+		steps: Vec<i32>,
+		galois_keys: &PyGaloisKey,
+	) -> PyResult<Vec<PyCiphertextTensor>> {
+		let rotated = self
+			.inner
+			.rotate_many(&a.inner, &steps, &galois_keys.inner)
+			.map_err(|e| {
+				PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+					"Failed to rotate ciphertext tensor: {:?}",
+					e
+				))
+			})?;
+		Ok(rotated
+			.into_iter()
+			.map(|inner| PyCiphertextTensor { inner })
+			.collect())
+	}
 }
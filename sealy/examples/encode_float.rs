@@ -1,8 +1,8 @@
 use rand::Rng;
 use sealy::{
 	CKKSEncoder, CKKSEncryptionParametersBuilder, CoefficientModulusFactory, Context, DegreeType,
-	EncryptionParameters, Error, KeyGenerator, SecurityLevel, TensorDecryptor, TensorEncoder,
-	TensorEncryptor,
+	EncryptionParameters, Error, KeyGenerator, MemoryPool, SecurityLevel, TensorDecryptor,
+	TensorEncoder, TensorEncryptor,
 };
 
 fn generate_random_tensor(size: usize) -> Vec<f64> {
@@ -52,15 +52,22 @@ fn main() -> Result<(), Error> {
 
 	for i in 0..rounds {
 		println!("Start Round: {}", i);
+
+		// A fresh pool per round bounds this round's scratch allocations and reclaims them
+		// the moment the pool is dropped, instead of growing SEAL's global pool by one more
+		// round's worth of encode/encrypt/decode scratch space forever.
+		let pool = MemoryPool::new()?;
+
 		println!("Encoding tensor...");
-		let encoded = encoder.encode_f64(&tensor)?;
+		let encoded = encoder.encode_f64_with_pool(&tensor, &pool)?;
 		println!("Encrypting tensor...");
-		let encrypted = encryptor.encrypt(&encoded)?;
+		let encrypted = encryptor.encrypt_with_pool(&encoded, &pool)?;
 		println!("Decrypting tensor...");
 		let decrypted = decryptor.decrypt(&encrypted)?;
 		println!("Decoding tensor...");
-		let decoded = encoder.decode_f64(&decrypted)?;
+		let decoded = encoder.decode_f64_with_pool(&decrypted, &pool)?;
 		std::mem::drop(decoded);
+		std::mem::drop(pool);
 		println!("==================");
 		std::thread::sleep(delay);
 	}
@@ -0,0 +1,345 @@
+use crate::{error::*, CKKSEncoder, CKKSEvaluator, Ciphertext, Evaluator, Plaintext, Tensor, TensorEvaluator};
+
+/// Homomorphically accumulates a stream of clients' encrypted updates into a single running
+/// sum, releasing the encrypted average only once a configurable minimum number of clients has
+/// contributed.
+///
+/// This is the aggregator side of a secure-aggregation protocol for federated learning: each
+/// client encrypts its own update (e.g. model gradients, packed into CKKS slots) and the
+/// coordinator folds updates in one at a time via [`CKKSEvaluator::add_many`] as they arrive.
+/// Because the running sum is always a single ciphertext, the coordinator never buffers every
+/// client's contribution at once and never decrypts anything itself — only [`finalize`] hands
+/// back a result, and only once [`min_clients`] contributions have been folded in. The `1/n`
+/// averaging step also happens under encryption via `multiply_plain`, so clients only ever
+/// receive the finished average, never the raw sum.
+///
+/// [`finalize`]: SecureAggregator::finalize
+/// [`min_clients`]: SecureAggregator::min_clients
+pub struct SecureAggregator {
+	min_clients: usize,
+	contributors: usize,
+	running_sum: Option<Ciphertext>,
+}
+
+impl SecureAggregator {
+	/// Starts a new aggregation round that releases its sum only once `min_clients` updates
+	/// have been folded in via [`accumulate`](SecureAggregator::accumulate).
+	pub fn new(min_clients: usize) -> Result<Self> {
+		if min_clients == 0 {
+			return Err(Error::InvalidArgument);
+		}
+
+		Ok(Self {
+			min_clients,
+			contributors: 0,
+			running_sum: None,
+		})
+	}
+
+	/// Returns the minimum number of clients that must contribute before [`finalize`] will
+	/// release the aggregate.
+	///
+	/// [`finalize`]: SecureAggregator::finalize
+	pub fn min_clients(&self) -> usize {
+		self.min_clients
+	}
+
+	/// Returns how many client updates have been folded into the running sum so far.
+	pub fn contributors(&self) -> usize {
+		self.contributors
+	}
+
+	/// Folds one more client's encrypted update into the running sum.
+	///
+	/// `update` is consumed by `evaluator.add_many` immediately; this type never retains more
+	/// than the single accumulated ciphertext between calls.
+	pub fn accumulate(
+		&mut self,
+		evaluator: &CKKSEvaluator,
+		update: &Ciphertext,
+	) -> Result<()> {
+		self.running_sum = Some(match self.running_sum.take() {
+			Some(sum) => evaluator.add_many(&[sum, update.clone()])?,
+			None => update.clone(),
+		});
+		self.contributors += 1;
+
+		Ok(())
+	}
+
+	/// Returns the encrypted average of every update folded in so far, scaling the running sum
+	/// by `1 / contributors` under encryption via `multiply_plain` so the plaintext average is
+	/// never computed outside the ciphertext domain.
+	///
+	/// * `evaluator` - the evaluator used to scale the running sum.
+	/// * `encoder` - used to encode the `1/n` scaling factor; must share scale and slot count
+	///   with the client updates that were folded in.
+	/// * `slot_count` - the number of CKKS slots to fill with the scaling factor.
+	///
+	/// Returns [`Error::QuorumNotReached`] if fewer than [`min_clients`](SecureAggregator::min_clients)
+	/// updates have been accumulated yet.
+	pub fn finalize(
+		&self,
+		evaluator: &CKKSEvaluator,
+		encoder: &CKKSEncoder,
+		slot_count: usize,
+	) -> Result<Ciphertext> {
+		if self.contributors < self.min_clients {
+			return Err(Error::QuorumNotReached);
+		}
+
+		// `contributors >= min_clients >= 1` here, so `running_sum` is always populated.
+		let sum = self
+			.running_sum
+			.as_ref()
+			.expect("Internal error: quorum reached with no accumulated updates.");
+
+		let fraction = vec![1.0 / self.contributors as f64; slot_count];
+		let fraction = encoder.encode_f64(&fraction)?;
+
+		evaluator.multiply_plain(sum, &fraction)
+	}
+}
+
+/// The aggregator side of a secure-aggregation protocol, generalized over whatever a client's
+/// update is packed into: this crate's [`TensorAggregator`] folds in whole
+/// [`Tensor<Ciphertext>`]s (one ciphertext per chunk of a client's flattened update, however many
+/// chunks that takes), rather than [`SecureAggregator`]'s single ciphertext, so a single update
+/// too large for one ciphertext's slot count can still be aggregated as a unit.
+///
+/// Implementations never decrypt anything and never buffer more than one running sum, mirroring
+/// [`SecureAggregator`]'s no-buffering guarantee.
+pub trait FheAggregator {
+	/// Returns the minimum number of clients that must contribute before [`finalize`] will
+	/// release the aggregate.
+	///
+	/// [`finalize`]: FheAggregator::finalize
+	fn min_clients(&self) -> usize;
+
+	/// Returns how many client updates have been folded into the running sum so far.
+	fn contributors(&self) -> usize;
+
+	/// Folds one more client's encrypted update into the running sum.
+	fn accumulate(
+		&mut self,
+		update: &Tensor<Ciphertext>,
+	) -> Result<()>;
+
+	/// Returns the encrypted elementwise average of every update folded in so far, scaling the
+	/// running sum by `fraction` (the caller-encoded `1 / contributors` factor, one plaintext per
+	/// chunk) under encryption via `multiply_plain`.
+	///
+	/// Returns [`Error::QuorumNotReached`] if fewer than [`min_clients`](FheAggregator::min_clients)
+	/// updates have been accumulated yet.
+	fn finalize(
+		&self,
+		fraction: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>>;
+}
+
+/// A [`FheAggregator`] built around a [`TensorEvaluator<E>`], so the same implementation serves
+/// both BFV and CKKS: `E` is typically [`crate::BFVEvaluator`] or [`CKKSEvaluator`], and every
+/// client update is a [`Tensor<Ciphertext>`] whose elements this accumulates via
+/// [`TensorEvaluator::add_many`].
+pub struct TensorAggregator<E> {
+	evaluator: TensorEvaluator<E>,
+	min_clients: usize,
+	contributors: usize,
+	running_sum: Option<Tensor<Ciphertext>>,
+}
+
+impl<E> TensorAggregator<E>
+where
+	E: Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+{
+	/// Starts a new aggregation round that releases its sum only once `min_clients` updates
+	/// have been folded in via [`FheAggregator::accumulate`].
+	pub fn new(
+		evaluator: E,
+		min_clients: usize,
+	) -> Result<Self> {
+		if min_clients == 0 {
+			return Err(Error::InvalidArgument);
+		}
+
+		Ok(Self {
+			evaluator: TensorEvaluator::new(evaluator),
+			min_clients,
+			contributors: 0,
+			running_sum: None,
+		})
+	}
+}
+
+impl<E> FheAggregator for TensorAggregator<E>
+where
+	E: Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+{
+	fn min_clients(&self) -> usize {
+		self.min_clients
+	}
+
+	fn contributors(&self) -> usize {
+		self.contributors
+	}
+
+	fn accumulate(
+		&mut self,
+		update: &Tensor<Ciphertext>,
+	) -> Result<()> {
+		self.running_sum = Some(match self.running_sum.take() {
+			Some(sum) => self.evaluator.add_many(&[sum, update.clone()])?,
+			None => update.clone(),
+		});
+		self.contributors += 1;
+
+		Ok(())
+	}
+
+	fn finalize(
+		&self,
+		fraction: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		if self.contributors < self.min_clients {
+			return Err(Error::QuorumNotReached);
+		}
+
+		// `contributors >= min_clients >= 1` here, so `running_sum` is always populated.
+		let sum = self
+			.running_sum
+			.as_ref()
+			.expect("Internal error: quorum reached with no accumulated updates.");
+
+		self.evaluator.multiply_plain(sum, fraction)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::ckks_ctx()
+	}
+
+	fn mk_ciphertext(
+		encryptor: &SymAsymEncryptor,
+		encoder: &CKKSEncoder,
+		value: f64,
+		slot_count: usize,
+	) -> Ciphertext {
+		let data = vec![value; slot_count];
+		let plaintext = encoder.encode_f64(&data).unwrap();
+
+		encryptor.encrypt(&plaintext).unwrap()
+	}
+
+	#[test]
+	fn rejects_a_zero_min_clients() {
+		assert!(matches!(
+			SecureAggregator::new(0),
+			Err(Error::InvalidArgument)
+		));
+	}
+
+	#[test]
+	fn finalize_reports_quorum_not_reached_before_enough_contributors() {
+		let ctx = mk_ctx();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = Encryptor::with_public_and_secret_key(
+			&ctx,
+			&key_gen.create_public_key(),
+			&key_gen.secret_key(),
+		)
+		.unwrap();
+
+		let mut aggregator = SecureAggregator::new(2).unwrap();
+		let update = mk_ciphertext(&encryptor, &encoder, 1.0, 10);
+		aggregator.accumulate(&evaluator, &update).unwrap();
+
+		assert!(matches!(
+			aggregator.finalize(&evaluator, &encoder, 10),
+			Err(Error::QuorumNotReached)
+		));
+	}
+
+	#[test]
+	fn averages_client_updates_once_quorum_is_reached() {
+		let ctx = mk_ctx();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let decryptor = Decryptor::new(&ctx, &key_gen.secret_key()).unwrap();
+		let encryptor = Encryptor::with_public_and_secret_key(
+			&ctx,
+			&key_gen.create_public_key(),
+			&key_gen.secret_key(),
+		)
+		.unwrap();
+
+		let mut aggregator = SecureAggregator::new(3).unwrap();
+		for value in [1.0, 2.0, 3.0] {
+			let update = mk_ciphertext(&encryptor, &encoder, value, 10);
+			aggregator.accumulate(&evaluator, &update).unwrap();
+		}
+
+		let average = aggregator.finalize(&evaluator, &encoder, 10).unwrap();
+		let decoded = encoder.decode_f64(&decryptor.decrypt(&average).unwrap()).unwrap();
+
+		for slot in decoded.iter().take(10) {
+			assert!((slot - 2.0).abs() < 1e-2);
+		}
+	}
+
+	#[test]
+	fn tensor_aggregator_rejects_a_zero_min_clients() {
+		let ctx = mk_ctx();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		assert!(matches!(
+			TensorAggregator::new(evaluator, 0),
+			Err(Error::InvalidArgument)
+		));
+	}
+
+	#[test]
+	fn tensor_aggregator_averages_client_updates_once_quorum_is_reached() {
+		let ctx = mk_ctx();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let decryptor = Decryptor::new(&ctx, &key_gen.secret_key()).unwrap();
+		let encryptor = Encryptor::with_public_and_secret_key(
+			&ctx,
+			&key_gen.create_public_key(),
+			&key_gen.secret_key(),
+		)
+		.unwrap();
+
+		let slot_count = 10;
+		let mut aggregator = TensorAggregator::new(evaluator, 3).unwrap();
+
+		for value in [1.0, 2.0, 3.0] {
+			let ciphertext = mk_ciphertext(&encryptor, &encoder, value, slot_count);
+			aggregator.accumulate(&Tensor(vec![ciphertext])).unwrap();
+		}
+
+		assert_eq!(aggregator.contributors(), 3);
+
+		let fraction = encoder.encode_f64(&vec![1.0 / 3.0; slot_count]).unwrap();
+		let average = aggregator.finalize(&Tensor(vec![fraction])).unwrap();
+		let decoded = encoder
+			.decode_f64(&decryptor.decrypt(&average.0[0]).unwrap())
+			.unwrap();
+
+		for slot in decoded.iter().take(slot_count) {
+			assert!((slot - 2.0).abs() < 1e-2);
+		}
+	}
+}
@@ -0,0 +1,286 @@
+use std::ffi::c_void;
+use std::fmt::Debug;
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use serde::ser::Error;
+use serde::{Serialize, Serializer};
+
+use crate::{bindgen, serialization::CompressionType, Context, FromBytes, ToBytes};
+use crate::{error::*, try_seal};
+
+/// Class to store a ciphertext element. The data for a ciphertext consists
+/// of two or more polynomials, which are the product of encryption and
+/// any following operations performed on the ciphertext. The number of
+/// polynomials in a given ciphertext is called its `size`, which is stored
+/// in the `Ciphertext` object, and can be obtained using `len()`. A fresh
+/// ciphertext always has size 2.
+pub struct Ciphertext {
+	handle: AtomicPtr<c_void>,
+}
+
+unsafe impl Sync for Ciphertext {}
+unsafe impl Send for Ciphertext {}
+
+impl Ciphertext {
+	/// Constructs an empty ciphertext allocating no memory.
+	pub fn new() -> Result<Self> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::Ciphertext_Create1(null_mut(), &mut handle) })?;
+
+		Ok(Self {
+			handle: AtomicPtr::new(handle),
+		})
+	}
+
+	/// Returns the handle to the underlying SEAL object.
+	pub(crate) unsafe fn get_handle(&self) -> *mut c_void {
+		self.handle.load(Ordering::SeqCst)
+	}
+
+	/// Returns the number of polynomials (the size) of this ciphertext.
+	pub fn len(&self) -> usize {
+		let mut size: u64 = 0;
+
+		try_seal!(unsafe { bindgen::Ciphertext_Size(self.get_handle(), &mut size) })
+			.expect("Fatal error in Ciphertext::len().");
+
+		size as usize
+	}
+
+	/// Returns `true` if the ciphertext has no polynomials.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the number of primes in the coefficient modulus of the associated encryption
+	/// parameters. This directly affects the allocation size of the ciphertext.
+	pub fn coeff_modulus_size(&self) -> usize {
+		let mut size: u64 = 0;
+
+		try_seal!(unsafe {
+			bindgen::Ciphertext_CoeffModulusSize(self.get_handle(), &mut size)
+		})
+		.expect("Fatal error in Ciphertext::coeff_modulus_size().");
+
+		size as usize
+	}
+
+	/// Returns the scale of this ciphertext, as set by a CKKS encoder at creation time and
+	/// updated by any subsequent `multiply`/`square`/rescale operation. Meaningless for BFV
+	/// ciphertexts.
+	pub fn scale(&self) -> f64 {
+		let mut scale: f64 = 0.0;
+
+		try_seal!(unsafe { bindgen::Ciphertext_Scale(self.get_handle(), &mut scale) })
+			.expect("Fatal error in Ciphertext::scale().");
+
+		scale
+	}
+
+	/// Returns whether the ciphertext is in NTT form. Fresh BFV/BGV ciphertexts start in the
+	/// default (non-NTT) form; [`crate::Evaluator::transform_to_ntt`]/
+	/// [`crate::Evaluator::transform_from_ntt`] move a ciphertext between the two.
+	/// [`crate::Decryptor::decrypt`] requires its input in the default form.
+	pub fn is_ntt_form(&self) -> bool {
+		let mut result = false;
+
+		try_seal!(unsafe { bindgen::Ciphertext_IsNTTForm(self.get_handle(), &mut result) })
+			.expect("Fatal error in Ciphertext::is_ntt_form().");
+
+		result
+	}
+
+	/// Returns the parms_id of the encryption parameters this ciphertext is currently
+	/// associated with, i.e. its position in the modulus switching chain.
+	pub fn parms_id(&self) -> Result<Vec<u64>> {
+		let mut parms_id: Vec<u64> = vec![0; 4];
+
+		try_seal!(unsafe {
+			bindgen::Ciphertext_GetParmsId(self.get_handle(), parms_id.as_mut_ptr())
+		})?;
+
+		Ok(parms_id)
+	}
+
+	/// Returns the number of bytes [`ToBytes::to_bytes_with_compression`] would produce for
+	/// this ciphertext under the given `compression` codec, without actually serializing it.
+	/// Useful for pre-sizing a buffer or transport frame before a save.
+	pub fn save_size(
+		&self,
+		compression: CompressionType,
+	) -> Result<usize> {
+		crate::serialization::require_supported_compression(compression)?;
+
+		let mut num_bytes: i64 = 0;
+
+		try_seal!(unsafe {
+			bindgen::Ciphertext_SaveSize(self.get_handle(), compression as u8, &mut num_bytes)
+		})?;
+
+		Ok(num_bytes as usize)
+	}
+}
+
+impl Debug for Ciphertext {
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result {
+		f.debug_struct("Ciphertext")
+			.field("handle", &self.handle)
+			.finish()
+	}
+}
+
+impl Clone for Ciphertext {
+	fn clone(&self) -> Self {
+		let mut copy = null_mut();
+
+		try_seal!(unsafe { bindgen::Ciphertext_Create2(self.get_handle(), &mut copy) })
+			.expect("Internal error: Failed to copy ciphertext.");
+
+		Self {
+			handle: AtomicPtr::new(copy),
+		}
+	}
+}
+
+impl Serialize for Ciphertext {
+	fn serialize<S>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let bytes = self
+			.as_bytes()
+			.map_err(|e| S::Error::custom(format!("Failed to serialize bytes: {}", e)))?;
+
+		serializer.serialize_bytes(&bytes)
+	}
+}
+
+impl FromBytes for Ciphertext {
+	type State = Context;
+
+	/// Deserializes a byte stream into a ciphertext. This requires a context, which is why
+	/// Ciphertext doesn't `impl Deserialize`.
+	fn from_bytes(
+		context: &Context,
+		data: &[u8],
+	) -> Result<Self> {
+		let mut bytes_read = 0;
+
+		let ciphertext = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			// While the interface marks data as mut, SEAL doesn't actually modify it, so we're okay.
+			bindgen::Ciphertext_Load(
+				ciphertext.get_handle(),
+				context.get_handle(),
+				data.as_ptr() as *mut u8,
+				data.len() as u64,
+				&mut bytes_read,
+			)
+		})?;
+
+		Ok(ciphertext)
+	}
+}
+
+impl ToBytes for Ciphertext {
+	fn as_bytes(&self) -> Result<Vec<u8>> {
+		self.to_bytes_with_compression(CompressionType::ZStd)
+	}
+
+	fn to_bytes_with_compression(
+		&self,
+		compression: CompressionType,
+	) -> Result<Vec<u8>> {
+		crate::serialization::require_supported_compression(compression)?;
+
+		let mut num_bytes: i64 = 0;
+
+		try_seal!(unsafe {
+			bindgen::Ciphertext_SaveSize(self.get_handle(), compression as u8, &mut num_bytes)
+		})?;
+
+		let mut data: Vec<u8> = Vec::with_capacity(num_bytes as usize);
+		let mut bytes_written: i64 = 0;
+
+		try_seal!(unsafe {
+			let data_ptr = data.as_mut_ptr();
+
+			bindgen::Ciphertext_Save(
+				self.get_handle(),
+				data_ptr,
+				num_bytes as u64,
+				compression as u8,
+				&mut bytes_written,
+			)
+		})?;
+
+		unsafe { data.set_len(bytes_written as usize) };
+
+		Ok(data)
+	}
+}
+
+impl Drop for Ciphertext {
+	fn drop(&mut self) {
+		try_seal!(unsafe { bindgen::Ciphertext_Destroy(self.get_handle()) })
+			.expect("Internal error in Ciphertext::drop.");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn can_create_and_destroy_ciphertext() {
+		let ciphertext = Ciphertext::new().unwrap();
+
+		std::mem::drop(ciphertext);
+	}
+
+	#[test]
+	fn save_size_matches_the_actual_serialized_length() {
+		use crate::{
+			BFVEncryptionParametersBuilder, BFVEncoder, CoefficientModulusFactory, DegreeType,
+			Encryptor, KeyGenerator, SecurityLevel,
+		};
+
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(1234)
+			.build()
+			.unwrap();
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let public_key = gen.create_public_key();
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		for compression in [CompressionType::None, CompressionType::ZStd] {
+			if !compression.is_supported() {
+				continue;
+			}
+
+			let predicted = ciphertext.save_size(compression).unwrap();
+			let actual = ciphertext.to_bytes_with_compression(compression).unwrap().len();
+
+			assert_eq!(predicted, actual);
+		}
+	}
+}
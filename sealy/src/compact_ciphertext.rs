@@ -0,0 +1,105 @@
+use crate::{error::*, try_seal};
+use crate::{bindgen, Ciphertext, CompressionType, Context, FromBytes, ToBytes};
+
+/// A ciphertext produced with SEAL's seed-compression enabled: the polynomial that would
+/// otherwise hold random-looking key-switching noise is instead replaced by the 64-byte PRNG
+/// seed that generated it. This roughly halves the serialized size of a freshly encrypted
+/// symmetric ciphertext, at the cost that it cannot be evaluated on or decrypted directly — a
+/// receiver holding the matching [`Context`] must first call [`CompactCiphertext::expand`] to
+/// regenerate the missing polynomial from the seed.
+///
+/// This is the same trick used when wrapping a freshly generated session secret for transport
+/// in hybrid-encryption schemes: the (small) secret is encrypted asymmetrically, while the
+/// (much larger) symmetric payload stays compact in transit.
+pub struct CompactCiphertext {
+	inner: Ciphertext,
+}
+
+impl CompactCiphertext {
+	pub(crate) fn from_ciphertext(inner: Ciphertext) -> Self {
+		Self { inner }
+	}
+
+	/// Expands the seed carried by this ciphertext back into a full `Ciphertext` that can be
+	/// used in homomorphic evaluation or decryption.
+	///
+	/// * `context` - The context the ciphertext was encrypted under.
+	pub fn expand(
+		&self,
+		context: &Context,
+	) -> Result<Ciphertext> {
+		let expanded = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Ciphertext_ExpandSeed(
+				self.inner.get_handle(),
+				context.get_handle(),
+				expanded.get_handle(),
+			)
+		})?;
+
+		Ok(expanded)
+	}
+}
+
+impl ToBytes for CompactCiphertext {
+	fn as_bytes(&self) -> Result<Vec<u8>> {
+		self.inner.as_bytes()
+	}
+
+	fn to_bytes_with_compression(
+		&self,
+		compression: CompressionType,
+	) -> Result<Vec<u8>> {
+		self.inner.to_bytes_with_compression(compression)
+	}
+}
+
+impl FromBytes for CompactCiphertext {
+	type State = Context;
+
+	/// Deserializes a byte stream into a seed-compressed ciphertext. This requires a context,
+	/// which is why `CompactCiphertext` doesn't `impl Deserialize`.
+	fn from_bytes(
+		context: &Context,
+		data: &[u8],
+	) -> Result<Self> {
+		Ok(Self {
+			inner: Ciphertext::from_bytes(context, data)?,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	#[test]
+	fn can_expand_compact_ciphertext() {
+		let params = BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(1234)
+			.build()
+			.unwrap();
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let secret_key = gen.secret_key();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let encryptor = Encryptor::with_secret_key(&ctx, &secret_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+
+		let compact = encryptor.encrypt_symmetric_compact(&plaintext).unwrap();
+		let expanded = compact.expand(&ctx).unwrap();
+
+		let decrypted = decryptor.decrypt(&expanded).unwrap();
+		assert_eq!(data, encoder.decode_u64(&decrypted).unwrap());
+	}
+}
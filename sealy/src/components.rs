@@ -0,0 +1,365 @@
+use serde::ser::Error as SerdeError;
+use serde::{Serialize, Serializer};
+
+use crate::{error::*, Context, FromBytes, Plaintext, PolynomialArray, ToBytes};
+use crate::{Protected, SecretToBytes};
+
+/// Zero-sized marker types used to select which encryption capabilities an
+/// [`crate::Encryptor`] or [`crate::TensorEncryptor`] instance supports.
+pub mod marker {
+	/// Implemented by marker types that support asymmetric (public-key) encryption.
+	pub trait Asym {}
+
+	/// Implemented by marker types that support symmetric (secret-key) encryption.
+	pub trait Sym {}
+}
+
+/// Marker type indicating an encryptor supports asymmetric (public-key) encryption.
+pub struct Asym;
+
+/// Marker type indicating an encryptor supports symmetric (secret-key) encryption.
+pub struct Sym;
+
+/// Marker type indicating an encryptor supports both asymmetric and symmetric encryption.
+pub struct SymAsym;
+
+impl marker::Asym for Asym {}
+impl marker::Sym for Sym {}
+impl marker::Asym for SymAsym {}
+impl marker::Sym for SymAsym {}
+
+/// Concatenates `parts` into a single byte stream, length-prefixing each part so
+/// `split_parts` can recover them without knowing the byte lengths of the underlying SEAL
+/// objects ahead of time.
+fn join_parts(parts: &[Vec<u8>]) -> Vec<u8> {
+	let mut bytes = Vec::new();
+
+	for part in parts {
+		bytes.extend_from_slice(&(part.len() as u64).to_le_bytes());
+		bytes.extend_from_slice(part);
+	}
+
+	bytes
+}
+
+/// Splits a byte stream produced by `join_parts` back into exactly `count` parts.
+fn split_parts(
+	data: &[u8],
+	count: usize,
+) -> Result<Vec<Vec<u8>>> {
+	let mut parts = Vec::with_capacity(count);
+	let mut offset = 0;
+
+	for _ in 0..count {
+		if data.len() - offset < 8 {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let len = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize;
+		offset += 8;
+
+		if data.len() - offset < len {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		parts.push(data[offset..offset + len].to_vec());
+		offset += len;
+	}
+
+	Ok(parts)
+}
+
+/// The intermediate values produced while performing an asymmetric encryption, namely the `u`
+/// and `e` noise polynomials and the `r` remainder plaintext.
+///
+/// Serializing these alongside a deterministically-encrypted ciphertext lets a third party
+/// re-derive the ciphertext from the same plaintext and seed and check that it matches,
+/// without needing to repeat the encryption itself.
+///
+/// # Security
+/// `u`, `e`, and `r` are exactly the secret material needed to reconstruct the ephemeral
+/// randomness behind a ciphertext, so they deserve the same handling as a secret key. The
+/// `secret_*` accessors (e.g. [`AsymmetricComponents::secret_u`]) return each field through
+/// [`SecretToBytes`] instead of the plain `u`/`e`/`r` getters, landing it in a `mlock`'d,
+/// zeroize-on-drop [`Protected`] buffer rather than ordinary heap; wrap the result in an
+/// [`crate::Encrypted`] to keep it encrypted at rest for longer than the scope of a single call.
+pub struct AsymmetricComponents {
+	u: PolynomialArray,
+	e: PolynomialArray,
+	r: Plaintext,
+}
+
+impl AsymmetricComponents {
+	pub(crate) fn new(
+		u: PolynomialArray,
+		e: PolynomialArray,
+		r: Plaintext,
+	) -> Self {
+		Self {
+			u,
+			e,
+			r,
+		}
+	}
+
+	/// Returns the `u` polynomial used while encrypting.
+	pub fn u(&self) -> &PolynomialArray {
+		&self.u
+	}
+
+	/// Returns the `e` (noise) polynomial used while encrypting.
+	pub fn e(&self) -> &PolynomialArray {
+		&self.e
+	}
+
+	/// Returns the `r` (remainder) plaintext used while encrypting.
+	pub fn r(&self) -> &Plaintext {
+		&self.r
+	}
+
+	/// Returns the `u` polynomial serialized into a `mlock`'d, zeroize-on-drop buffer, for
+	/// callers that want to hold onto it without leaving a plain copy in ordinary heap.
+	pub fn secret_u(&self) -> Result<Protected> {
+		self.u.secret_as_bytes()
+	}
+
+	/// Returns the `e` (noise) polynomial serialized into a `mlock`'d, zeroize-on-drop buffer,
+	/// for callers that want to hold onto it without leaving a plain copy in ordinary heap.
+	pub fn secret_e(&self) -> Result<Protected> {
+		self.e.secret_as_bytes()
+	}
+
+	/// Returns the `r` (remainder) plaintext serialized into a `mlock`'d, zeroize-on-drop
+	/// buffer, for callers that want to hold onto it without leaving a plain copy in ordinary
+	/// heap.
+	pub fn secret_r(&self) -> Result<Protected> {
+		self.r.secret_as_bytes()
+	}
+}
+
+impl ToBytes for AsymmetricComponents {
+	fn as_bytes(&self) -> Result<Vec<u8>> {
+		Ok(join_parts(&[
+			self.u.as_bytes()?,
+			self.e.as_bytes()?,
+			self.r.as_bytes()?,
+		]))
+	}
+}
+
+impl FromBytes for AsymmetricComponents {
+	type State = Context;
+
+	/// Reconstructs the `u`, `e`, and `r` components from the byte stream produced by
+	/// `as_bytes`. This requires a context, which is why `AsymmetricComponents` doesn't
+	/// `impl Deserialize`.
+	fn from_bytes(
+		context: &Context,
+		data: &[u8],
+	) -> Result<Self> {
+		let mut parts = split_parts(data, 3)?.into_iter();
+
+		Ok(Self::new(
+			PolynomialArray::from_bytes(context, &parts.next().unwrap())?,
+			PolynomialArray::from_bytes(context, &parts.next().unwrap())?,
+			Plaintext::from_bytes(context, &parts.next().unwrap())?,
+		))
+	}
+}
+
+impl Serialize for AsymmetricComponents {
+	fn serialize<S>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let bytes = self
+			.as_bytes()
+			.map_err(|e| S::Error::custom(format!("Failed to serialize components: {}", e)))?;
+
+		serializer.serialize_bytes(&bytes)
+	}
+}
+
+/// The intermediate values produced while performing a symmetric encryption, namely the `e`
+/// noise polynomial and the `r` remainder plaintext.
+///
+/// Serializing these alongside a deterministically-encrypted ciphertext lets a third party
+/// re-derive the ciphertext from the same plaintext and seed and check that it matches,
+/// without needing to repeat the encryption itself.
+///
+/// # Security
+/// See [`AsymmetricComponents`]'s "Security" section: `e` and `r` are the same kind of secret
+/// material, so [`SymmetricComponents::secret_e`]/[`SymmetricComponents::secret_r`] return them
+/// through [`SecretToBytes`] into a `mlock`'d, zeroize-on-drop [`Protected`] buffer instead of
+/// the plain `e`/`r` getters.
+pub struct SymmetricComponents {
+	e: PolynomialArray,
+	r: Plaintext,
+}
+
+impl SymmetricComponents {
+	pub(crate) fn new(
+		e: PolynomialArray,
+		r: Plaintext,
+	) -> Self {
+		Self {
+			e,
+			r,
+		}
+	}
+
+	/// Returns the `e` (noise) polynomial used while encrypting.
+	pub fn e(&self) -> &PolynomialArray {
+		&self.e
+	}
+
+	/// Returns the `r` (remainder) plaintext used while encrypting.
+	pub fn r(&self) -> &Plaintext {
+		&self.r
+	}
+
+	/// Returns the `e` (noise) polynomial serialized into a `mlock`'d, zeroize-on-drop buffer,
+	/// for callers that want to hold onto it without leaving a plain copy in ordinary heap.
+	pub fn secret_e(&self) -> Result<Protected> {
+		self.e.secret_as_bytes()
+	}
+
+	/// Returns the `r` (remainder) plaintext serialized into a `mlock`'d, zeroize-on-drop
+	/// buffer, for callers that want to hold onto it without leaving a plain copy in ordinary
+	/// heap.
+	pub fn secret_r(&self) -> Result<Protected> {
+		self.r.secret_as_bytes()
+	}
+}
+
+impl ToBytes for SymmetricComponents {
+	fn as_bytes(&self) -> Result<Vec<u8>> {
+		Ok(join_parts(&[self.e.as_bytes()?, self.r.as_bytes()?]))
+	}
+}
+
+impl FromBytes for SymmetricComponents {
+	type State = Context;
+
+	/// Reconstructs the `e` and `r` components from the byte stream produced by `as_bytes`.
+	/// This requires a context, which is why `SymmetricComponents` doesn't `impl Deserialize`.
+	fn from_bytes(
+		context: &Context,
+		data: &[u8],
+	) -> Result<Self> {
+		let mut parts = split_parts(data, 2)?.into_iter();
+
+		Ok(Self::new(
+			PolynomialArray::from_bytes(context, &parts.next().unwrap())?,
+			Plaintext::from_bytes(context, &parts.next().unwrap())?,
+		))
+	}
+}
+
+impl Serialize for SymmetricComponents {
+	fn serialize<S>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let bytes = self
+			.as_bytes()
+			.map_err(|e| S::Error::custom(format!("Failed to serialize components: {}", e)))?;
+
+		serializer.serialize_bytes(&bytes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	#[test]
+	fn can_round_trip_asymmetric_components() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let encryptor =
+			Encryptor::with_public_and_secret_key(&ctx, &public_key, &secret_key).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let (_, components) = encryptor.encrypt_return_components(&plaintext).unwrap();
+
+		let bytes = components.as_bytes().unwrap();
+		let loaded = AsymmetricComponents::from_bytes(&ctx, &bytes).unwrap();
+
+		assert_eq!(components.u().as_bytes().unwrap(), loaded.u().as_bytes().unwrap());
+		assert_eq!(components.e().as_bytes().unwrap(), loaded.e().as_bytes().unwrap());
+		assert_eq!(components.r().as_bytes().unwrap(), loaded.r().as_bytes().unwrap());
+	}
+
+	#[test]
+	fn can_round_trip_symmetric_components() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let secret_key = gen.secret_key();
+		let encryptor = Encryptor::with_secret_key(&ctx, &secret_key).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let (_, components) = encryptor
+			.encrypt_symmetric_return_components(&plaintext)
+			.unwrap();
+
+		let bytes = components.as_bytes().unwrap();
+		let loaded = SymmetricComponents::from_bytes(&ctx, &bytes).unwrap();
+
+		assert_eq!(components.e().as_bytes().unwrap(), loaded.e().as_bytes().unwrap());
+		assert_eq!(components.r().as_bytes().unwrap(), loaded.r().as_bytes().unwrap());
+	}
+
+	#[test]
+	fn asymmetric_components_secret_accessors_match_the_plain_ones() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let encryptor =
+			Encryptor::with_public_and_secret_key(&ctx, &public_key, &secret_key).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let (_, components) = encryptor.encrypt_return_components(&plaintext).unwrap();
+
+		assert_eq!(&*components.secret_u().unwrap(), &components.u().as_bytes().unwrap()[..]);
+		assert_eq!(&*components.secret_e().unwrap(), &components.e().as_bytes().unwrap()[..]);
+		assert_eq!(&*components.secret_r().unwrap(), &components.r().as_bytes().unwrap()[..]);
+	}
+
+	#[test]
+	fn symmetric_components_secret_accessors_match_the_plain_ones() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let secret_key = gen.secret_key();
+		let encryptor = Encryptor::with_secret_key(&ctx, &secret_key).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let (_, components) = encryptor
+			.encrypt_symmetric_return_components(&plaintext)
+			.unwrap();
+
+		assert_eq!(&*components.secret_e().unwrap(), &components.e().as_bytes().unwrap()[..]);
+		assert_eq!(&*components.secret_r().unwrap(), &components.r().as_bytes().unwrap()[..]);
+	}
+}
@@ -43,6 +43,7 @@ use crate::SecurityLevel;
 /// The chain is a doubly linked list and is referred to as the modulus switching chain.
 pub struct Context {
 	pub(crate) handle: *mut c_void,
+	security_level: SecurityLevel,
 }
 
 unsafe impl Sync for Context {}
@@ -73,6 +74,7 @@ impl Context {
 
 		Ok(Context {
 			handle,
+			security_level,
 		})
 	}
 
@@ -93,6 +95,7 @@ impl Context {
 
 		Ok(Context {
 			handle,
+			security_level: SecurityLevel::None,
 		})
 	}
 
@@ -101,6 +104,23 @@ impl Context {
 		self.handle
 	}
 
+	/// Returns the security level this context was constructed with.
+	pub fn get_security_level(&self) -> Result<SecurityLevel> {
+		Ok(self.security_level)
+	}
+
+	/// Returns the encryption parameters used to create the first ContextData in the
+	/// modulus switching chain.
+	pub fn get_encryption_parameters(&self) -> Result<EncryptionParameters> {
+		self.get_first_context_data()?.get_encryption_parameters()
+	}
+
+	/// Returns the total bit count of the coefficient modulus of the first
+	/// ContextData in the modulus switching chain.
+	pub fn get_total_coeff_modulus_bit_count(&self) -> Result<i32> {
+		self.get_first_context_data()?.get_total_coeff_modulus_bit_count()
+	}
+
 	/// Returns the key ContextData in the modulus switching chain.
 	pub fn get_key_parms_id(&self) -> Result<Vec<u64>> {
 		let mut parms_id: Vec<u64> =
@@ -183,6 +203,93 @@ impl Context {
 
 		Ok(ContextData::new(context_data))
 	}
+
+	/// Returns the key ContextData, i.e. the context data holding the full coefficient modulus
+	/// including the special primes reserved for key generation. This sits one level above
+	/// [`Self::get_first_context_data`] in the modulus switching chain, which only ever holds
+	/// data-level (non-key) primes.
+	pub fn get_key_context_data(&self) -> Result<ContextData> {
+		let mut context_data: *mut c_void = null_mut();
+
+		convert_seal_error(unsafe {
+			bindgen::SEALContext_KeyContextData(self.handle, &mut context_data)
+		})?;
+
+		if context_data.is_null() {
+			return Err(Error::InvalidPointer);
+		}
+
+		Ok(ContextData::new(context_data))
+	}
+
+	/// Returns whether the encryption parameters this context was constructed from were deemed
+	/// valid and usable, i.e. whether [`Context::get_encryption_parameters`] and key generation
+	/// can proceed. When this is `false`, [`Self::get_parameter_error_name`]/
+	/// [`Self::get_parameter_error_message`] explain exactly why.
+	pub fn is_parameters_set(&self) -> Result<bool> {
+		let mut params_set = false;
+
+		try_seal!(unsafe { bindgen::SEALContext_ParametersSet(self.handle, &mut params_set) })?;
+
+		Ok(params_set)
+	}
+
+	/// Returns a short, machine-oriented name for the reason the encryption parameters were
+	/// rejected (e.g. `"invalid_coeff_modulus_size"`), or `"success"` if
+	/// [`Self::is_parameters_set`] is `true`. See [`Self::get_parameter_error_message`] for a
+	/// human-readable description instead.
+	pub fn get_parameter_error_name(&self) -> Result<String> {
+		self.get_parameter_error_string(bindgen::SEALContext_ParameterErrorName)
+	}
+
+	/// Returns a human-readable description of the reason the encryption parameters were
+	/// rejected, or a message indicating success if [`Self::is_parameters_set`] is `true`. This
+	/// is what a user should be shown when diagnosing why a parameter set doesn't work, e.g.
+	/// after passing a coefficient modulus with too few primes for the requested multiplication
+	/// depth.
+	pub fn get_parameter_error_message(&self) -> Result<String> {
+		self.get_parameter_error_string(bindgen::SEALContext_ParameterErrorMessage)
+	}
+
+	/// Calls a `SEALContext_ParameterError*` getter twice, as with every other variable-length
+	/// string/buffer this binding reads from SEAL: once with a null buffer to learn the required
+	/// length, then again with a buffer of that length to fill it in.
+	fn get_parameter_error_string(
+		&self,
+		getter: unsafe extern "C" fn(*mut c_void, *mut u8, *mut u64) -> i64,
+	) -> Result<String> {
+		let mut length: u64 = 0;
+
+		try_seal!(unsafe { getter(self.handle, null_mut(), &mut length) })?;
+
+		let mut buffer = vec![0u8; length as usize];
+
+		try_seal!(unsafe { getter(self.handle, buffer.as_mut_ptr(), &mut length) })?;
+
+		while buffer.last() == Some(&0) {
+			buffer.pop();
+		}
+
+		Ok(String::from_utf8_lossy(&buffer).into_owned())
+	}
+
+	/// Returns the number of remaining levels in the modulus switching chain for `ciphertext`:
+	/// how many more `mod_switch_to_next`/`rescale_to_next` calls it can still absorb before it
+	/// reaches the last usable level. This is [`ContextData::chain_index`] of the ciphertext's
+	/// own parms_id, since that index is defined as 0 at the last level and counts up toward
+	/// the first (freshest) one.
+	///
+	/// Unlike [`crate::Decryptor::invariant_noise_budget`], this doesn't need the secret key:
+	/// it only reflects how many primes are left in the chain, not how much actual noise
+	/// headroom remains in the ciphertext, so it's a cheap upper bound rather than a substitute
+	/// for the real noise budget.
+	pub fn remaining_levels(
+		&self,
+		ciphertext: &crate::Ciphertext,
+	) -> Result<usize> {
+		let parms_id = ciphertext.parms_id()?;
+		self.get_context_data(&parms_id)?.chain_index()
+	}
 }
 
 impl Drop for Context {
@@ -211,4 +318,72 @@ mod tests {
 
 		std::mem::drop(ctx);
 	}
+
+	#[test]
+	fn valid_parameters_report_success() {
+		let params = BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulus::create(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(1234)
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+
+		assert!(ctx.is_parameters_set().unwrap());
+		assert_eq!(ctx.get_parameter_error_name().unwrap(), "success");
+		assert!(!ctx.get_parameter_error_message().unwrap().is_empty());
+	}
+
+	#[test]
+	fn prev_context_data_reverses_next_context_data() {
+		let params = BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulus::create(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(1234)
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, true, SecurityLevel::TC128).unwrap();
+
+		let key_data = ctx.get_key_context_data().unwrap();
+		assert!(key_data.next_context_data().unwrap().is_some());
+
+		let first = ctx.get_first_context_data().unwrap();
+		let next = first.next_context_data().unwrap().unwrap();
+		let back = next.prev_context_data().unwrap().unwrap();
+
+		assert_eq!(back.parms_id().unwrap(), first.parms_id().unwrap());
+	}
+
+	#[test]
+	fn remaining_levels_counts_down_after_each_mod_switch() {
+		let params = CkksEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulus::create(DegreeType::D8192, &[60, 40, 40, 60]).unwrap(),
+			)
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, true, SecurityLevel::TC128).unwrap();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let encryptor =
+			Encryptor::with_public_key(&ctx, &key_gen.create_public_key()).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let plaintext = encoder.encode_f64(&[1.0, 2.0, 3.0]).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let before = ctx.remaining_levels(&ciphertext).unwrap();
+		let switched = evaluator.mod_switch_to_next(&ciphertext).unwrap();
+		let after = ctx.remaining_levels(&switched).unwrap();
+
+		assert_eq!(after, before - 1);
+	}
 }
@@ -47,6 +47,87 @@ impl ContextData {
 
 		Ok(bit_count)
 	}
+
+	/// Returns the parms_id of the encryption parameters this context data was created from.
+	pub fn parms_id(&self) -> Result<Vec<u64>> {
+		let mut parms_id: Vec<u64> = vec![0; 4];
+
+		try_seal!(unsafe {
+			bindgen::ContextData_ParmsId(self.get_handle(), parms_id.as_mut_ptr())
+		})?;
+
+		Ok(parms_id)
+	}
+
+	/// Returns this context data's index in the modulus switching chain: 0 at the last usable
+	/// level (just before the special primes used only for key generation), counting up toward
+	/// the first (freshest) level. Mirrors [`crate::Ciphertext::parms_id`]/[`Self::parms_id`]'s
+	/// lookup key, but as a small integer a caller can compare or display directly instead of
+	/// the raw 4-word hash.
+	pub fn chain_index(&self) -> Result<usize> {
+		let mut index: u64 = 0;
+
+		try_seal!(unsafe { bindgen::ContextData_ChainIndex(self.get_handle(), &mut index) })?;
+
+		Ok(index as usize)
+	}
+
+	/// Returns whether batching (SIMD slot encoding) is available under this context data's
+	/// parameters, i.e. whether the plaintext modulus was chosen to support it. Mirrors SEAL's
+	/// `EncryptionParameterQualifiers::using_batching`.
+	pub fn using_batching(&self) -> Result<bool> {
+		let mut qualifiers: *mut c_void = null_mut();
+		let mut using_batching = false;
+
+		try_seal!(unsafe { bindgen::ContextData_Qualifiers(self.get_handle(), &mut qualifiers) })?;
+		try_seal!(unsafe { bindgen::EPQ_UsingBatching(qualifiers, &mut using_batching) })?;
+
+		Ok(using_batching)
+	}
+
+	/// Returns whether the "fast plain lift" optimization applies under this context data's
+	/// parameters, which skips part of the BFV encoding computation when the plaintext modulus
+	/// is smaller than every prime in the coefficient modulus. Mirrors SEAL's
+	/// `EncryptionParameterQualifiers::using_fast_plain_lift`.
+	pub fn using_fast_plain_lift(&self) -> Result<bool> {
+		let mut qualifiers: *mut c_void = null_mut();
+		let mut using_fast_plain_lift = false;
+
+		try_seal!(unsafe { bindgen::ContextData_Qualifiers(self.get_handle(), &mut qualifiers) })?;
+		try_seal!(unsafe {
+			bindgen::EPQ_UsingFastPlainLift(qualifiers, &mut using_fast_plain_lift)
+		})?;
+
+		Ok(using_fast_plain_lift)
+	}
+
+	/// Returns the next context data down the modulus switching chain (one fewer prime in the
+	/// coefficient modulus), or `None` if this is already the last usable level.
+	pub fn next_context_data(&self) -> Result<Option<ContextData>> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::ContextData_NextContextData(self.get_handle(), &mut handle) })?;
+
+		if handle.is_null() {
+			Ok(None)
+		} else {
+			Ok(Some(ContextData::new(handle)))
+		}
+	}
+
+	/// Returns the previous context data up the modulus switching chain (one more prime in the
+	/// coefficient modulus), or `None` if this is already the first (freshest) level.
+	pub fn prev_context_data(&self) -> Result<Option<ContextData>> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::ContextData_PrevContextData(self.get_handle(), &mut handle) })?;
+
+		if handle.is_null() {
+			Ok(None)
+		} else {
+			Ok(Some(ContextData::new(handle)))
+		}
+	}
 }
 
 impl Drop for ContextData {
@@ -81,4 +162,49 @@ mod tests {
 		assert_eq!(expected_params.get_plain_modulus().value(), 1234);
 		assert_eq!(expected_params.get_coefficient_modulus().len(), 5);
 	}
+
+	#[test]
+	fn chain_index_decreases_while_walking_next_context_data() {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(1234)
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, true, SecurityLevel::TC128).unwrap();
+
+		let mut current = ctx.get_first_context_data().unwrap();
+		let mut last_index = current.chain_index().unwrap();
+
+		while let Some(next) = current.next_context_data().unwrap() {
+			let next_index = next.chain_index().unwrap();
+
+			assert!(next_index < last_index);
+
+			last_index = next_index;
+			current = next;
+		}
+
+		assert_eq!(last_index, 0);
+	}
+
+	#[test]
+	fn batching_plain_modulus_reports_using_batching() {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+		let ctx_data = ctx.get_first_context_data().unwrap();
+
+		assert!(ctx_data.using_batching().unwrap());
+	}
 }
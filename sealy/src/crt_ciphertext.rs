@@ -0,0 +1,261 @@
+use crate::{error::*, Ciphertext, CrtEncoder, Decryptor, Encryptor, Evaluator, RelinearizationKey};
+
+/// A large integer (beyond what any single plaintext modulus can hold) spread across several
+/// independently encrypted channels via the Chinese Remainder Theorem, inspired by the
+/// CRT-composed multi-ciphertext approach used by concrete-integer.
+///
+/// A [`CrtEncoder`] splits a value into residues `v mod t_i`, one per coprime plain modulus;
+/// this type holds one [`Ciphertext`] per residue, each encrypted under its own context/plain
+/// modulus `t_i`. Homomorphic add/multiply apply component-wise, one channel's evaluator at a
+/// time, so each residue channel stays below its own modulus rather than overflowing a single
+/// shared one. [`Self::decrypt`] reconstructs the original value via the same CRT
+/// recombination [`CrtEncoder::decode_crt`] uses.
+///
+/// # Key invariant
+/// Every channel must share the same poly modulus degree, and any evaluation (add/multiply)
+/// must be applied to every channel in lockstep with the matching per-channel evaluator/keys,
+/// so the channels' `parms_id`s stay aligned for decryption. Mixing up channel order between
+/// encryption, evaluation, and decryption silently reconstructs the wrong value rather than
+/// erroring, since nothing here ties a `Ciphertext` back to the channel it was produced for.
+pub struct CrtCiphertext {
+	channels: Vec<Ciphertext>,
+}
+
+impl CrtCiphertext {
+	/// Splits `value` into residues via `encoder` and encrypts each one under the matching
+	/// entry of `encryptors`.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidParams`] if `encryptors.len()` doesn't match
+	/// `encoder.channel_count()`, or whatever [`CrtEncoder::encode_crt`] returns for `value`.
+	pub fn encrypt(
+		encoder: &CrtEncoder,
+		encryptors: &[Encryptor],
+		value: i128,
+	) -> Result<Self> {
+		if encryptors.len() != encoder.channel_count() {
+			return Err(Error::InvalidParams);
+		}
+
+		let plaintexts = encoder.encode_crt(value)?;
+
+		let channels = plaintexts
+			.iter()
+			.zip(encryptors)
+			.map(|(plaintext, encryptor)| encryptor.encrypt(plaintext))
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(Self {
+			channels,
+		})
+	}
+
+	/// Decrypts every channel with the matching entry of `decryptors`, then reconstructs the
+	/// original value via [`CrtEncoder::decode_crt`].
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidParams`] if `decryptors.len()` doesn't match the number of
+	/// channels this ciphertext holds.
+	pub fn decrypt(
+		&self,
+		encoder: &CrtEncoder,
+		decryptors: &[Decryptor],
+	) -> Result<i128> {
+		if decryptors.len() != self.channels.len() {
+			return Err(Error::InvalidParams);
+		}
+
+		let plaintexts = self
+			.channels
+			.iter()
+			.zip(decryptors)
+			.map(|(ciphertext, decryptor)| decryptor.decrypt(ciphertext))
+			.collect::<Result<Vec<_>>>()?;
+
+		encoder.decode_crt(&plaintexts)
+	}
+
+	/// Adds `self` and `other` component-wise, one channel's `evaluator` at a time.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidParams`] if `other` or `evaluators` don't have one entry per
+	/// channel this ciphertext holds.
+	pub fn add<E>(
+		&self,
+		other: &Self,
+		evaluators: &[E],
+	) -> Result<Self>
+	where
+		E: Evaluator<Plaintext = crate::Plaintext, Ciphertext = Ciphertext>,
+	{
+		if other.channels.len() != self.channels.len() || evaluators.len() != self.channels.len() {
+			return Err(Error::InvalidParams);
+		}
+
+		let channels = self
+			.channels
+			.iter()
+			.zip(&other.channels)
+			.zip(evaluators)
+			.map(|((a, b), evaluator)| evaluator.add(a, b))
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(Self {
+			channels,
+		})
+	}
+
+	/// Multiplies `self` and `other` component-wise, one channel's `evaluator` at a time, then
+	/// relinearizes each product against the matching entry of `relin_keys`.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidParams`] if `other`, `evaluators`, or `relin_keys` don't have one
+	/// entry per channel this ciphertext holds.
+	pub fn multiply<E>(
+		&self,
+		other: &Self,
+		evaluators: &[E],
+		relin_keys: &[RelinearizationKey],
+	) -> Result<Self>
+	where
+		E: Evaluator<Plaintext = crate::Plaintext, Ciphertext = Ciphertext>,
+	{
+		if other.channels.len() != self.channels.len()
+			|| evaluators.len() != self.channels.len()
+			|| relin_keys.len() != self.channels.len()
+		{
+			return Err(Error::InvalidParams);
+		}
+
+		let channels = self
+			.channels
+			.iter()
+			.zip(&other.channels)
+			.zip(evaluators)
+			.zip(relin_keys)
+			.map(|(((a, b), evaluator), relin_keys)| {
+				let mut product = evaluator.multiply(a, b)?;
+
+				evaluator.relinearize_inplace(&mut product, relin_keys)?;
+
+				Ok(product)
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(Self {
+			channels,
+		})
+	}
+
+	/// Returns the number of CRT channels this ciphertext holds.
+	pub fn channel_count(&self) -> usize {
+		self.channels.len()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		BFVEvaluator, BfvEncryptionParametersBuilder, CoefficientModulusFactory, Context,
+		DegreeType, KeyGenerator, SecurityLevel,
+	};
+
+	fn mk_ctx(plain_modulus: u64) -> Context {
+		let params = BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(plain_modulus)
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[allow(clippy::type_complexity)]
+	fn mk_channels(
+		moduli: &[u64],
+	) -> (
+		Vec<Context>,
+		Vec<Encryptor>,
+		Vec<Decryptor>,
+		Vec<BFVEvaluator>,
+		Vec<RelinearizationKey>,
+	) {
+		let mut contexts = Vec::with_capacity(moduli.len());
+		let mut encryptors = Vec::with_capacity(moduli.len());
+		let mut decryptors = Vec::with_capacity(moduli.len());
+		let mut evaluators = Vec::with_capacity(moduli.len());
+		let mut relin_keys = Vec::with_capacity(moduli.len());
+
+		for &modulus in moduli {
+			let ctx = mk_ctx(modulus);
+			let gen = KeyGenerator::new(&ctx).unwrap();
+			let public_key = gen.create_public_key();
+			let secret_key = gen.secret_key();
+
+			encryptors.push(Encryptor::with_public_key(&ctx, &public_key).unwrap());
+			decryptors.push(Decryptor::new(&ctx, &secret_key).unwrap());
+			evaluators.push(BFVEvaluator::new(&ctx).unwrap());
+			relin_keys.push(gen.create_relinearization_keys().unwrap());
+			contexts.push(ctx);
+		}
+
+		(contexts, encryptors, decryptors, evaluators, relin_keys)
+	}
+
+	#[test]
+	fn round_trips_a_value_beyond_a_single_modulus() {
+		let (contexts, encryptors, decryptors, _, _) = mk_channels(&[65537, 65539, 65543]);
+		let encoder = CrtEncoder::new(&contexts).unwrap();
+
+		let value: i128 = 123_456_789_012;
+		let ciphertext = CrtCiphertext::encrypt(&encoder, &encryptors, value).unwrap();
+		let decrypted = ciphertext.decrypt(&encoder, &decryptors).unwrap();
+
+		assert_eq!(decrypted, value);
+	}
+
+	#[test]
+	fn adds_component_wise_across_channels() {
+		let (contexts, encryptors, decryptors, evaluators, _) =
+			mk_channels(&[65537, 65539, 65543]);
+		let encoder = CrtEncoder::new(&contexts).unwrap();
+
+		let a = CrtCiphertext::encrypt(&encoder, &encryptors, 40).unwrap();
+		let b = CrtCiphertext::encrypt(&encoder, &encryptors, 2).unwrap();
+
+		let sum = a.add(&b, &evaluators).unwrap();
+		let decrypted = sum.decrypt(&encoder, &decryptors).unwrap();
+
+		assert_eq!(decrypted, 42);
+	}
+
+	#[test]
+	fn multiplies_component_wise_across_channels() {
+		let (contexts, encryptors, decryptors, evaluators, relin_keys) =
+			mk_channels(&[65537, 65539, 65543]);
+		let encoder = CrtEncoder::new(&contexts).unwrap();
+
+		let a = CrtCiphertext::encrypt(&encoder, &encryptors, 6).unwrap();
+		let b = CrtCiphertext::encrypt(&encoder, &encryptors, 7).unwrap();
+
+		let product = a.multiply(&b, &evaluators, &relin_keys).unwrap();
+		let decrypted = product.decrypt(&encoder, &decryptors).unwrap();
+
+		assert_eq!(decrypted, 42);
+	}
+
+	#[test]
+	fn rejects_mismatched_channel_counts() {
+		let (contexts, encryptors, _, _, _) = mk_channels(&[65537, 65539]);
+		let encoder = CrtEncoder::new(&contexts).unwrap();
+
+		assert!(matches!(
+			CrtCiphertext::encrypt(&encoder, &encryptors[..1], 5),
+			Err(Error::InvalidParams)
+		));
+	}
+}
@@ -4,7 +4,22 @@ use std::{
 	sync::atomic::{AtomicPtr, Ordering},
 };
 
-use crate::{bindgen, error::Result, try_seal, Ciphertext, Context, Plaintext, SecretKey};
+use crate::{bindgen, error::Error, error::Result, try_seal, Ciphertext, Context, Plaintext, SecretKey};
+
+/// A type capable of decrypting a [`Ciphertext`] into a [`Plaintext`].
+///
+/// Mirrors [`crate::Encrypt`] so generic code can accept "any decryptor" — including as a
+/// trait object — instead of requiring a concrete [`Decryptor`].
+pub trait Decrypt {
+	/// The error produced when decryption fails.
+	type Error;
+
+	/// Decrypts `ciphertext`, returning the resulting plaintext.
+	fn decrypt(
+		&self,
+		ciphertext: &Ciphertext,
+	) -> std::result::Result<Plaintext, Self::Error>;
+}
 
 /// Decrypts Ciphertext objects into Plaintext objects. Constructing a Decryptor requires
 /// a SEALContext with valid encryption parameters, and the secret key. The Decryptor is
@@ -71,7 +86,7 @@ impl Decryptor {
 	/// budget measures the amount of room there is for the noise to grow while ensuring
 	/// correct decryptions. Dynamic memory allocations in the process are allocated from
 	/// the memory pool pointed to by the given MemoryPoolHandle. This function works only
-	/// with the BFV scheme.
+	/// with the BFV and BGV schemes.
 	///
 	/// # Invariant Noise Budget
 	/// The invariant noise polynomial of a ciphertext is a rational coefficient polynomial,
@@ -103,7 +118,7 @@ impl Decryptor {
 
 	/// Computes the invariant noise of a ciphertext. The invariant noise is
 	/// a value that increases with FHE operations. This function only works
-	/// with the BFV scheme.
+	/// with the BFV and BGV schemes.
 	///
 	/// # Invariant Noise
 	/// The invariant noise polynomial of a ciphertext is a rational * coefficient
@@ -130,6 +145,17 @@ impl Decryptor {
 	}
 }
 
+impl Decrypt for Decryptor {
+	type Error = Error;
+
+	fn decrypt(
+		&self,
+		ciphertext: &Ciphertext,
+	) -> Result<Plaintext> {
+		Decryptor::decrypt(self, ciphertext)
+	}
+}
+
 impl Drop for Decryptor {
 	fn drop(&mut self) {
 		try_seal!(unsafe { bindgen::Decryptor_Destroy(self.get_handle()) })
@@ -292,6 +318,114 @@ mod tests {
 		assert_eq!(data, data_2);
 	}
 
+	fn mk_bgv_ctx() -> Context {
+		let params = BGVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[test]
+	fn bgv_can_encrypt_and_decrypt_round_trip() {
+		let ctx = mk_bgv_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let mut data = vec![];
+
+		for i in 0..encoder.get_slot_count() {
+			data.push(i as u64);
+		}
+
+		let plaintext = encoder.encode_u64(&data).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor =
+			Encryptor::with_public_and_secret_key(&ctx, &public_key, &secret_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+		let decrypted = decryptor.decrypt(&ciphertext).unwrap();
+		let data_2: Vec<u64> = encoder.decode_u64(&decrypted).unwrap();
+		assert_eq!(data, data_2);
+	}
+
+	#[test]
+	fn bgv_invariant_noise_budget_matches_invariant_noise() {
+		// Mirrors SEAL's own decryptor test: for a freshly encrypted ciphertext,
+		// invariant_noise_budget should equal floor(-log2(2 * invariant_noise)).
+		let ctx = mk_bgv_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let plaintext = encoder.encode_u64(&vec![0u64; encoder.get_slot_count()]).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor =
+			Encryptor::with_public_and_secret_key(&ctx, &public_key, &secret_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let noise = decryptor.invariant_noise(&ciphertext).unwrap();
+		let budget = decryptor.invariant_noise_budget(&ciphertext).unwrap();
+
+		assert_eq!((-(2.0 * noise).log2()).floor() as u32, budget);
+	}
+
+	#[test]
+	fn ckks_can_encrypt_and_decrypt_approximate_floats() {
+		let params = CkksEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[60, 40, 40, 60]).unwrap(),
+			)
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count())
+			.map(|i| i as f64 / 3.0)
+			.collect();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor =
+			Encryptor::with_public_and_secret_key(&ctx, &public_key, &secret_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+		let decrypted = decryptor.decrypt(&ciphertext).unwrap();
+		let data_2 = encoder.decode_f64(&decrypted).unwrap();
+
+		for (expected, actual) in data.iter().zip(data_2.iter()) {
+			assert!(
+				(expected - actual).abs() < 1e-4,
+				"expected {} to be within 1e-4 of {}",
+				actual,
+				expected
+			);
+		}
+	}
+
 	#[cfg(feature = "deterministic")]
 	mod deterministic {
 		use std::collections::hash_map::DefaultHasher;
@@ -48,6 +48,7 @@ use crate::{Context, Plaintext};
 /// flags ParametersSet and EnableBatching set to true.
 pub struct BFVEncoder {
 	handle: AtomicPtr<c_void>,
+	plain_modulus: u64,
 }
 
 impl BFVEncoder {
@@ -61,8 +62,15 @@ impl BFVEncoder {
 
 		try_seal!(unsafe { bindgen::BatchEncoder_Create(ctx.get_handle(), &mut handle) })?;
 
+		let plain_modulus = ctx
+			.get_first_context_data()?
+			.get_encryption_parameters()?
+			.get_plain_modulus()
+			.value();
+
 		Ok(Self {
 			handle: AtomicPtr::new(handle),
+			plain_modulus,
 		})
 	}
 
@@ -221,6 +229,53 @@ impl BFVEncoder {
 		Ok(data)
 	}
 
+	/// Encodes a `2`-by-`(N/2)` matrix into a plaintext, the same way [`Self::encode_i64`]
+	/// does for the flattened slot vector, but taking the two rows explicitly instead of
+	/// requiring the caller to hand-index into the first/second half of a flat slice.
+	///
+	/// * `rows` - The two rows of the matrix, each of which must be no longer than half this
+	///   encoder's slot count.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidParams`] if `rows` doesn't contain exactly 2 rows, or if either
+	/// row is longer than half this encoder's slot count.
+	pub fn encode_matrix(
+		&self,
+		rows: &[&[i64]],
+	) -> Result<Plaintext> {
+		let half = self.get_slot_count() / 2;
+
+		if rows.len() != 2 || rows[0].len() > half || rows[1].len() > half {
+			return Err(Error::InvalidParams);
+		}
+
+		let mut flattened = Vec::with_capacity(2 * half);
+		flattened.extend_from_slice(rows[0]);
+		flattened.resize(half, 0);
+		flattened.extend_from_slice(rows[1]);
+		flattened.resize(2 * half, 0);
+
+		self.encode_i64(&flattened)
+	}
+
+	/// Decodes a plaintext produced by [`Self::encode_matrix`] (or any other `i64` batched
+	/// plaintext) back into its `2`-by-`(N/2)` matrix form, splitting the flat slot vector
+	/// [`Self::decode_i64`] returns into its first and second half.
+	///
+	/// * `plaintext` - The plaintext polynomial to unbatch.
+	pub fn decode_matrix(
+		&self,
+		plaintext: &Plaintext,
+	) -> Result<Vec<Vec<i64>>> {
+		let half = self.get_slot_count() / 2;
+		let flattened = self.decode_i64(plaintext)?;
+
+		Ok(vec![
+			flattened[..half].to_vec(),
+			flattened[half..].to_vec(),
+		])
+	}
+
 	/// Encodes a slice of float point numbers as integers.
 	///
 	/// * `values` - The slice of float point numbers to encode.
@@ -246,6 +301,308 @@ impl BFVEncoder {
 
 		Ok(unsigned_data.iter().map(|v| *v as f64 / base).collect())
 	}
+
+	/// Packs `data` into a single plaintext as an `n`-symbol systematic Reed-Solomon codeword
+	/// over `GF(plain_modulus)`, using the first `k` slots as the data itself and the
+	/// remaining `n - k` as parity, so [`Self::decode_rs_from_symbols`] can reconstruct `data`
+	/// from any surviving `k` of the `n` symbols.
+	///
+	/// `data` is first length-prefixed (an 8-byte little-endian original length, so decoding
+	/// can de-pad) and chopped into fixed-width little-endian field elements, each strictly
+	/// less than `plain_modulus`; these become the `k` systematic symbols (zero-padded if
+	/// `data` doesn't fill all `k` of them). The `n - k` parity symbols are the same message
+	/// polynomial — the unique degree-`< k` polynomial through points `(0, symbol_0), ...,
+	/// (k-1, symbol_{k-1})` — evaluated at `k, k+1, ..., n-1`.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidParams`] if `n` exceeds this encoder's slot count, if `n` is not
+	/// strictly greater than `k`, if `n` doesn't fit in distinct points of `GF(plain_modulus)`,
+	/// or if `data` (plus its length prefix) doesn't fit in `k` field elements at this
+	/// plaintext modulus's symbol width.
+	pub fn encode_rs(
+		&self,
+		data: &[u8],
+		k: usize,
+		n: usize,
+	) -> Result<Plaintext> {
+		if k == 0 || n <= k || n > self.get_slot_count() || (n as u128) > self.plain_modulus as u128
+		{
+			return Err(Error::InvalidParams);
+		}
+
+		let width = gf_symbol_width(self.plain_modulus);
+		let elements = bytes_to_symbols(data, width, k)?;
+
+		let points: Vec<u64> = (0..n as u64).collect();
+		let mut codeword = elements.clone();
+
+		for &x in &points[k..n] {
+			codeword.push(lagrange_eval(&points[..k], &elements, x, self.plain_modulus));
+		}
+
+		self.encode_u64(&codeword)
+	}
+
+	/// Inverse of [`Self::encode_rs`] for the case where the codeword plaintext produced by
+	/// `encode_rs` is fully intact: reads the first `k` (systematic) symbols back out and
+	/// reassembles the original bytes.
+	///
+	/// Unlike [`Self::decode_rs_from_symbols`], this can't tolerate any missing or corrupted
+	/// symbols, since a decoded [`Plaintext`] always has a concrete value in every slot; use
+	/// `decode_rs_from_symbols` when recovering from a partial set of surviving symbols drawn
+	/// from separate plaintexts or ciphertexts.
+	pub fn decode_rs(
+		&self,
+		plaintext: &Plaintext,
+		k: usize,
+		n: usize,
+	) -> Result<Vec<u8>> {
+		if k == 0 || n <= k || n > self.get_slot_count() {
+			return Err(Error::InvalidParams);
+		}
+
+		let slots = self.decode_u64(plaintext)?;
+
+		if slots.len() < k {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let width = gf_symbol_width(self.plain_modulus);
+
+		symbols_to_bytes(&slots[..k], width)
+	}
+
+	/// Recovers the bytes originally passed to [`Self::encode_rs`] from any `k` of the `n`
+	/// codeword symbols it produced, given as `(position, symbol)` pairs (`position` being the
+	/// slot index within the codeword, i.e. `0..n`). Positions are typically gathered by
+	/// decoding whichever of the `n` slot-groups or ciphertexts survived, pairing each
+	/// recovered slot value with the codeword position it was encoded at.
+	///
+	/// Recovery is Lagrange interpolation of the degree-`< k` message polynomial through the
+	/// surviving points, evaluated back at `0, 1, ..., k - 1` to recover the systematic
+	/// symbols, exactly as if none had been lost.
+	///
+	/// # Errors
+	/// Returns [`Error::TooManySymbolsLost`] if fewer than `k` symbols are given (or remain
+	/// after deduplicating positions), and [`Error::InvalidParams`] for the same malformed
+	/// `k`/`n` this shares with [`Self::encode_rs`].
+	pub fn decode_rs_from_symbols(
+		&self,
+		symbols: &[(usize, u64)],
+		k: usize,
+		n: usize,
+	) -> Result<Vec<u8>> {
+		if k == 0 || n <= k {
+			return Err(Error::InvalidParams);
+		}
+
+		let mut by_position: Vec<Option<u64>> = vec![None; n];
+
+		for &(position, value) in symbols {
+			if position >= n {
+				return Err(Error::InvalidParams);
+			}
+
+			by_position[position] = Some(value);
+		}
+
+		let surviving: Vec<(u64, u64)> = by_position
+			.iter()
+			.enumerate()
+			.filter_map(|(position, value)| value.map(|v| (position as u64, v)))
+			.take(k)
+			.collect();
+
+		if surviving.len() < k {
+			return Err(Error::TooManySymbolsLost {
+				k,
+				available: surviving.len(),
+			});
+		}
+
+		let nodes_x: Vec<u64> = surviving.iter().map(|&(x, _)| x).collect();
+		let nodes_y: Vec<u64> = surviving.iter().map(|&(_, y)| y).collect();
+
+		let elements: Vec<u64> = (0..k as u64)
+			.map(|x| lagrange_eval(&nodes_x, &nodes_y, x, self.plain_modulus))
+			.collect();
+
+		let width = gf_symbol_width(self.plain_modulus);
+
+		symbols_to_bytes(&elements, width)
+	}
+}
+
+/// Returns the largest `w` such that every `w`-byte little-endian value is strictly less than
+/// `modulus`, i.e. the widest fixed-width byte chunk that's guaranteed to be a valid
+/// `GF(modulus)` element no matter its contents.
+fn gf_symbol_width(modulus: u64) -> usize {
+	let mut width = 0;
+	let mut capacity: u128 = 1;
+
+	while capacity.saturating_mul(256) <= modulus as u128 {
+		capacity *= 256;
+		width += 1;
+	}
+
+	width
+}
+
+/// Length-prefixes `data` with its original byte length (8-byte little-endian), then chops the
+/// result into `width`-byte little-endian field elements, zero-padding the final chunk and the
+/// element count itself out to exactly `k` elements.
+fn bytes_to_symbols(
+	data: &[u8],
+	width: usize,
+	k: usize,
+) -> Result<Vec<u64>> {
+	if width == 0 {
+		return Err(Error::InvalidParams);
+	}
+
+	let mut buffer = Vec::with_capacity(8 + data.len());
+
+	buffer.extend_from_slice(&(data.len() as u64).to_le_bytes());
+	buffer.extend_from_slice(data);
+
+	let mut elements = Vec::with_capacity(k);
+
+	for chunk in buffer.chunks(width) {
+		let mut padded = [0u8; 8];
+		padded[..chunk.len()].copy_from_slice(chunk);
+
+		elements.push(u64::from_le_bytes(padded));
+	}
+
+	if elements.len() > k {
+		return Err(Error::InvalidParams);
+	}
+
+	elements.resize(k, 0);
+
+	Ok(elements)
+}
+
+/// Inverse of [`bytes_to_symbols`]: reassembles the `width`-byte little-endian encoding of each
+/// of `elements` back into a byte buffer, reads off the original length from its 8-byte
+/// little-endian prefix, and truncates away the length prefix and any trailing zero padding.
+fn symbols_to_bytes(
+	elements: &[u64],
+	width: usize,
+) -> Result<Vec<u8>> {
+	if width == 0 {
+		return Err(Error::InvalidParams);
+	}
+
+	let mut buffer = Vec::with_capacity(elements.len() * width);
+
+	for &element in elements {
+		buffer.extend_from_slice(&element.to_le_bytes()[..width]);
+	}
+
+	if buffer.len() < 8 {
+		return Err(Error::InvalidSerializedData);
+	}
+
+	let len = u64::from_le_bytes(buffer[..8].try_into().unwrap()) as usize;
+
+	if buffer.len() < 8 + len {
+		return Err(Error::InvalidSerializedData);
+	}
+
+	Ok(buffer[8..8 + len].to_vec())
+}
+
+/// Adds `a` and `b` modulo `modulus`.
+fn gf_add(
+	a: u64,
+	b: u64,
+	modulus: u64,
+) -> u64 {
+	(((a as u128) + (b as u128)) % modulus as u128) as u64
+}
+
+/// Subtracts `b` from `a` modulo `modulus`, wrapping around rather than underflowing.
+fn gf_sub(
+	a: u64,
+	b: u64,
+	modulus: u64,
+) -> u64 {
+	gf_add(a, modulus - (b % modulus), modulus)
+}
+
+/// Multiplies `a` and `b` modulo `modulus`.
+fn gf_mul(
+	a: u64,
+	b: u64,
+	modulus: u64,
+) -> u64 {
+	(((a as u128) * (b as u128)) % modulus as u128) as u64
+}
+
+/// Raises `base` to `exponent` modulo `modulus` via square-and-multiply.
+fn gf_pow(
+	base: u64,
+	exponent: u64,
+	modulus: u64,
+) -> u64 {
+	let mut result: u64 = 1;
+	let mut base = base % modulus;
+	let mut exponent = exponent;
+
+	while exponent > 0 {
+		if exponent & 1 == 1 {
+			result = gf_mul(result, base, modulus);
+		}
+
+		base = gf_mul(base, base, modulus);
+		exponent >>= 1;
+	}
+
+	result
+}
+
+/// Inverts `a` modulo the prime `modulus` via Fermat's little theorem (`a^(modulus - 2)`).
+///
+/// `modulus` must be prime for this to be correct; every plaintext modulus usable with
+/// [`BFVEncoder`] already has to be prime for batching to be enabled at all, so this never
+/// needs to fall back to the extended Euclidean algorithm for a composite modulus.
+fn gf_inv(
+	a: u64,
+	modulus: u64,
+) -> u64 {
+	gf_pow(a, modulus - 2, modulus)
+}
+
+/// Evaluates, at `x`, the unique polynomial of degree `< nodes_x.len()` that passes through
+/// `(nodes_x[i], nodes_y[i])` for every `i`, via Lagrange interpolation over `GF(modulus)`.
+fn lagrange_eval(
+	nodes_x: &[u64],
+	nodes_y: &[u64],
+	x: u64,
+	modulus: u64,
+) -> u64 {
+	let mut result = 0u64;
+
+	for (i, (&xi, &yi)) in nodes_x.iter().zip(nodes_y.iter()).enumerate() {
+		let mut numerator = 1u64;
+		let mut denominator = 1u64;
+
+		for (j, &xj) in nodes_x.iter().enumerate() {
+			if i == j {
+				continue;
+			}
+
+			numerator = gf_mul(numerator, gf_sub(x, xj, modulus), modulus);
+			denominator = gf_mul(denominator, gf_sub(xi, xj, modulus), modulus);
+		}
+
+		let term = gf_mul(yi, gf_mul(numerator, gf_inv(denominator, modulus), modulus), modulus);
+
+		result = gf_add(result, term, modulus);
+	}
+
+	result
 }
 
 impl Drop for BFVEncoder {
@@ -262,6 +619,352 @@ impl Debug for BFVEncoder {
 	) -> std::fmt::Result {
 		f.debug_struct("BFVEncoder")
 			.field("handle", &self.handle)
+			.field("plain_modulus", &self.plain_modulus)
+			.finish()
+	}
+}
+
+impl crate::SlotCount for BFVEncoder {
+	fn get_slot_count(&self) -> usize {
+		self.get_slot_count()
+	}
+}
+
+impl crate::Encoder<i64> for BFVEncoder {
+	type Encoded = Plaintext;
+
+	fn encode(
+		&self,
+		data: &[i64],
+	) -> Result<Self::Encoded> {
+		self.encode_i64(data)
+	}
+
+	fn decode(
+		&self,
+		encoded: &Self::Encoded,
+	) -> Result<Vec<i64>> {
+		self.decode_i64(encoded)
+	}
+}
+
+/// Encodes a single integer into the coefficients of a BFV plaintext polynomial, rather
+/// than into CRT batch slots like [`BFVEncoder`] does.
+///
+/// The encoding computes the base-`b` expansion of the integer and stores the digits as
+/// the coefficients of the plaintext polynomial, e.g. with the default base 2, `26 = 2^4 +
+/// 2^3 + 2^1` becomes `x^4 + x^3 + x`. Negative integers are encoded by storing each negative
+/// digit as `plain_modulus - digit`.
+///
+/// Because homomorphic addition and multiplication grow the magnitude of the polynomial
+/// coefficients, callers must keep the encoded values well clear of `plain_modulus` to avoid
+/// wraparound corrupting the decoded result.
+pub struct IntegerEncoder {
+	plain_modulus: u64,
+	base: u64,
+}
+
+impl IntegerEncoder {
+	/// Creates an `IntegerEncoder` using the default base of 2.
+	///
+	/// * `ctx` - The Context
+	pub fn new(ctx: &Context) -> Result<Self> {
+		Self::with_base(ctx, 2)
+	}
+
+	/// Creates an `IntegerEncoder` using the given base for the digit expansion.
+	///
+	/// * `ctx` - The Context
+	/// * `base` - The base used to expand integers into polynomial coefficients. Must be at
+	///   least 2.
+	pub fn with_base(
+		ctx: &Context,
+		base: u64,
+	) -> Result<Self> {
+		if base < 2 {
+			return Err(Error::InvalidParams);
+		}
+
+		let plain_modulus = ctx
+			.get_first_context_data()?
+			.get_encryption_parameters()?
+			.get_plain_modulus()
+			.value();
+
+		Ok(Self {
+			plain_modulus,
+			base,
+		})
+	}
+
+	/// Encodes a single integer into a plaintext polynomial.
+	///
+	/// * `value` - The integer to encode.
+	pub fn encode(
+		&self,
+		value: i64,
+	) -> Result<Plaintext> {
+		let negative = value < 0;
+		let mut magnitude = value.unsigned_abs();
+
+		let mut digits = vec![];
+
+		if magnitude == 0 {
+			digits.push(0u64);
+		}
+
+		while magnitude > 0 {
+			digits.push(magnitude % self.base);
+			magnitude /= self.base;
+		}
+
+		// Drop trailing (highest-degree) zero coefficients, but always keep at least one.
+		while digits.len() > 1 && *digits.last().unwrap() == 0 {
+			digits.pop();
+		}
+
+		let plaintext = Plaintext::new()?;
+		let mut plaintext = plaintext;
+		plaintext.resize(digits.len());
+
+		for (i, digit) in digits.into_iter().enumerate() {
+			let coeff = if negative && digit != 0 {
+				self.plain_modulus - digit
+			} else {
+				digit
+			};
+
+			plaintext.set_coefficient(i, coeff);
+		}
+
+		Ok(plaintext)
+	}
+
+	/// Decodes a plaintext polynomial, previously produced by [`IntegerEncoder::encode`],
+	/// back into an integer.
+	///
+	/// * `plaintext` - The plaintext to decode.
+	pub fn decode(
+		&self,
+		plaintext: &Plaintext,
+	) -> Result<i64> {
+		let half_modulus = self.plain_modulus / 2;
+		let mut result: i64 = 0;
+		let mut place_value: i64 = 1;
+
+		for i in 0..plaintext.len() {
+			let coeff = plaintext.get_coefficient(i);
+
+			let signed_digit = if coeff > half_modulus {
+				-((self.plain_modulus - coeff) as i64)
+			} else {
+				coeff as i64
+			};
+
+			result += signed_digit * place_value;
+			place_value *= self.base as i64;
+		}
+
+		Ok(result)
+	}
+}
+
+impl Debug for IntegerEncoder {
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result {
+		f.debug_struct("IntegerEncoder")
+			.field("plain_modulus", &self.plain_modulus)
+			.field("base", &self.base)
+			.finish()
+	}
+}
+
+/// Encodes a single rational number into the coefficients of a BFV plaintext polynomial, in
+/// the style of SEAL's old `FractionalEncoder`, rather than into CRT batch slots like
+/// [`BFVEncoder`] does or by naively scaling and rounding like [`BFVEncoder::encode_f64`] does.
+///
+/// A value `r` is split into an integer part `m` and a fractional part `f`. `m`'s base-`base`
+/// digits become the low-degree coefficients `c_0, c_1, ...`, the same way [`IntegerEncoder`]
+/// encodes a whole integer. `f` is instead scaled by `base` one digit at a time and those
+/// digits become the *top* coefficients `c_{N-1}, c_{N-2}, ...`, each stored negated modulo the
+/// plaintext modulus. That's because the plaintext ring is `X^N+1`, so `X^N ≡ -1` and a
+/// coefficient at `X^{N-1-j}` represents `-X^{-(j+1)}`: storing `plain_modulus - digit` there
+/// encodes `+digit * base^{-(j+1)}`. A negative `r` is handled by negating
+/// (`plain_modulus - c`) every nonzero coefficient the positive-`r` encoding above would have
+/// placed, for both the integer and fractional digits.
+///
+/// Unlike [`BFVEncoder::encode_f64`], a value encoded this way keeps its sign and fractional
+/// precision through homomorphic addition and multiplication by another
+/// [`BFVFractionalEncoder`]-encoded value (up to the usual BFV noise budget), since it's a real
+/// positional encoding rather than an integer cast of `value * base`.
+pub struct BFVFractionalEncoder {
+	plain_modulus: u64,
+	base: u64,
+	integer_coeff_count: usize,
+	fraction_coeff_count: usize,
+	poly_modulus_degree: usize,
+}
+
+impl BFVFractionalEncoder {
+	/// Creates a `BFVFractionalEncoder` over the given `Context`.
+	///
+	/// * `ctx` - The Context
+	/// * `base` - the base used to expand the integer and fractional parts into polynomial
+	///   coefficients; must be at least 2.
+	/// * `integer_coeff_count` - how many low-degree coefficients to reserve for the integer
+	///   part's digits.
+	/// * `fraction_coeff_count` - how many top coefficients to reserve for the fractional
+	///   part's digits.
+	///
+	/// `integer_coeff_count + fraction_coeff_count` must not exceed the polynomial modulus
+	/// degree, since the two digit ranges must not overlap.
+	pub fn new(
+		ctx: &Context,
+		base: u64,
+		integer_coeff_count: usize,
+		fraction_coeff_count: usize,
+	) -> Result<Self> {
+		if base < 2 {
+			return Err(Error::InvalidParams);
+		}
+
+		let params = ctx.get_first_context_data()?.get_encryption_parameters()?;
+		let poly_modulus_degree = params.get_poly_modulus_degree() as usize;
+
+		if integer_coeff_count + fraction_coeff_count > poly_modulus_degree {
+			return Err(Error::InvalidParams);
+		}
+
+		Ok(Self {
+			plain_modulus: params.get_plain_modulus().value(),
+			base,
+			integer_coeff_count,
+			fraction_coeff_count,
+			poly_modulus_degree,
+		})
+	}
+
+	/// Negates `digit` modulo the plaintext modulus when `negative` is set and `digit` is
+	/// nonzero; both the integer and fractional digit loops in [`Self::encode`] route their
+	/// placement through this so a negative input flips every nonzero coefficient the same way.
+	fn place(
+		&self,
+		digit: u64,
+		negative: bool,
+	) -> u64 {
+		if negative && digit != 0 {
+			self.plain_modulus - digit
+		} else {
+			digit
+		}
+	}
+
+	/// Encodes a single rational number into a plaintext polynomial.
+	///
+	/// * `value` - the rational number to encode.
+	pub fn encode(
+		&self,
+		value: f64,
+	) -> Result<Plaintext> {
+		let negative = value.is_sign_negative();
+		let magnitude = value.abs();
+
+		let mut integer_part = magnitude.trunc() as u64;
+		let integer_digits: Vec<u64> = (0..self.integer_coeff_count)
+			.map(|_| {
+				let digit = integer_part % self.base;
+				integer_part /= self.base;
+				digit
+			})
+			.collect();
+
+		let mut fraction_part = magnitude.fract();
+		let fraction_digits: Vec<u64> = (0..self.fraction_coeff_count)
+			.map(|_| {
+				fraction_part *= self.base as f64;
+				let digit = fraction_part.trunc() as u64;
+				fraction_part -= digit as f64;
+				digit
+			})
+			.collect();
+
+		let mut plaintext = Plaintext::new()?;
+		plaintext.resize(self.poly_modulus_degree);
+
+		for (i, &digit) in integer_digits.iter().enumerate() {
+			plaintext.set_coefficient(i, self.place(digit, negative));
+		}
+
+		for (j, &digit) in fraction_digits.iter().enumerate() {
+			// `digit` is always placed pre-negated (see the doc comment above), then `place`
+			// negates it again on top when `value` itself is negative.
+			let pre_negated = if digit == 0 { 0 } else { self.plain_modulus - digit };
+			let index = self.poly_modulus_degree - 1 - j;
+
+			plaintext.set_coefficient(index, self.place(pre_negated, negative));
+		}
+
+		Ok(plaintext)
+	}
+
+	/// Decodes a plaintext polynomial, previously produced by [`BFVFractionalEncoder::encode`],
+	/// back into a rational number.
+	///
+	/// * `plaintext` - The plaintext to decode.
+	pub fn decode(
+		&self,
+		plaintext: &Plaintext,
+	) -> Result<f64> {
+		let half_modulus = self.plain_modulus / 2;
+		let signed = |coeff: u64| -> i64 {
+			if coeff > half_modulus {
+				-((self.plain_modulus - coeff) as i64)
+			} else {
+				coeff as i64
+			}
+		};
+		let coeff_or_zero = |index: usize| -> u64 {
+			if index < plaintext.len() {
+				plaintext.get_coefficient(index)
+			} else {
+				0
+			}
+		};
+
+		let mut integer_value: i64 = 0;
+		let mut place_value: i64 = 1;
+		for i in 0..self.integer_coeff_count {
+			integer_value += signed(coeff_or_zero(i)) * place_value;
+			place_value *= self.base as i64;
+		}
+
+		let mut fraction_value: f64 = 0.0;
+		let mut place_value: f64 = 1.0 / self.base as f64;
+		for j in 0..self.fraction_coeff_count {
+			let index = self.poly_modulus_degree - 1 - j;
+
+			// The top coefficients carry the negated digit (see the doc comment above), so the
+			// sign flips back here.
+			fraction_value -= signed(coeff_or_zero(index)) as f64 * place_value;
+			place_value /= self.base as f64;
+		}
+
+		Ok(integer_value as f64 + fraction_value)
+	}
+}
+
+impl Debug for BFVFractionalEncoder {
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result {
+		f.debug_struct("BFVFractionalEncoder")
+			.field("plain_modulus", &self.plain_modulus)
+			.field("base", &self.base)
+			.field("integer_coeff_count", &self.integer_coeff_count)
+			.field("fraction_coeff_count", &self.fraction_coeff_count)
 			.finish()
 	}
 }
@@ -306,6 +1009,33 @@ mod tests {
 		assert_eq!(encoder.get_slot_count(), 8192);
 	}
 
+	#[test]
+	fn encoder_trait_impl_matches_the_inherent_methods() {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		assert_eq!(
+			SlotCount::get_slot_count(&encoder),
+			encoder.get_slot_count()
+		);
+
+		let data: Vec<i64> = vec![1, 2, 3];
+		let encoded = Encoder::encode(&encoder, &data).unwrap();
+		let decoded = Encoder::decode(&encoder, &encoded).unwrap();
+
+		assert_eq!(data, decoded);
+	}
+
 	#[test]
 	fn can_get_encode_and_decode_unsigned() {
 		let params = BFVEncryptionParametersBuilder::new()
@@ -360,6 +1090,55 @@ mod tests {
 		assert_eq!(data, data_2);
 	}
 
+	#[test]
+	fn matrix_encoder_round_trips_both_rows() {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let row_0 = vec![1i64, 2, 3, -4];
+		let row_1 = vec![-5i64, 6, 7, 8];
+
+		let plaintext = encoder.encode_matrix(&[&row_0, &row_1]).unwrap();
+		let decoded = encoder.decode_matrix(&plaintext).unwrap();
+
+		assert_eq!(&decoded[0][..row_0.len()], &row_0[..]);
+		assert_eq!(&decoded[1][..row_1.len()], &row_1[..]);
+	}
+
+	#[test]
+	fn matrix_encoder_rejects_a_row_that_is_too_long() {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let oversized_row = vec![1i64; encoder.get_slot_count()];
+		let row_1 = vec![1i64];
+
+		assert!(matches!(
+			encoder.encode_matrix(&[&oversized_row, &row_1]),
+			Err(Error::InvalidParams)
+		));
+	}
+
 	#[test]
 	fn scalar_encoder_can_encode_decode_signed() {
 		let params = BFVEncryptionParametersBuilder::new()
@@ -424,4 +1203,201 @@ mod tests {
 
 		assert!((decoded[0] - 42f64).abs() < 1e-10);
 	}
+
+	fn mk_int_ctx() -> Context {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(1234)
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[test]
+	fn integer_encoder_can_encode_and_decode_positive() {
+		let ctx = mk_int_ctx();
+		let encoder = IntegerEncoder::new(&ctx).unwrap();
+
+		let encoded = encoder.encode(26).unwrap();
+		assert_eq!(encoded.get_coefficient(0), 0);
+		assert_eq!(encoded.get_coefficient(1), 1);
+		assert_eq!(encoded.get_coefficient(3), 1);
+		assert_eq!(encoded.get_coefficient(4), 1);
+
+		let decoded = encoder.decode(&encoded).unwrap();
+		assert_eq!(decoded, 26);
+	}
+
+	#[test]
+	fn integer_encoder_can_encode_and_decode_negative() {
+		let ctx = mk_int_ctx();
+		let encoder = IntegerEncoder::new(&ctx).unwrap();
+
+		let encoded = encoder.encode(-15).unwrap();
+		let decoded = encoder.decode(&encoded).unwrap();
+
+		assert_eq!(decoded, -15);
+	}
+
+	#[test]
+	fn integer_encoder_can_encode_and_decode_zero() {
+		let ctx = mk_int_ctx();
+		let encoder = IntegerEncoder::new(&ctx).unwrap();
+
+		let encoded = encoder.encode(0).unwrap();
+		let decoded = encoder.decode(&encoded).unwrap();
+
+		assert_eq!(decoded, 0);
+	}
+
+	#[test]
+	fn fractional_encoder_can_encode_and_decode_positive() {
+		let ctx = mk_int_ctx();
+		let encoder = BFVFractionalEncoder::new(&ctx, 2, 16, 16).unwrap();
+
+		let encoded = encoder.encode(42.5).unwrap();
+		let decoded = encoder.decode(&encoded).unwrap();
+
+		assert!((decoded - 42.5).abs() < 1e-6);
+	}
+
+	#[test]
+	fn fractional_encoder_can_encode_and_decode_negative() {
+		let ctx = mk_int_ctx();
+		let encoder = BFVFractionalEncoder::new(&ctx, 2, 16, 16).unwrap();
+
+		let encoded = encoder.encode(-15.25).unwrap();
+		let decoded = encoder.decode(&encoded).unwrap();
+
+		assert!((decoded - -15.25).abs() < 1e-6);
+	}
+
+	#[test]
+	fn fractional_encoder_can_encode_and_decode_zero() {
+		let ctx = mk_int_ctx();
+		let encoder = BFVFractionalEncoder::new(&ctx, 2, 16, 16).unwrap();
+
+		let encoded = encoder.encode(0.0).unwrap();
+		let decoded = encoder.decode(&encoded).unwrap();
+
+		assert_eq!(decoded, 0.0);
+	}
+
+	#[test]
+	fn fractional_encoder_can_encode_and_decode_pure_fraction() {
+		let ctx = mk_int_ctx();
+		let encoder = BFVFractionalEncoder::new(&ctx, 2, 16, 16).unwrap();
+
+		let encoded = encoder.encode(0.125).unwrap();
+		let decoded = encoder.decode(&encoded).unwrap();
+
+		assert!((decoded - 0.125).abs() < 1e-6);
+	}
+
+	#[test]
+	fn fractional_encoder_rejects_a_base_below_two() {
+		let ctx = mk_int_ctx();
+
+		assert!(matches!(
+			BFVFractionalEncoder::new(&ctx, 1, 16, 16),
+			Err(Error::InvalidParams)
+		));
+	}
+
+	#[test]
+	fn fractional_encoder_rejects_digit_counts_that_overlap() {
+		let ctx = mk_int_ctx();
+
+		assert!(matches!(
+			BFVFractionalEncoder::new(&ctx, 2, 8192, 1),
+			Err(Error::InvalidParams)
+		));
+	}
+
+	fn mk_batch_ctx() -> Context {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[test]
+	fn rs_round_trips_with_no_symbols_lost() {
+		let ctx = mk_batch_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		// Each symbol holds 2 bytes at this plaintext modulus's width, so k = 30 data symbols
+		// give room for the 8-byte length prefix plus this 43-byte sentence.
+		let data = b"the quick brown fox jumps over the lazy dog";
+		let plaintext = encoder.encode_rs(data, 30, 40).unwrap();
+
+		let decoded = encoder.decode_rs(&plaintext, 30, 40).unwrap();
+
+		assert_eq!(decoded, data);
+	}
+
+	#[test]
+	fn rs_recovers_from_the_maximum_tolerable_symbol_loss() {
+		let ctx = mk_batch_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let data = b"RSerasur";
+		let plaintext = encoder.encode_rs(data, 8, 12).unwrap();
+		let codeword = encoder.decode_u64(&plaintext).unwrap();
+
+		// Drop all but 8 of the 12 symbols (the tolerable maximum for k = 8), keeping a mix of
+		// systematic and parity positions so this doesn't just exercise the fast path.
+		let surviving: Vec<(usize, u64)> = vec![1, 3, 4, 5, 7, 8, 10, 11]
+			.into_iter()
+			.map(|i| (i, codeword[i]))
+			.collect();
+
+		let decoded = encoder.decode_rs_from_symbols(&surviving, 8, 12).unwrap();
+
+		assert_eq!(decoded, data);
+	}
+
+	#[test]
+	fn rs_reports_too_many_symbols_lost() {
+		let ctx = mk_batch_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let surviving: Vec<(usize, u64)> = vec![(0, 1), (1, 2), (2, 3)];
+
+		assert_eq!(
+			encoder.decode_rs_from_symbols(&surviving, 8, 12),
+			Err(Error::TooManySymbolsLost {
+				k: 8,
+				available: 3
+			})
+		);
+	}
+
+	#[test]
+	fn rs_rejects_n_not_greater_than_k() {
+		let ctx = mk_batch_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		assert!(matches!(encoder.encode_rs(b"data", 8, 8), Err(Error::InvalidParams)));
+	}
+
+	#[test]
+	fn rs_rejects_data_too_large_for_k_symbols() {
+		let ctx = mk_batch_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let data = vec![0u8; 1024];
+
+		assert!(matches!(encoder.encode_rs(&data, 4, 6), Err(Error::InvalidParams)));
+	}
 }
@@ -4,7 +4,7 @@ use std::ptr::null_mut;
 use std::sync::atomic::{AtomicPtr, Ordering};
 
 use crate::error::Result;
-use crate::{bindgen, try_seal, Context, MemoryPool, Plaintext};
+use crate::{bindgen, try_seal, Ciphertext, Context, Encoder, MemoryPool, Plaintext, SlotCount};
 
 /// To create CKKS plaintexts we need a special encoder: there is no other way
 /// to create them. The BatchEncoder cannot be used with the
@@ -78,20 +78,131 @@ impl CKKSEncoder {
 		&self,
 		data: &[f64],
 	) -> Result<Plaintext> {
-		let mem = MemoryPool::new()?;
+		self.encode_f64_at(data, &self.parms_id, self.scale)
+	}
+
+	/// Like [`Self::encode_f64`], but threads an explicit [`MemoryPool`] through the call
+	/// instead of allocating a fresh one.
+	///
+	/// Encoding allocates scratch memory on every call; reuse the same pool across a batch of
+	/// encode calls (e.g. the per-coefficient loop in
+	/// [`crate::CKKSEvaluator::evaluate_polynomial_with_pool`]) to pay that cost once instead of
+	/// once per call.
+	pub fn encode_f64_with_pool(
+		&self,
+		data: &[f64],
+		pool: &MemoryPool,
+	) -> Result<Plaintext> {
+		self.encode_f64_at_with_pool(data, &self.parms_id, self.scale, pool)
+	}
 
+	/// Creates a plaintext from a given matrix of f64 data, encoded at a specific level of the
+	/// modulus switching chain and scale rather than the encoder's defaults.
+	///
+	/// This is needed once a ciphertext has been rescaled down from `ctx.get_first_parms_id()`:
+	/// `add_plain`/`multiply_plain` require the plaintext operand to share the ciphertext's
+	/// `parms_id` and scale, which [`Self::encode_f64`] alone cannot produce.
+	///
+	///  * `data` - The data to encode.
+	///  * `parms_id` - The modulus switching chain level to encode at.
+	///  * `scale` - The scaling factor to encode with.
+	pub fn encode_f64_at(
+		&self,
+		data: &[f64],
+		parms_id: &[u64],
+		scale: f64,
+	) -> Result<Plaintext> {
+		let pool = MemoryPool::new()?;
+
+		self.encode_f64_at_with_pool(data, parms_id, scale, &pool)
+	}
+
+	/// Combines [`Self::encode_f64_at`] and [`Self::encode_f64_with_pool`]: encodes at an
+	/// explicit level and scale using a caller-supplied pool rather than either of the encoder's
+	/// defaults.
+	pub fn encode_f64_at_with_pool(
+		&self,
+		data: &[f64],
+		parms_id: &[u64],
+		scale: f64,
+		pool: &MemoryPool,
+	) -> Result<Plaintext> {
 		let plaintext = Plaintext::new()?;
 
 		// I pinky promise SEAL won't mutate data, the C bindings just aren't
 		// const correct.
 		try_seal!(unsafe {
-			let mut parms_id = self.parms_id.clone();
+			let mut parms_id = parms_id.to_vec();
 			let parms_id_ptr = parms_id.as_mut_ptr();
 			bindgen::CKKSEncoder_Encode1(
 				self.get_handle(),
 				data.len() as u64,
 				data.as_ptr() as *mut f64,
 				parms_id_ptr,
+				scale,
+				plaintext.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok(plaintext)
+	}
+
+	/// Convenience wrapper around [`Self::encode_f64_at`] that encodes at the `parms_id` and
+	/// scale of an existing ciphertext, so the result can be used directly as the plaintext
+	/// operand of `add_plain`/`multiply_plain` against it.
+	pub fn encode_f64_for(
+		&self,
+		data: &[f64],
+		ciphertext: &Ciphertext,
+	) -> Result<Plaintext> {
+		self.encode_f64_at(data, &ciphertext.parms_id()?, ciphertext.scale())
+	}
+
+	/// Combines [`Self::encode_f64_for`] and [`Self::encode_f64_with_pool`]: encodes at the
+	/// `parms_id` and scale of an existing ciphertext using a caller-supplied pool.
+	pub fn encode_f64_for_with_pool(
+		&self,
+		data: &[f64],
+		ciphertext: &Ciphertext,
+		pool: &MemoryPool,
+	) -> Result<Plaintext> {
+		self.encode_f64_at_with_pool(data, &ciphertext.parms_id()?, ciphertext.scale(), pool)
+	}
+
+	/// Creates a plaintext from a given vector of complex numbers, represented as
+	/// `(real, imaginary)` pairs, one per slot.
+	///
+	/// This is the complex counterpart to [`Self::encode_f64`]: CKKS natively supports both,
+	/// since a plaintext's `N/2` slots are really a vector over `C`, and `encode_f64` is simply
+	/// the special case where every imaginary part is zero.
+	///
+	///  * `data` - The complex values to encode
+	pub fn encode_complex(
+		&self,
+		data: &[(f64, f64)],
+	) -> Result<Plaintext> {
+		let mem = MemoryPool::new()?;
+
+		let plaintext = Plaintext::new()?;
+
+		let mut interleaved: Vec<f64> = Vec::with_capacity(data.len() * 2);
+
+		for &(re, im) in data {
+			interleaved.push(re);
+			interleaved.push(im);
+		}
+
+		// I pinky promise SEAL won't mutate data, the C bindings just aren't
+		// const correct.
+		try_seal!(unsafe {
+			let mut parms_id = self.parms_id.clone();
+			let parms_id_ptr = parms_id.as_mut_ptr();
+			bindgen::CKKSEncoder_Encode2(
+				self.get_handle(),
+				data.len() as u64,
+				interleaved.as_mut_ptr(),
+				parms_id_ptr,
 				self.scale,
 				plaintext.get_handle(),
 				mem.get_handle(),
@@ -101,6 +212,42 @@ impl CKKSEncoder {
 		Ok(plaintext)
 	}
 
+	/// Inverse of [`Self::encode_complex`]. This function decodes a given plaintext into a list
+	/// of `(real, imaginary)` pairs.
+	///
+	///  * `plaintext` - The plaintext polynomial to unbatch
+	pub fn decode_complex(
+		&self,
+		plaintext: &Plaintext,
+	) -> Result<Vec<(f64, f64)>> {
+		let slot_count = self.get_slot_count();
+		let mut interleaved: Vec<f64> = Vec::with_capacity(slot_count * 2);
+		let data_ptr = interleaved.as_mut_ptr();
+		let mut size: u64 = 0;
+
+		// I pinky promise SEAL won't mutate data, the C bindings just aren't
+		// const correct.
+		try_seal!(unsafe {
+			bindgen::CKKSEncoder_Decode2(
+				self.get_handle(),
+				plaintext.get_handle(),
+				&mut size,
+				data_ptr,
+				null_mut(),
+			)
+		})?;
+
+		if interleaved.capacity() < size as usize * 2 {
+			panic!("Allocation overflow CKKSEncoder::decode_complex");
+		}
+
+		unsafe {
+			interleaved.set_len(size as usize * 2);
+		}
+
+		Ok(interleaved.chunks_exact(2).map(|c| (c[0], c[1])).collect())
+	}
+
 	/// Inverse of encode. This function decodes a given plaintext into
 	/// a list of f64 elements.
 	///
@@ -108,6 +255,19 @@ impl CKKSEncoder {
 	pub fn decode_f64(
 		&self,
 		plaintext: &Plaintext,
+	) -> Result<Vec<f64>> {
+		let pool = MemoryPool::new()?;
+
+		self.decode_f64_with_pool(plaintext, &pool)
+	}
+
+	/// Like [`Self::decode_f64`], but threads an explicit [`MemoryPool`] through the call
+	/// instead of allocating a fresh one. See [`Self::encode_f64_with_pool`] for when this is
+	/// worth doing.
+	pub fn decode_f64_with_pool(
+		&self,
+		plaintext: &Plaintext,
+		pool: &MemoryPool,
 	) -> Result<Vec<f64>> {
 		let mut data = Vec::with_capacity(self.get_slot_count());
 		let data_ptr = data.as_mut_ptr();
@@ -121,7 +281,7 @@ impl CKKSEncoder {
 				plaintext.get_handle(),
 				&mut size,
 				data_ptr,
-				null_mut(),
+				pool.get_handle(),
 			)
 		})?;
 
@@ -158,6 +318,30 @@ impl Drop for CKKSEncoder {
 	}
 }
 
+impl SlotCount for CKKSEncoder {
+	fn get_slot_count(&self) -> usize {
+		self.get_slot_count()
+	}
+}
+
+impl Encoder<f64> for CKKSEncoder {
+	type Encoded = Plaintext;
+
+	fn encode(
+		&self,
+		data: &[f64],
+	) -> Result<Self::Encoded> {
+		self.encode_f64(data)
+	}
+
+	fn decode(
+		&self,
+		encoded: &Self::Encoded,
+	) -> Result<Vec<f64>> {
+		self.decode_f64(encoded)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::{
@@ -313,4 +497,112 @@ mod tests {
 		// Assert that the original and decoded data match within a small tolerance
 		float_iter_assert_eq(data, decoded_data);
 	}
+
+	/// Test encoding and decoding of a vector of complex numbers in CKKS.
+	#[test]
+	fn can_get_encode_and_decode_complex() {
+		let ctx = create_ckks_context(DegreeType::D8192, &[60, 40, 40, 60]).unwrap();
+
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+
+		let data: Vec<(f64, f64)> = (0..encoder.get_slot_count())
+			.map(|i| (i as f64 - 2048.0, 2048.0 - i as f64))
+			.collect();
+
+		let plaintext = encoder.encode_complex(&data).unwrap();
+		let decoded = encoder.decode_complex(&plaintext).unwrap();
+
+		for ((re, im), (expected_re, expected_im)) in decoded.into_iter().zip(data) {
+			float_assert_eq(re, expected_re);
+			float_assert_eq(im, expected_im);
+		}
+	}
+
+	/// Test that the generic [`crate::Encoder`]/[`crate::SlotCount`] trait impls agree with the
+	/// inherent `encode_f64`/`decode_f64`/`get_slot_count` methods they forward to, since this is
+	/// what lets [`crate::TensorEncoder`]-style generic wrappers drive CKKS.
+	#[test]
+	fn encoder_trait_impl_matches_the_inherent_methods() {
+		use crate::{Encoder, SlotCount};
+
+		let ctx = create_ckks_context(DegreeType::D8192, &[60, 40, 40, 60]).unwrap();
+
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+
+		assert_eq!(
+			SlotCount::get_slot_count(&encoder),
+			encoder.get_slot_count()
+		);
+
+		let data: Vec<f64> = (0..encoder.get_slot_count())
+			.map(|i| i as f64 - 2048.0)
+			.collect();
+
+		let plaintext = Encoder::encode(&encoder, &data).unwrap();
+		let decoded = Encoder::decode(&encoder, &plaintext).unwrap();
+
+		float_iter_assert_eq(data, decoded);
+	}
+
+	/// Test that encoding/decoding through a caller-supplied `MemoryPool` produces the same
+	/// result as the default, per-call pool.
+	#[test]
+	fn can_encode_and_decode_with_an_explicit_pool() {
+		use crate::MemoryPool;
+
+		let ctx = create_ckks_context(DegreeType::D8192, &[60, 40, 40, 60]).unwrap();
+
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let pool = MemoryPool::new().unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count())
+			.map(|i| i as f64 - 2048.0)
+			.collect();
+
+		let plaintext = encoder.encode_f64_with_pool(&data, &pool).unwrap();
+		let decoded = encoder.decode_f64_with_pool(&plaintext, &pool).unwrap();
+
+		float_iter_assert_eq(data, decoded);
+	}
+
+	/// Test that a plaintext encoded to match a rescaled ciphertext's level and scale can be
+	/// added to it, which fails if the two don't share the same `parms_id` and scale.
+	#[test]
+	fn encode_f64_for_matches_a_rescaled_ciphertext() {
+		use crate::{Decryptor, Encryptor, Evaluator, KeyGenerator};
+
+		// Rescaling needs the modulus switching chain, which `create_ckks_context` doesn't build.
+		let params = CKKSEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[60, 40, 40, 60]).unwrap(),
+			)
+			.build()
+			.unwrap();
+		let ctx = Context::new(&params, true, SecurityLevel::TC128).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = crate::CKKSEvaluator::new(&ctx).unwrap();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data = vec![3.0; encoder.get_slot_count()];
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let squared = evaluator.square(&ciphertext).unwrap();
+		let rescaled = evaluator.rescale_to_next(&squared).unwrap();
+
+		let addend = encoder.encode_f64_for(&data, &rescaled).unwrap();
+		let summed = evaluator.add_plain(&rescaled, &addend).unwrap();
+
+		let decrypted = decryptor.decrypt(&summed).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		float_iter_assert_eq(decoded, data.iter().map(|v| v * v + v));
+	}
 }
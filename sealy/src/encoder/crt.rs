@@ -0,0 +1,268 @@
+use std::fmt::Debug;
+
+use crate::{error::*, Context, IntegerEncoder, Plaintext};
+
+/// Encodes integers wider than any single plaintext modulus can hold across several BFV
+/// contexts at once, using the Chinese Remainder Theorem to split a value into one residue
+/// per context and recombine it afterwards.
+///
+/// Each of the `r` underlying contexts contributes one coprime modulus `t_1, ..., t_r`; a
+/// value `x` is encoded as the residues `x mod t_i`, one [`Plaintext`] per residue, via an
+/// [`IntegerEncoder`] over that context. Addition and multiplication can be carried out
+/// homomorphically on each residue's ciphertext independently (mirroring the multi-limb radix
+/// approach integer FHE libraries use), then [`CrtEncoder::decode_crt`] recombines the
+/// decrypted residues back into a single integer via the standard CRT reconstruction formula
+/// `x = sum_i r_i * M_i * (M_i^-1 mod t_i) mod M`, where `M = prod_i t_i`.
+///
+/// # Limitations
+/// This crate has no arbitrary-precision integer type, so the reconstructed value is an
+/// `i128` rather than an unbounded big integer; `M = prod_i t_i` must fit in a `u128`, and the
+/// value being encoded must fit in `(-M/2, M/2]`. That still comfortably covers values well
+/// beyond what a single plaintext modulus can hold (the motivating case for this type), just
+/// not arbitrary precision.
+pub struct CrtEncoder {
+	moduli: Vec<u64>,
+	product: u128,
+	encoders: Vec<IntegerEncoder>,
+}
+
+impl CrtEncoder {
+	/// Creates a `CrtEncoder` from one context per CRT limb.
+	///
+	/// Each context's plaintext modulus becomes one of the `t_i`; they must be pairwise
+	/// coprime (as any valid CRT moduli set must be) or this returns
+	/// [`Error::InvalidParams`]. At least two contexts are required, since a single modulus
+	/// wouldn't need CRT reconstruction at all.
+	pub fn new(contexts: &[Context]) -> Result<Self> {
+		if contexts.len() < 2 {
+			return Err(Error::InvalidParams);
+		}
+
+		let mut moduli = Vec::with_capacity(contexts.len());
+		let mut encoders = Vec::with_capacity(contexts.len());
+
+		for ctx in contexts {
+			let modulus = ctx
+				.get_first_context_data()?
+				.get_encryption_parameters()?
+				.get_plain_modulus()
+				.value();
+
+			moduli.push(modulus);
+			encoders.push(IntegerEncoder::new(ctx)?);
+		}
+
+		for i in 0..moduli.len() {
+			for j in (i + 1)..moduli.len() {
+				if gcd(moduli[i], moduli[j]) != 1 {
+					return Err(Error::InvalidParams);
+				}
+			}
+		}
+
+		let product = moduli.iter().try_fold(1u128, |acc, &t| {
+			acc.checked_mul(t as u128).ok_or(Error::InvalidParams)
+		})?;
+
+		Ok(Self {
+			moduli,
+			product,
+			encoders,
+		})
+	}
+
+	/// Returns `M = prod_i t_i`, the modulus of the combined residue ring. Values passed to
+	/// [`Self::encode_crt`] must fit in `(-M/2, M/2]`.
+	pub fn modulus(&self) -> u128 {
+		self.product
+	}
+
+	/// Returns the number of CRT limbs/channels this encoder was constructed with.
+	pub fn channel_count(&self) -> usize {
+		self.moduli.len()
+	}
+
+	/// Splits `value` into one residue plaintext per limb, via `value mod t_i` encoded through
+	/// that limb's [`IntegerEncoder`].
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidParams`] if `value` doesn't fit in `(-M/2, M/2]` for `M =
+	/// `[`Self::modulus`].
+	pub fn encode_crt(
+		&self,
+		value: i128,
+	) -> Result<Vec<Plaintext>> {
+		let half = (self.product / 2) as i128;
+
+		if value > half || value < -half {
+			return Err(Error::InvalidParams);
+		}
+
+		self.moduli
+			.iter()
+			.zip(self.encoders.iter())
+			.map(|(&modulus, encoder)| {
+				let residue = value.rem_euclid(modulus as i128) as i64;
+
+				encoder.encode(residue)
+			})
+			.collect()
+	}
+
+	/// Recombines residue plaintexts produced by [`Self::encode_crt`] (after any homomorphic
+	/// computation and decryption) back into the original integer, via the standard CRT
+	/// reconstruction formula, mapped to its balanced (signed) representative modulo `M`.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidParams`] if `plaintexts.len()` doesn't match the number of
+	/// limbs this encoder was constructed with.
+	pub fn decode_crt(
+		&self,
+		plaintexts: &[Plaintext],
+	) -> Result<i128> {
+		if plaintexts.len() != self.moduli.len() {
+			return Err(Error::InvalidParams);
+		}
+
+		let product = self.product as i128;
+		let mut accumulator: i128 = 0;
+
+		for ((&modulus, encoder), plaintext) in
+			self.moduli.iter().zip(self.encoders.iter()).zip(plaintexts)
+		{
+			let modulus = modulus as i128;
+			let residue = (encoder.decode(plaintext)? as i128).rem_euclid(modulus);
+
+			let limb_modulus = product / modulus;
+			let inverse = mod_inverse(limb_modulus.rem_euclid(modulus), modulus);
+
+			let term = (residue * limb_modulus).rem_euclid(product) * inverse % product;
+
+			accumulator = (accumulator + term).rem_euclid(product);
+		}
+
+		Ok(if accumulator > product / 2 {
+			accumulator - product
+		} else {
+			accumulator
+		})
+	}
+}
+
+impl Debug for CrtEncoder {
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result {
+		f.debug_struct("CrtEncoder")
+			.field("moduli", &self.moduli)
+			.field("product", &self.product)
+			.finish()
+	}
+}
+
+/// Greatest common divisor via the Euclidean algorithm.
+fn gcd(
+	a: u64,
+	b: u64,
+) -> u64 {
+	if b == 0 {
+		a
+	} else {
+		gcd(b, a % b)
+	}
+}
+
+/// Inverts `a` modulo `m` via the extended Euclidean algorithm.
+///
+/// Callers in this module only ever pass an `a` already known to be coprime to `m` (since
+/// [`CrtEncoder::new`] rejects non-pairwise-coprime moduli), so this assumes a solution exists
+/// rather than returning an `Option`.
+fn mod_inverse(
+	a: i128,
+	m: i128,
+) -> i128 {
+	let (mut old_r, mut r) = (a, m);
+	let (mut old_s, mut s) = (1i128, 0i128);
+
+	while r != 0 {
+		let quotient = old_r / r;
+
+		let next_r = old_r - quotient * r;
+		old_r = r;
+		r = next_r;
+
+		let next_s = old_s - quotient * s;
+		old_s = s;
+		s = next_s;
+	}
+
+	old_s.rem_euclid(m)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		BFVEncryptionParametersBuilder, CoefficientModulusFactory, DegreeType, SecurityLevel,
+	};
+
+	fn mk_ctx(plain_modulus: u64) -> Context {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(plain_modulus)
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[test]
+	fn round_trips_a_positive_value_beyond_a_single_modulus() {
+		let contexts = vec![mk_ctx(65537), mk_ctx(65539), mk_ctx(65543)];
+		let encoder = CrtEncoder::new(&contexts).unwrap();
+
+		let value: i128 = 123_456_789_012;
+		let plaintexts = encoder.encode_crt(value).unwrap();
+		let decoded = encoder.decode_crt(&plaintexts).unwrap();
+
+		assert_eq!(decoded, value);
+	}
+
+	#[test]
+	fn round_trips_a_negative_value() {
+		let contexts = vec![mk_ctx(65537), mk_ctx(65539), mk_ctx(65543)];
+		let encoder = CrtEncoder::new(&contexts).unwrap();
+
+		let value: i128 = -987_654_321;
+		let plaintexts = encoder.encode_crt(value).unwrap();
+		let decoded = encoder.decode_crt(&plaintexts).unwrap();
+
+		assert_eq!(decoded, value);
+	}
+
+	#[test]
+	fn rejects_non_coprime_moduli() {
+		let contexts = vec![mk_ctx(100), mk_ctx(200)];
+
+		assert!(matches!(CrtEncoder::new(&contexts), Err(Error::InvalidParams)));
+	}
+
+	#[test]
+	fn rejects_a_value_outside_the_representable_range() {
+		let contexts = vec![mk_ctx(97), mk_ctx(101)];
+		let encoder = CrtEncoder::new(&contexts).unwrap();
+
+		assert!(matches!(encoder.encode_crt(1_000_000), Err(Error::InvalidParams)));
+	}
+
+	#[test]
+	fn rejects_a_single_context() {
+		let contexts = vec![mk_ctx(65537)];
+
+		assert!(matches!(CrtEncoder::new(&contexts), Err(Error::InvalidParams)));
+	}
+}
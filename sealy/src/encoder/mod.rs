@@ -6,6 +6,9 @@ pub mod bfv;
 /// CKKS encoder.
 pub mod ckks;
 
+/// CRT multi-plaintext integer encoder.
+pub mod crt;
+
 /// An interface for encoding and decoding data.
 pub trait Encoder<T>: SlotCount {
 	/// The type of the encoded data.
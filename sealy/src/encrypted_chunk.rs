@@ -0,0 +1,237 @@
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce as AesNonce};
+use argon2::Argon2;
+use chacha20poly1305::{ChaCha20Poly1305, Nonce as ChaChaNonce};
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use crate::error::*;
+
+/// The AEAD cipher an [`EncryptedChunk`] was (or should be) sealed with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EncryptionAlgorithm {
+	/// AES-256 in Galois/Counter Mode.
+	AesGcm = 1,
+	/// ChaCha20-Poly1305.
+	ChaCha20Poly1305 = 2,
+}
+
+impl EncryptionAlgorithm {
+	fn from_u8(value: u8) -> Result<Self> {
+		match value {
+			1 => Ok(Self::AesGcm),
+			2 => Ok(Self::ChaCha20Poly1305),
+			_ => Err(Error::InvalidSerializedData),
+		}
+	}
+}
+
+/// Magic bytes identifying a blob produced by [`EncryptedChunk::seal`].
+const CHUNK_MAGIC: [u8; 4] = *b"SLYC";
+
+/// The current envelope format. Bump this if the header layout ever changes.
+const CHUNK_VERSION: u8 = 1;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// `magic (4) + version (1) + algorithm (1) + salt (16)`.
+const HEADER_LEN: usize = 4 + 1 + 1 + SALT_LEN;
+
+/// Derives a 256-bit key from `password` and `salt` with Argon2id.
+fn derive_key(
+	password: &str,
+	salt: &[u8],
+) -> Result<[u8; 32]> {
+	let mut key = [0u8; 32];
+
+	Argon2::default()
+		.hash_password_into(password.as_bytes(), salt, &mut key)
+		.map_err(|_| Error::InvalidArgument)?;
+
+	Ok(key)
+}
+
+fn encrypt_frame(
+	algorithm: EncryptionAlgorithm,
+	key: &[u8; 32],
+	nonce: &[u8; NONCE_LEN],
+	frame: &[u8],
+) -> Result<Vec<u8>> {
+	match algorithm {
+		EncryptionAlgorithm::AesGcm => {
+			let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| Error::InvalidArgument)?;
+
+			cipher
+				.encrypt(AesNonce::from_slice(nonce), frame)
+				.map_err(|_| Error::Unexpected)
+		}
+		EncryptionAlgorithm::ChaCha20Poly1305 => {
+			let cipher =
+				ChaCha20Poly1305::new_from_slice(key).map_err(|_| Error::InvalidArgument)?;
+
+			cipher
+				.encrypt(ChaChaNonce::from_slice(nonce), frame)
+				.map_err(|_| Error::Unexpected)
+		}
+	}
+}
+
+fn decrypt_frame(
+	algorithm: EncryptionAlgorithm,
+	key: &[u8; 32],
+	nonce: &[u8; NONCE_LEN],
+	frame: &[u8],
+) -> Result<Vec<u8>> {
+	match algorithm {
+		EncryptionAlgorithm::AesGcm => {
+			let cipher = Aes256Gcm::new_from_slice(key).map_err(|_| Error::InvalidArgument)?;
+
+			cipher
+				.decrypt(AesNonce::from_slice(nonce), frame)
+				.map_err(|_| Error::DecryptionFailed)
+		}
+		EncryptionAlgorithm::ChaCha20Poly1305 => {
+			let cipher =
+				ChaCha20Poly1305::new_from_slice(key).map_err(|_| Error::InvalidArgument)?;
+
+			cipher
+				.decrypt(ChaChaNonce::from_slice(nonce), frame)
+				.map_err(|_| Error::DecryptionFailed)
+		}
+	}
+}
+
+/// A password-protected, self-describing AEAD envelope around a list of raw byte chunks, such
+/// as the ones produced by [`crate::ext::tensor::ToChunk::to_chunk`].
+///
+/// The encryption key is derived from the password with Argon2id and a random 16-byte salt,
+/// and each chunk ("frame") is sealed independently with its own fresh random 12-byte nonce
+/// under either AES-256-GCM or ChaCha20-Poly1305. The output is prefixed with a small header
+/// (a magic tag, a one-byte algorithm selector, and the salt) so [`EncryptedChunk::open`] is
+/// self-describing and doesn't need the caller to remember which cipher or salt was used.
+pub struct EncryptedChunk;
+
+impl EncryptedChunk {
+	/// Seals `chunks` into a single password-protected blob, encrypting each chunk
+	/// independently under `algorithm`.
+	pub fn seal(
+		chunks: &[Vec<u8>],
+		password: &str,
+		algorithm: EncryptionAlgorithm,
+	) -> Result<Vec<u8>> {
+		let mut salt = [0u8; SALT_LEN];
+		OsRng.fill_bytes(&mut salt);
+
+		let key = derive_key(password, &salt)?;
+
+		let mut out = Vec::new();
+		out.extend_from_slice(&CHUNK_MAGIC);
+		out.push(CHUNK_VERSION);
+		out.push(algorithm as u8);
+		out.extend_from_slice(&salt);
+
+		for chunk in chunks {
+			let mut nonce = [0u8; NONCE_LEN];
+			OsRng.fill_bytes(&mut nonce);
+
+			let ciphertext = encrypt_frame(algorithm, &key, &nonce, chunk)?;
+
+			out.extend_from_slice(&nonce);
+			out.extend_from_slice(&(ciphertext.len() as u64).to_le_bytes());
+			out.extend_from_slice(&ciphertext);
+		}
+
+		Ok(out)
+	}
+
+	/// Opens a blob produced by [`EncryptedChunk::seal`], returning the original chunks.
+	///
+	/// Returns [`Error::InvalidSerializedData`] if the header is malformed, and
+	/// [`Error::DecryptionFailed`] if `password` is wrong or a frame was tampered with.
+	pub fn open(
+		data: &[u8],
+		password: &str,
+	) -> Result<Vec<Vec<u8>>> {
+		if data.len() < HEADER_LEN || data[0..4] != CHUNK_MAGIC {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		if data[4] != CHUNK_VERSION {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let algorithm = EncryptionAlgorithm::from_u8(data[5])?;
+		let salt = &data[6..6 + SALT_LEN];
+		let key = derive_key(password, salt)?;
+
+		let mut offset = HEADER_LEN;
+		let mut chunks = Vec::new();
+
+		while offset < data.len() {
+			if data.len() - offset < NONCE_LEN + 8 {
+				return Err(Error::InvalidSerializedData);
+			}
+
+			let nonce: [u8; NONCE_LEN] = data[offset..offset + NONCE_LEN].try_into().unwrap();
+			offset += NONCE_LEN;
+
+			let len = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize;
+			offset += 8;
+
+			if data.len() - offset < len {
+				return Err(Error::InvalidSerializedData);
+			}
+
+			chunks.push(decrypt_frame(algorithm, &key, &nonce, &data[offset..offset + len])?);
+			offset += len;
+		}
+
+		Ok(chunks)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn can_round_trip_with_aes_gcm() {
+		let chunks = vec![b"hello".to_vec(), b"world".to_vec()];
+		let sealed = EncryptedChunk::seal(&chunks, "hunter2", EncryptionAlgorithm::AesGcm).unwrap();
+
+		let opened = EncryptedChunk::open(&sealed, "hunter2").unwrap();
+
+		assert_eq!(chunks, opened);
+	}
+
+	#[test]
+	fn can_round_trip_with_chacha20poly1305() {
+		let chunks = vec![b"hello".to_vec(), b"world".to_vec()];
+		let sealed =
+			EncryptedChunk::seal(&chunks, "hunter2", EncryptionAlgorithm::ChaCha20Poly1305)
+				.unwrap();
+
+		let opened = EncryptedChunk::open(&sealed, "hunter2").unwrap();
+
+		assert_eq!(chunks, opened);
+	}
+
+	#[test]
+	fn rejects_the_wrong_password() {
+		let chunks = vec![b"hello".to_vec()];
+		let sealed = EncryptedChunk::seal(&chunks, "hunter2", EncryptionAlgorithm::AesGcm).unwrap();
+
+		let result = EncryptedChunk::open(&sealed, "wrong password");
+
+		assert!(matches!(result, Err(Error::DecryptionFailed)));
+	}
+
+	#[test]
+	fn rejects_garbage_bytes() {
+		let result = EncryptedChunk::open(b"not an envelope", "hunter2");
+
+		assert!(matches!(result, Err(Error::InvalidSerializedData)));
+	}
+}
@@ -10,10 +10,26 @@ use crate::error::*;
 use crate::poly_array::PolynomialArray;
 use crate::try_seal;
 use crate::{
-	Asym, AsymmetricComponents, Ciphertext, Context, Plaintext, PublicKey, SecretKey, Sym, SymAsym,
-	SymmetricComponents,
+	Asym, AsymmetricComponents, Ciphertext, CompactCiphertext, Context, MemoryPool, Plaintext,
+	PublicKey, SecretKey, Sym, SymAsym, SymmetricComponents,
 };
 
+/// A type capable of encrypting a [`Plaintext`] into a [`Ciphertext`], independent of whether
+/// the underlying encryptor uses asymmetric (public-key) or symmetric (secret-key) encryption.
+///
+/// This lets generic code accept "any encryptor" — including as a trait object — without
+/// committing to a particular encryption mode at compile time.
+pub trait Encrypt {
+	/// The error produced when encryption fails.
+	type Error;
+
+	/// Encrypts `plaintext`, returning the resulting ciphertext.
+	fn encrypt(
+		&self,
+		plaintext: &Plaintext,
+	) -> std::result::Result<Ciphertext, Self::Error>;
+}
+
 /// Encrypts Plaintext objects into Ciphertext objects.
 ///
 /// Constructing an Encryptor requires a SEALContext with valid encryption parameters, the public
@@ -198,6 +214,35 @@ impl<T: component_marker::Asym> Encryptor<T> {
 		Ok(ciphertext)
 	}
 
+	/// Encrypts a plaintext with the public key and returns the ciphertext as a
+	/// serializable object, allocating scratch memory from `pool` instead of the
+	/// global memory pool.
+	///
+	/// Sharing a single Encryptor across threads and giving each thread its own
+	/// MemoryPoolHandle avoids the allocation contention that falling back to the
+	/// global pool would otherwise cause.
+	///
+	/// * `plainext` - The plaintext to encrypt.
+	/// * `pool` - The memory pool to allocate scratch space from.
+	pub fn encrypt_with_pool(
+		&self,
+		plaintext: &Plaintext,
+		pool: &MemoryPool,
+	) -> Result<Ciphertext> {
+		let ciphertext = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Encryptor_Encrypt(
+				self.get_handle(),
+				plaintext.get_handle(),
+				ciphertext.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok(ciphertext)
+	}
+
 	/// Encrypts a plaintext with the public key and returns the ciphertext as a
 	/// serializable object. Also returns the u and e values used in encrypting
 	/// the value.
@@ -237,6 +282,41 @@ impl<T: component_marker::Asym> Encryptor<T> {
 		))
 	}
 
+	/// Encrypts a plaintext with the public key and returns the ciphertext as a
+	/// serializable object along with the u and e values used in encrypting it,
+	/// allocating scratch memory from `pool` instead of the global memory pool.
+	///
+	/// * `plainext` - The plaintext to encrypt.
+	/// * `pool` - The memory pool to allocate scratch space from.
+	pub fn encrypt_return_components_with_pool(
+		&self,
+		plaintext: &Plaintext,
+		pool: &MemoryPool,
+	) -> Result<(Ciphertext, AsymmetricComponents)> {
+		let ciphertext = Ciphertext::new()?;
+		let u_destination = PolynomialArray::new()?;
+		let e_destination = PolynomialArray::new()?;
+		let r_destination = Plaintext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Encryptor_EncryptReturnComponents(
+				self.get_handle(),
+				plaintext.get_handle(),
+				true,
+				ciphertext.get_handle(),
+				u_destination.get_handle(),
+				e_destination.get_handle(),
+				r_destination.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok((
+			ciphertext,
+			AsymmetricComponents::new(u_destination, e_destination, r_destination),
+		))
+	}
+
 	/// DO NOT USE THIS FUNCTION IN PRODUCTION: IT PRODUCES DETERMINISTIC
 	/// ENCRYPTIONS. IT IS INHERENTLY INSECURE, AND ONLY MEANT FOR TESTING OR
 	/// DEMONSTRATION PURPOSES.
@@ -330,6 +410,17 @@ impl<T: component_marker::Asym> Encryptor<T> {
 	}
 }
 
+impl Encrypt for AsymmetricEncryptor {
+	type Error = Error;
+
+	fn encrypt(
+		&self,
+		plaintext: &Plaintext,
+	) -> Result<Ciphertext> {
+		self.encrypt(plaintext)
+	}
+}
+
 impl<T: component_marker::Sym> Encryptor<T> {
 	/// Encrypts a plaintext with the secret key and returns the ciphertext as
 	/// a serializable object.
@@ -363,6 +454,88 @@ impl<T: component_marker::Sym> Encryptor<T> {
 		Ok(ciphertext)
 	}
 
+	/// Encrypts a plaintext with the secret key and returns the ciphertext as a
+	/// serializable object, allocating scratch memory from `pool` instead of the
+	/// global memory pool.
+	///
+	/// * `plainext` - The plaintext to encrypt.
+	/// * `pool` - The memory pool to allocate scratch space from.
+	pub fn encrypt_symmetric_with_pool(
+		&self,
+		plaintext: &Plaintext,
+		pool: &MemoryPool,
+	) -> Result<Ciphertext> {
+		let ciphertext = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Encryptor_EncryptSymmetric(
+				self.get_handle(),
+				plaintext.get_handle(),
+				false,
+				ciphertext.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok(ciphertext)
+	}
+
+	/// Encrypts a plaintext with the secret key using SEAL's seed-compression, returning a
+	/// [`CompactCiphertext`] whose noise polynomial has been replaced by the PRNG seed that
+	/// generated it. This roughly halves the size of the serialized ciphertext; a receiver
+	/// holding the matching `Context` must call [`CompactCiphertext::expand`] before
+	/// evaluating or decrypting it.
+	///
+	/// * `plainext` - The plaintext to encrypt.
+	pub fn encrypt_symmetric_compact(
+		&self,
+		plaintext: &Plaintext,
+	) -> Result<CompactCiphertext> {
+		let ciphertext = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Encryptor_EncryptSymmetric(
+				self.get_handle(),
+				plaintext.get_handle(),
+				true,
+				ciphertext.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(CompactCiphertext::from_ciphertext(ciphertext))
+	}
+
+	/// Encrypts a plaintext with the secret key using SEAL's seed-compression, returning a
+	/// [`CompactCiphertext`] along with the e (noise) and r (remainder) values used in
+	/// encrypting it.
+	///
+	/// * `plainext` - The plaintext to encrypt.
+	pub fn encrypt_symmetric_return_components_compact(
+		&self,
+		plaintext: &Plaintext,
+	) -> Result<(CompactCiphertext, SymmetricComponents)> {
+		let ciphertext = Ciphertext::new()?;
+		let e_destination = PolynomialArray::new()?;
+		let r_destination = Plaintext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Encryptor_EncryptSymmetricReturnComponentsCompact(
+				self.get_handle(),
+				plaintext.get_handle(),
+				ciphertext.get_handle(),
+				e_destination.get_handle(),
+				r_destination.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok((
+			CompactCiphertext::from_ciphertext(ciphertext),
+			SymmetricComponents::new(e_destination, r_destination),
+		))
+	}
+
 	/// DO NOT USE THIS FUNCTION IN PRODUCTION: IT PRODUCES DETERMINISTIC
 	/// ENCRYPTIONS. IT IS INHERENTLY INSECURE, AND ONLY MEANT FOR TESTING OR
 	/// DEMONSTRATION PURPOSES.
@@ -440,6 +613,39 @@ impl<T: component_marker::Sym> Encryptor<T> {
 		))
 	}
 
+	/// Encrypts a plaintext with the secret key and returns the ciphertext as a
+	/// serializable object along with the e (noise) and r (remainder) values used
+	/// in encrypting it, allocating scratch memory from `pool` instead of the
+	/// global memory pool.
+	///
+	/// * `plainext` - The plaintext to encrypt.
+	/// * `pool` - The memory pool to allocate scratch space from.
+	pub fn encrypt_symmetric_return_components_with_pool(
+		&self,
+		plaintext: &Plaintext,
+		pool: &MemoryPool,
+	) -> Result<(Ciphertext, SymmetricComponents)> {
+		let ciphertext = Ciphertext::new()?;
+		let e_destination = PolynomialArray::new()?;
+		let r_destination = Plaintext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Encryptor_EncryptSymmetricReturnComponents(
+				self.get_handle(),
+				plaintext.get_handle(),
+				ciphertext.get_handle(),
+				e_destination.get_handle(),
+				r_destination.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok((
+			ciphertext,
+			SymmetricComponents::new(e_destination, r_destination),
+		))
+	}
+
 	/// DO NOT USE THIS FUNCTION IN PRODUCTION: IT PRODUCES DETERMINISTIC
 	/// ENCRYPTIONS. IT IS INHERENTLY INSECURE, AND ONLY MEANT FOR TESTING OR
 	/// DEMONSTRATION PURPOSES.
@@ -486,6 +692,17 @@ impl<T: component_marker::Sym> Encryptor<T> {
 	}
 }
 
+impl Encrypt for SymmetricEncryptor {
+	type Error = Error;
+
+	fn encrypt(
+		&self,
+		plaintext: &Plaintext,
+	) -> Result<Ciphertext> {
+		self.encrypt_symmetric(plaintext)
+	}
+}
+
 impl<T> Drop for Encryptor<T> {
 	fn drop(&mut self) {
 		try_seal!(unsafe { bindgen::Encryptor_Destroy(self.get_handle()) })
@@ -551,4 +768,62 @@ mod tests {
 
 		std::mem::drop(encryptor);
 	}
+
+	#[test]
+	fn can_encrypt_with_explicit_pool() {
+		let ctx = mk_ctx(|b| {
+			b.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+		});
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor =
+			Encryptor::with_public_and_secret_key(&ctx, &public_key, &secret_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let pool = MemoryPool::new().unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+
+		let ciphertext = encryptor.encrypt_with_pool(&plaintext, &pool).unwrap();
+		let decrypted = decryptor.decrypt(&ciphertext).unwrap();
+
+		assert_eq!(data, encoder.decode_u64(&decrypted).unwrap());
+	}
+
+	#[test]
+	fn can_encrypt_generically_over_encryptor_kind() {
+		fn encrypt_with<E: Encrypt>(
+			encryptor: &E,
+			plaintext: &Plaintext,
+		) -> Ciphertext {
+			encryptor.encrypt(plaintext).unwrap()
+		}
+
+		let ctx = mk_ctx(|b| {
+			b.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+		});
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let asym_encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let sym_encryptor = Encryptor::with_secret_key(&ctx, &secret_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+
+		let from_asym = encrypt_with(&asym_encryptor, &plaintext);
+		let from_sym = encrypt_with(&sym_encryptor, &plaintext);
+
+		assert_eq!(data, encoder.decode_u64(&decryptor.decrypt(&from_asym).unwrap()).unwrap());
+		assert_eq!(data, encoder.decode_u64(&decryptor.decrypt(&from_sym).unwrap()).unwrap());
+	}
 }
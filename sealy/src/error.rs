@@ -0,0 +1,202 @@
+use std::os::raw::c_long;
+
+use crate::bindgen;
+
+/// The result type for this crate. This is a type alias for `std::result::Result<T, Error>`.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The set of errors this library produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+	/// The underlying SEAL library returned an internal error with the given HRESULT value.
+	InternalError(i64),
+
+	/// An argument passed to a SEAL API call was invalid.
+	InvalidArgument,
+
+	/// A SEAL allocation failed because the system ran out of memory.
+	OutOfMemory,
+
+	/// An I/O error occurred, e.g. while reading or writing a serialized object.
+	IoError,
+
+	/// An unexpected, otherwise unclassified error occurred inside SEAL.
+	Unexpected,
+
+	/// A SEAL API call unexpectedly returned a null pointer.
+	InvalidPointer,
+
+	/// The polynomial modulus degree was not set on an encryption parameters builder.
+	DegreeNotSet,
+
+	/// The coefficient modulus was not set on an encryption parameters builder.
+	CoefficientModulusNotSet,
+
+	/// The plaintext modulus was not set on an encryption parameters builder.
+	PlainModulusNotSet,
+
+	/// An otherwise-valid argument was out of the range this API supports.
+	InvalidParams,
+
+	/// A byte stream did not contain the expected serialization header.
+	InvalidSerializedData,
+
+	/// A byte stream was produced under encryption parameters that are incompatible with the
+	/// `Context` passed to `from_bytes`/`load`.
+	IncompatibleParameters,
+
+	/// An operation that requires a quorum of participants was attempted before enough of
+	/// them had checked in.
+	QuorumNotReached,
+
+	/// The requested operation isn't backed by any primitive this SEAL binding exposes.
+	UnsupportedOperation,
+
+	/// An AEAD-sealed blob failed to decrypt, either because the password was wrong or the
+	/// data was tampered with in transit.
+	DecryptionFailed,
+
+	/// Locking a [`crate::Protected`] buffer's pages into physical memory via `mlock` failed,
+	/// e.g. because the process's `ulimit -l` is smaller than the buffer being locked.
+	MlockFailed {
+		/// The OS error code returned by `mlock`.
+		errno: i32,
+		/// The address of the buffer that failed to lock.
+		addr: usize,
+		/// The size in bytes of the buffer that failed to lock.
+		n_bytes: usize,
+	},
+
+	/// Unlocking a [`crate::Protected`] buffer's pages via `munlock` failed.
+	MunlockFailed {
+		/// The OS error code returned by `munlock`.
+		errno: i32,
+		/// The address of the buffer that failed to unlock.
+		addr: usize,
+		/// The size in bytes of the buffer that failed to unlock.
+		n_bytes: usize,
+	},
+
+	/// A polynomial modulus degree passed to a parameters builder was not a power of two.
+	DegreeNotPowerOfTwo,
+
+	/// A coefficient modulus prime had more than the 60 significant bits SEAL supports.
+	PrimeTooLarge,
+
+	/// A coefficient modulus prime was not congruent to 1 modulo `2 * poly_modulus_degree`, as
+	/// required for batching and NTT-friendly modulus switching.
+	PrimeNotCongruent,
+
+	/// The same prime appeared more than once in a coefficient modulus chain.
+	DuplicateModulus,
+
+	/// A [`crate::SignedCiphertext`]/[`crate::SignedCiphertextBatch`]'s Ed25519 signature did
+	/// not verify, either because the payload was tampered with or it was signed by a key
+	/// other than the one checked against.
+	SignatureVerificationFailed,
+
+	/// A Reed-Solomon codeword passed to `decode_rs_from_symbols` had fewer than `k` surviving symbols, so
+	/// the original message couldn't be recovered.
+	TooManySymbolsLost {
+		/// The number of data symbols (`k`) the codeword was encoded with.
+		k: usize,
+		/// The number of symbols that were actually available to decode from.
+		available: usize,
+	},
+}
+
+impl std::fmt::Display for Error {
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result {
+		match self {
+			Error::InternalError(code) => write!(f, "internal SEAL error (code {})", code),
+			Error::InvalidArgument => write!(f, "invalid argument"),
+			Error::OutOfMemory => write!(f, "out of memory"),
+			Error::IoError => write!(f, "I/O error"),
+			Error::Unexpected => write!(f, "unexpected error"),
+			Error::InvalidPointer => write!(f, "invalid pointer"),
+			Error::DegreeNotSet => write!(f, "poly modulus degree not set"),
+			Error::CoefficientModulusNotSet => write!(f, "coefficient modulus not set"),
+			Error::PlainModulusNotSet => write!(f, "plain modulus not set"),
+			Error::InvalidParams => write!(f, "invalid parameters"),
+			Error::InvalidSerializedData => write!(f, "invalid serialized data"),
+			Error::IncompatibleParameters => {
+				write!(f, "serialized data was produced under incompatible encryption parameters")
+			}
+			Error::QuorumNotReached => write!(f, "quorum of participants not yet reached"),
+			Error::UnsupportedOperation => {
+				write!(f, "operation is not supported by this SEAL binding")
+			}
+			Error::DecryptionFailed => {
+				write!(f, "decryption failed: wrong password or corrupted data")
+			}
+			Error::MlockFailed {
+				errno,
+				addr,
+				n_bytes,
+			} => write!(
+				f,
+				"mlock failed for {} byte(s) at {:#x} (errno {})",
+				n_bytes, addr, errno
+			),
+			Error::MunlockFailed {
+				errno,
+				addr,
+				n_bytes,
+			} => write!(
+				f,
+				"munlock failed for {} byte(s) at {:#x} (errno {})",
+				n_bytes, addr, errno
+			),
+			Error::DegreeNotPowerOfTwo => write!(f, "poly modulus degree is not a power of two"),
+			Error::PrimeTooLarge => {
+				write!(f, "coefficient modulus prime has more than 60 significant bits")
+			}
+			Error::PrimeNotCongruent => write!(
+				f,
+				"coefficient modulus prime is not congruent to 1 modulo 2 * poly_modulus_degree"
+			),
+			Error::DuplicateModulus => {
+				write!(f, "coefficient modulus chain contains a duplicate prime")
+			}
+			Error::SignatureVerificationFailed => {
+				write!(f, "Ed25519 signature verification failed")
+			}
+			Error::TooManySymbolsLost {
+				k,
+				available,
+			} => write!(
+				f,
+				"too many Reed-Solomon symbols lost: need at least {} surviving symbols, found {}",
+				k, available
+			),
+		}
+	}
+}
+
+impl std::error::Error for Error {}
+
+/// Converts a SEAL HRESULT return code into a [`Result`]. `S_OK` maps to `Ok(())`, while
+/// every other code maps to the closest matching [`Error`] variant.
+pub fn convert_seal_error(ret: c_long) -> Result<()> {
+	match ret {
+		bindgen::S_OK => Ok(()),
+		bindgen::E_POINTER => Err(Error::InvalidPointer),
+		bindgen::E_INVALIDARG => Err(Error::InvalidArgument),
+		bindgen::E_OUTOFMEMORY => Err(Error::OutOfMemory),
+		bindgen::E_UNEXPECTED => Err(Error::Unexpected),
+		bindgen::COR_E_IO => Err(Error::IoError),
+		bindgen::COR_E_INVALIDOPERATION => Err(Error::InvalidArgument),
+		other => Err(Error::InternalError(other as i64)),
+	}
+}
+
+/// Calls a SEAL FFI function and converts its `HRESULT`-style return code into a [`Result`].
+#[macro_export]
+macro_rules! try_seal {
+	($e:expr) => {
+		$crate::error::convert_seal_error($e)
+	};
+}
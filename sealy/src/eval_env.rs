@@ -0,0 +1,257 @@
+use crate::{
+	BFVEncoder, BFVEvaluator, CKKSEncoder, CKKSEvaluator, Context, Decryptor, Encryptor,
+	EncryptionParameters, GaloisKey, KeyGenerator, PublicKey, RelinearizationKey, Result,
+	SecretKey, SymAsym,
+};
+
+/// A reusable, precomputed BFV evaluation environment bound to a single [`Context`].
+///
+/// Constructing a [`KeyGenerator`] and deriving keys, an encoder, an encryptor, a decryptor,
+/// and an evaluator from a `Context` is the standard setup every BFV benchmark or server
+/// handler needs, and each of those objects does real work up front (key generation in
+/// particular is not cheap). `BFVEvalEnv` pays that setup cost exactly once in
+/// [`BFVEvalEnv::new`] and then hands out borrowed references to the cached objects, so
+/// callers can thread around one owned value instead of a dozen `&ctx`/`&keys` parameters.
+pub struct BFVEvalEnv {
+	context: Context,
+	key_generator: KeyGenerator,
+	public_key: PublicKey,
+	relin_keys: RelinearizationKey,
+	galois_keys: GaloisKey,
+	encoder: BFVEncoder,
+	encryptor: Encryptor<SymAsym>,
+	decryptor: Decryptor,
+	evaluator: BFVEvaluator,
+}
+
+impl BFVEvalEnv {
+	/// Builds every cached object this environment exposes from `context`: a fresh secret key,
+	/// its matching public/relinearization/Galois keys, a [`BFVEncoder`], an [`Encryptor`]
+	/// holding both keys, a [`Decryptor`], and a [`BFVEvaluator`].
+	pub fn new(context: Context) -> Result<Self> {
+		let key_generator = KeyGenerator::new(&context)?;
+		let secret_key = key_generator.secret_key();
+		let public_key = key_generator.create_public_key();
+		let relin_keys = key_generator.create_relinearization_keys()?;
+		let galois_keys = key_generator.create_galois_keys()?;
+		let encoder = BFVEncoder::new(&context)?;
+		let encryptor =
+			Encryptor::with_public_and_secret_key(&context, &public_key, &secret_key)?;
+		let decryptor = Decryptor::new(&context, &secret_key)?;
+		let evaluator = BFVEvaluator::new(&context)?;
+
+		Ok(Self {
+			context,
+			key_generator,
+			public_key,
+			relin_keys,
+			galois_keys,
+			encoder,
+			encryptor,
+			decryptor,
+			evaluator,
+		})
+	}
+
+	/// Builds a `BFVEvalEnv` from `params` via [`Context::new_insecure`], skipping the usual
+	/// security-level enforcement. Only for testing/experimentation, never production use.
+	#[cfg(feature = "insecure-params")]
+	pub fn new_insecure(
+		params: &EncryptionParameters,
+		expand_mod_chain: bool,
+	) -> Result<Self> {
+		Self::new(Context::new_insecure(params, expand_mod_chain)?)
+	}
+
+	/// Returns the [`Context`] this environment was built from.
+	pub fn context(&self) -> &Context {
+		&self.context
+	}
+
+	/// Returns the [`KeyGenerator`] this environment was built from, for generating additional
+	/// Galois key subsets or other keys beyond the ones cached here.
+	pub fn key_generator(&self) -> &KeyGenerator {
+		&self.key_generator
+	}
+
+	/// Returns a copy of the secret key.
+	pub fn secret_key(&self) -> SecretKey {
+		self.key_generator.secret_key()
+	}
+
+	/// Returns the cached public key.
+	pub fn public_key(&self) -> &PublicKey {
+		&self.public_key
+	}
+
+	/// Returns the cached relinearization keys.
+	pub fn relin_keys(&self) -> &RelinearizationKey {
+		&self.relin_keys
+	}
+
+	/// Returns the cached Galois keys.
+	pub fn galois_keys(&self) -> &GaloisKey {
+		&self.galois_keys
+	}
+
+	/// Returns the cached encoder.
+	pub fn encoder(&self) -> &BFVEncoder {
+		&self.encoder
+	}
+
+	/// Returns the cached encryptor, holding both the public and secret key so it supports
+	/// both asymmetric and symmetric encryption.
+	pub fn encryptor(&self) -> &Encryptor<SymAsym> {
+		&self.encryptor
+	}
+
+	/// Returns the cached decryptor.
+	pub fn decryptor(&self) -> &Decryptor {
+		&self.decryptor
+	}
+
+	/// Returns the cached evaluator.
+	pub fn evaluator(&self) -> &BFVEvaluator {
+		&self.evaluator
+	}
+}
+
+/// A reusable, precomputed CKKS evaluation environment bound to a single [`Context`]. See
+/// [`BFVEvalEnv`] for the BFV counterpart and the caching rationale; the only difference here
+/// is the encoder takes the fixed-point `scale` every CKKS encoding needs.
+pub struct CKKSEvalEnv {
+	context: Context,
+	key_generator: KeyGenerator,
+	public_key: PublicKey,
+	relin_keys: RelinearizationKey,
+	galois_keys: GaloisKey,
+	encoder: CKKSEncoder,
+	encryptor: Encryptor<SymAsym>,
+	decryptor: Decryptor,
+	evaluator: CKKSEvaluator,
+}
+
+impl CKKSEvalEnv {
+	/// Builds every cached object this environment exposes from `context`, encoding with the
+	/// given `scale`. See [`BFVEvalEnv::new`] for what each cached object is.
+	pub fn new(
+		context: Context,
+		scale: f64,
+	) -> Result<Self> {
+		let key_generator = KeyGenerator::new(&context)?;
+		let secret_key = key_generator.secret_key();
+		let public_key = key_generator.create_public_key();
+		let relin_keys = key_generator.create_relinearization_keys()?;
+		let galois_keys = key_generator.create_galois_keys()?;
+		let encoder = CKKSEncoder::new(&context, scale)?;
+		let encryptor =
+			Encryptor::with_public_and_secret_key(&context, &public_key, &secret_key)?;
+		let decryptor = Decryptor::new(&context, &secret_key)?;
+		let evaluator = CKKSEvaluator::new(&context)?;
+
+		Ok(Self {
+			context,
+			key_generator,
+			public_key,
+			relin_keys,
+			galois_keys,
+			encoder,
+			encryptor,
+			decryptor,
+			evaluator,
+		})
+	}
+
+	/// Builds a `CKKSEvalEnv` from `params` via [`Context::new_insecure`], skipping the usual
+	/// security-level enforcement. Only for testing/experimentation, never production use.
+	#[cfg(feature = "insecure-params")]
+	pub fn new_insecure(
+		params: &EncryptionParameters,
+		expand_mod_chain: bool,
+		scale: f64,
+	) -> Result<Self> {
+		Self::new(Context::new_insecure(params, expand_mod_chain)?, scale)
+	}
+
+	/// Returns the [`Context`] this environment was built from.
+	pub fn context(&self) -> &Context {
+		&self.context
+	}
+
+	/// Returns the [`KeyGenerator`] this environment was built from, for generating additional
+	/// Galois key subsets or other keys beyond the ones cached here.
+	pub fn key_generator(&self) -> &KeyGenerator {
+		&self.key_generator
+	}
+
+	/// Returns a copy of the secret key.
+	pub fn secret_key(&self) -> SecretKey {
+		self.key_generator.secret_key()
+	}
+
+	/// Returns the cached public key.
+	pub fn public_key(&self) -> &PublicKey {
+		&self.public_key
+	}
+
+	/// Returns the cached relinearization keys.
+	pub fn relin_keys(&self) -> &RelinearizationKey {
+		&self.relin_keys
+	}
+
+	/// Returns the cached Galois keys.
+	pub fn galois_keys(&self) -> &GaloisKey {
+		&self.galois_keys
+	}
+
+	/// Returns the cached encoder.
+	pub fn encoder(&self) -> &CKKSEncoder {
+		&self.encoder
+	}
+
+	/// Returns the cached encryptor, holding both the public and secret key so it supports
+	/// both asymmetric and symmetric encryption.
+	pub fn encryptor(&self) -> &Encryptor<SymAsym> {
+		&self.encryptor
+	}
+
+	/// Returns the cached decryptor.
+	pub fn decryptor(&self) -> &Decryptor {
+		&self.decryptor
+	}
+
+	/// Returns the cached evaluator.
+	pub fn evaluator(&self) -> &CKKSEvaluator {
+		&self.evaluator
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		BFVEncryptionParametersBuilder, CoefficientModulusFactory, DegreeType, PlainModulusFactory,
+		SecurityLevel,
+	};
+
+	#[test]
+	fn bfv_eval_env_round_trips_a_value() {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		let context = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+		let env = BFVEvalEnv::new(context).unwrap();
+
+		let plaintext = env.encoder().encode_u64(&[42]).unwrap();
+		let ciphertext = env.encryptor().encrypt(&plaintext).unwrap();
+		let decrypted = env.decryptor().decrypt(&ciphertext).unwrap();
+
+		assert_eq!(env.encoder().decode_u64(&decrypted).unwrap()[0], 42);
+	}
+}
@@ -0,0 +1,696 @@
+use std::ffi::c_void;
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::error::*;
+use crate::{bindgen, try_seal, Ciphertext, Context, MemoryPool, Plaintext, RelinearizationKey};
+
+/// Shared implementation of the non-scheme-specific operations defined by the `Evaluator`
+/// trait. `BFVEvaluator` and `CKKSEvaluator` both wrap an instance of this type and delegate
+/// to it, implementing any scheme-specific behavior (e.g. relinearization, rotation) on top.
+pub struct EvaluatorBase {
+	handle: AtomicPtr<c_void>,
+	poly_modulus_degree: u64,
+}
+
+unsafe impl Sync for EvaluatorBase {}
+unsafe impl Send for EvaluatorBase {}
+
+impl EvaluatorBase {
+	/// Creates an EvaluatorBase instance initialized with the specified Context.
+	pub fn new(ctx: &Context) -> Result<Self> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::Evaluator_Create(ctx.get_handle(), &mut handle) })?;
+
+		let poly_modulus_degree = ctx
+			.get_first_context_data()?
+			.get_encryption_parameters()?
+			.get_poly_modulus_degree();
+
+		Ok(Self {
+			handle: AtomicPtr::new(handle),
+			poly_modulus_degree,
+		})
+	}
+
+	/// Returns the handle to the underlying SEAL object.
+	pub(crate) unsafe fn get_handle(&self) -> *mut c_void {
+		self.handle.load(Ordering::SeqCst)
+	}
+
+	/// Returns the number of slots a batched plaintext holds under this evaluator's context,
+	/// i.e. half the polynomial modulus degree.
+	pub(crate) fn slot_count(&self) -> usize {
+		(self.poly_modulus_degree / 2) as usize
+	}
+
+	pub(crate) fn negate_inplace(
+		&self,
+		a: &mut Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_Negate(self.get_handle(), a.get_handle(), a.get_handle())
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn negate(
+		&self,
+		a: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_Negate(self.get_handle(), a.get_handle(), out.get_handle())
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn add_inplace(
+		&self,
+		a: &mut Ciphertext,
+		b: &Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_Add(self.get_handle(), a.get_handle(), b.get_handle(), a.get_handle())
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn add(
+		&self,
+		a: &Ciphertext,
+		b: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_Add(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				out.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn add_many(
+		&self,
+		a: &[Ciphertext],
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		let handles: Vec<*mut c_void> = a.iter().map(|c| unsafe { c.get_handle() }).collect();
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_AddMany(
+				self.get_handle(),
+				handles.len() as u64,
+				handles.as_ptr() as *mut *mut c_void,
+				out.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn multiply_many(
+		&self,
+		a: &[Ciphertext],
+		relin_keys: &RelinearizationKey,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		let handles: Vec<*mut c_void> = a.iter().map(|c| unsafe { c.get_handle() }).collect();
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_MultiplyMany(
+				self.get_handle(),
+				handles.len() as u64,
+				handles.as_ptr() as *mut *mut c_void,
+				relin_keys.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn sub_inplace(
+		&self,
+		a: &mut Ciphertext,
+		b: &Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_Sub(self.get_handle(), a.get_handle(), b.get_handle(), a.get_handle())
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn sub(
+		&self,
+		a: &Ciphertext,
+		b: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_Sub(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				out.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn multiply_inplace(
+		&self,
+		a: &mut Ciphertext,
+		b: &Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_Multiply(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn multiply(
+		&self,
+		a: &Ciphertext,
+		b: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_Multiply(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	/// Like [`Self::multiply`], but threads an explicit [`MemoryPool`] through the call instead
+	/// of letting SEAL allocate scratch memory from its global pool on every call.
+	pub(crate) fn multiply_with_pool(
+		&self,
+		a: &Ciphertext,
+		b: &Ciphertext,
+		pool: &MemoryPool,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_Multiply(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				out.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn square_inplace(
+		&self,
+		a: &mut Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_Square(self.get_handle(), a.get_handle(), a.get_handle(), null_mut())
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn square(
+		&self,
+		a: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_Square(self.get_handle(), a.get_handle(), out.get_handle(), null_mut())
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn mod_switch_to_next(
+		&self,
+		a: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_ModSwitchToNext1(self.get_handle(), a.get_handle(), out.get_handle())
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn mod_switch_to_next_inplace(
+		&self,
+		a: &Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_ModSwitchToNext1(self.get_handle(), a.get_handle(), a.get_handle())
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn mod_switch_to_next_plaintext(
+		&self,
+		a: &Plaintext,
+	) -> Result<Plaintext> {
+		let out = Plaintext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_ModSwitchToNext2(self.get_handle(), a.get_handle(), out.get_handle())
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn mod_switch_to_next_inplace_plaintext(
+		&self,
+		a: &Plaintext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_ModSwitchToNext2(self.get_handle(), a.get_handle(), a.get_handle())
+		})?;
+
+		Ok(())
+	}
+
+	/// Switches `a` down to the modulus switching chain level identified by `parms_id`, by
+	/// repeatedly calling [`Self::mod_switch_to_next`] until the result is associated with
+	/// those parameters. `parms_id` must name a level at or below `a`'s current one, or this
+	/// will switch past it and fail once no primes remain. A no-op if `a` is already at
+	/// `parms_id`.
+	pub(crate) fn mod_switch_to(
+		&self,
+		a: &Ciphertext,
+		parms_id: &[u64],
+	) -> Result<Ciphertext> {
+		if a.parms_id()?.as_slice() == parms_id {
+			return Ok(a.clone());
+		}
+
+		let mut current = self.mod_switch_to_next(a)?;
+
+		while current.parms_id()?.as_slice() != parms_id {
+			current = self.mod_switch_to_next(&current)?;
+		}
+
+		Ok(current)
+	}
+
+	pub(crate) fn transform_to_ntt_inplace(
+		&self,
+		a: &Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_TransformToNTT2(self.get_handle(), a.get_handle(), a.get_handle())
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn transform_to_ntt(
+		&self,
+		a: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_TransformToNTT2(self.get_handle(), a.get_handle(), out.get_handle())
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn transform_from_ntt_inplace(
+		&self,
+		a: &Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_TransformFromNTT(self.get_handle(), a.get_handle(), a.get_handle())
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn transform_from_ntt(
+		&self,
+		a: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_TransformFromNTT(self.get_handle(), a.get_handle(), out.get_handle())
+		})?;
+
+		Ok(out)
+	}
+
+	/// Transforms a plaintext into NTT representation at the modulus switching chain level
+	/// identified by `parms_id`, the plaintext form of [`Self::transform_to_ntt`]. Unlike the
+	/// ciphertext transform, a plaintext carries no `parms_id` of its own until it's placed at a
+	/// level this way, so the caller must name the level explicitly.
+	pub(crate) fn transform_plain_to_ntt(
+		&self,
+		a: &Plaintext,
+		parms_id: &[u64],
+	) -> Result<Plaintext> {
+		let out = Plaintext::new()?;
+		let mut parms_id = parms_id.to_vec();
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_TransformToNTT1(
+				self.get_handle(),
+				a.get_handle(),
+				parms_id.as_mut_ptr(),
+				out.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	/// In-place variant of [`Self::transform_plain_to_ntt`].
+	pub(crate) fn transform_plain_to_ntt_inplace(
+		&self,
+		a: &Plaintext,
+		parms_id: &[u64],
+	) -> Result<()> {
+		let mut parms_id = parms_id.to_vec();
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_TransformToNTT1(
+				self.get_handle(),
+				a.get_handle(),
+				parms_id.as_mut_ptr(),
+				a.get_handle(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn rescale_to_next(
+		&self,
+		a: &Ciphertext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_RescaleToNext(
+				self.get_handle(),
+				a.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn rescale_to_next_inplace(
+		&self,
+		a: &Ciphertext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_RescaleToNext(
+				self.get_handle(),
+				a.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	/// Like [`Self::rescale_to_next`], but threads an explicit [`MemoryPool`] through the call.
+	pub(crate) fn rescale_to_next_with_pool(
+		&self,
+		a: &Ciphertext,
+		pool: &MemoryPool,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_RescaleToNext(
+				self.get_handle(),
+				a.get_handle(),
+				out.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	/// Like [`Self::rescale_to_next_inplace`], but threads an explicit [`MemoryPool`] through
+	/// the call.
+	pub(crate) fn rescale_to_next_inplace_with_pool(
+		&self,
+		a: &Ciphertext,
+		pool: &MemoryPool,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_RescaleToNext(
+				self.get_handle(),
+				a.get_handle(),
+				a.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn exponentiate(
+		&self,
+		a: &Ciphertext,
+		exponent: u64,
+		relin_keys: &RelinearizationKey,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_Exponentiate(
+				self.get_handle(),
+				a.get_handle(),
+				exponent,
+				relin_keys.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn exponentiate_inplace(
+		&self,
+		a: &Ciphertext,
+		exponent: u64,
+		relin_keys: &RelinearizationKey,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_Exponentiate(
+				self.get_handle(),
+				a.get_handle(),
+				exponent,
+				relin_keys.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn add_plain(
+		&self,
+		a: &Ciphertext,
+		b: &Plaintext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_AddPlain(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				out.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn add_plain_inplace(
+		&self,
+		a: &mut Ciphertext,
+		b: &Plaintext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_AddPlain(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				a.get_handle(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn sub_plain(
+		&self,
+		a: &Ciphertext,
+		b: &Plaintext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_SubPlain(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				out.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	pub(crate) fn sub_plain_inplace(
+		&self,
+		a: &mut Ciphertext,
+		b: &Plaintext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_SubPlain(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				a.get_handle(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	pub(crate) fn multiply_plain(
+		&self,
+		a: &Ciphertext,
+		b: &Plaintext,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_MultiplyPlain(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	/// Like [`Self::multiply_plain`], but threads an explicit [`MemoryPool`] through the call.
+	pub(crate) fn multiply_plain_with_pool(
+		&self,
+		a: &Ciphertext,
+		b: &Plaintext,
+		pool: &MemoryPool,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		try_seal!(unsafe {
+			bindgen::Evaluator_MultiplyPlain(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				out.get_handle(),
+				pool.get_handle(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	/// Multiplies a ciphertext by a plaintext that is already in NTT form (e.g. produced by
+	/// [`Self::transform_plain_to_ntt`]), skipping the forward transform `multiply_plain` would
+	/// otherwise redo on every call. Worth it when the same plaintext weight multiplies many
+	/// different ciphertexts, e.g. a linear layer's fixed coefficients. Fails with
+	/// [`crate::Error::InvalidParams`] if `a`/`b` aren't both in NTT form, since SEAL silently
+	/// produces garbage rather than erroring when the forms are mismatched.
+	pub(crate) fn multiply_plain_ntt(
+		&self,
+		a: &Ciphertext,
+		b: &Plaintext,
+	) -> Result<Ciphertext> {
+		if !a.is_ntt_form() || !b.is_ntt_form() {
+			return Err(crate::Error::InvalidParams);
+		}
+
+		self.multiply_plain(a, b)
+	}
+
+	pub(crate) fn multiply_plain_inplace(
+		&self,
+		a: &mut Ciphertext,
+		b: &Plaintext,
+	) -> Result<()> {
+		try_seal!(unsafe {
+			bindgen::Evaluator_MultiplyPlain(
+				self.get_handle(),
+				a.get_handle(),
+				b.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+}
+
+impl Drop for EvaluatorBase {
+	fn drop(&mut self) {
+		try_seal!(unsafe { bindgen::Evaluator_Destroy(self.get_handle()) })
+			.expect("Internal error in EvaluatorBase::drop().");
+	}
+}
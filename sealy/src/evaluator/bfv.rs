@@ -0,0 +1,642 @@
+use std::ptr::null_mut;
+
+use crate::evaluator::base::EvaluatorBase;
+use crate::{
+	bindgen, error::convert_seal_error, Ciphertext, Context, Error, Evaluator, GaloisKey, Plaintext,
+	RelinearizationKey, Result,
+};
+
+/// An evaluator that contains the operations needed to build functions that evaluate `BFV`
+/// ciphertexts.
+pub struct BFVEvaluator(EvaluatorBase);
+
+impl std::ops::Deref for BFVEvaluator {
+	type Target = EvaluatorBase;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+impl BFVEvaluator {
+	/// Creates a BFVEvaluator instance initialized with the specified Context.
+	///  * `ctx` - The context.
+	pub fn new(ctx: &Context) -> Result<BFVEvaluator> {
+		Ok(BFVEvaluator(EvaluatorBase::new(ctx)?))
+	}
+}
+
+impl Evaluator for BFVEvaluator {
+	type Plaintext = Plaintext;
+	type Ciphertext = Ciphertext;
+
+	fn negate_inplace(&self, a: &mut Ciphertext) -> Result<()> {
+		self.0.negate_inplace(a)
+	}
+
+	fn negate(&self, a: &Ciphertext) -> Result<Ciphertext> {
+		self.0.negate(a)
+	}
+
+	fn add_inplace(&self, a: &mut Ciphertext, b: &Ciphertext) -> Result<()> {
+		self.0.add_inplace(a, b)
+	}
+
+	fn add(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
+		self.0.add(a, b)
+	}
+
+	fn add_many(&self, a: &[Ciphertext]) -> Result<Ciphertext> {
+		self.0.add_many(a)
+	}
+
+	fn multiply_many(
+		&self, a: &[Ciphertext], relin_keys: &RelinearizationKey,
+	) -> Result<Ciphertext> {
+		self.0.multiply_many(a, relin_keys)
+	}
+
+	fn sub_inplace(&self, a: &mut Ciphertext, b: &Ciphertext) -> Result<()> {
+		self.0.sub_inplace(a, b)
+	}
+
+	fn sub(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
+		self.0.sub(a, b)
+	}
+
+	fn multiply_inplace(&self, a: &mut Ciphertext, b: &Ciphertext) -> Result<()> {
+		self.0.multiply_inplace(a, b)
+	}
+
+	fn multiply(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
+		self.0.multiply(a, b)
+	}
+
+	fn square_inplace(&self, a: &mut Ciphertext) -> Result<()> {
+		self.0.square_inplace(a)
+	}
+
+	fn square(&self, a: &Ciphertext) -> Result<Ciphertext> {
+		self.0.square(a)
+	}
+
+	fn mod_switch_to_next(&self, a: &Ciphertext) -> Result<Ciphertext> {
+		self.0.mod_switch_to_next(a)
+	}
+
+	fn mod_switch_to_next_inplace(&self, a: &Ciphertext) -> Result<()> {
+		self.0.mod_switch_to_next_inplace(a)
+	}
+
+	fn mod_switch_to_next_plaintext(&self, a: &Plaintext) -> Result<Plaintext> {
+		self.0.mod_switch_to_next_plaintext(a)
+	}
+
+	fn mod_switch_to(&self, a: &Ciphertext, parms_id: &[u64]) -> Result<Ciphertext> {
+		self.0.mod_switch_to(a, parms_id)
+	}
+
+	fn transform_to_ntt(&self, a: &Ciphertext) -> Result<Ciphertext> {
+		self.0.transform_to_ntt(a)
+	}
+
+	fn transform_to_ntt_inplace(&self, a: &Ciphertext) -> Result<()> {
+		self.0.transform_to_ntt_inplace(a)
+	}
+
+	fn transform_from_ntt(&self, a: &Ciphertext) -> Result<Ciphertext> {
+		self.0.transform_from_ntt(a)
+	}
+
+	fn transform_from_ntt_inplace(&self, a: &Ciphertext) -> Result<()> {
+		self.0.transform_from_ntt_inplace(a)
+	}
+
+	fn mod_switch_to_next_inplace_plaintext(&self, a: &Plaintext) -> Result<()> {
+		self.0.mod_switch_to_next_inplace_plaintext(a)
+	}
+
+	fn transform_to_ntt_plaintext(&self, a: &Plaintext, parms_id: &[u64]) -> Result<Plaintext> {
+		self.0.transform_plain_to_ntt(a, parms_id)
+	}
+
+	fn transform_to_ntt_inplace_plaintext(&self, a: &Plaintext, parms_id: &[u64]) -> Result<()> {
+		self.0.transform_plain_to_ntt_inplace(a, parms_id)
+	}
+
+	fn multiply_plain_ntt(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
+		self.0.multiply_plain_ntt(a, b)
+	}
+
+	fn exponentiate(
+		&self, a: &Ciphertext, exponent: u64, relin_keys: &RelinearizationKey,
+	) -> Result<Ciphertext> {
+		self.0.exponentiate(a, exponent, relin_keys)
+	}
+
+	fn exponentiate_inplace(
+		&self, a: &Ciphertext, exponent: u64, relin_keys: &RelinearizationKey,
+	) -> Result<()> {
+		self.0.exponentiate_inplace(a, exponent, relin_keys)
+	}
+
+	fn add_plain(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
+		self.0.add_plain(a, b)
+	}
+
+	fn add_plain_inplace(&self, a: &mut Ciphertext, b: &Plaintext) -> Result<()> {
+		self.0.add_plain_inplace(a, b)
+	}
+
+	fn sub_plain(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
+		self.0.sub_plain(a, b)
+	}
+
+	fn sub_plain_inplace(&self, a: &mut Ciphertext, b: &Plaintext) -> Result<()> {
+		self.0.sub_plain_inplace(a, b)
+	}
+
+	fn multiply_plain(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
+		self.0.multiply_plain(a, b)
+	}
+
+	fn multiply_plain_inplace(&self, a: &mut Ciphertext, b: &Plaintext) -> Result<()> {
+		self.0.multiply_plain_inplace(a, b)
+	}
+
+	fn relinearize_inplace(
+		&self, a: &mut Ciphertext, relin_keys: &RelinearizationKey,
+	) -> Result<()> {
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_Relinearize(
+				self.get_handle(),
+				a.get_handle(),
+				relin_keys.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	fn relinearize(&self, a: &Ciphertext, relin_keys: &RelinearizationKey) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_Relinearize(
+				self.get_handle(),
+				a.get_handle(),
+				relin_keys.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	fn rotate_rows(
+		&self, a: &Ciphertext, steps: i32, galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_RotateRows(
+				self.get_handle(),
+				a.get_handle(),
+				steps,
+				galois_keys.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	fn rotate_rows_inplace(
+		&self, a: &Ciphertext, steps: i32, galois_keys: &GaloisKey,
+	) -> Result<()> {
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_RotateRows(
+				self.get_handle(),
+				a.get_handle(),
+				steps,
+				galois_keys.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	fn rotate_columns(&self, a: &Ciphertext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_RotateColumns(
+				self.get_handle(),
+				a.get_handle(),
+				galois_keys.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	fn rotate_columns_inplace(&self, a: &Ciphertext, galois_keys: &GaloisKey) -> Result<()> {
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_RotateColumns(
+				self.get_handle(),
+				a.get_handle(),
+				galois_keys.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	/// Always returns [`Error::UnsupportedOperation`]: BFV packs slots into a 2-by-(N/2) matrix
+	/// with distinct row/column rotations ([`Evaluator::rotate_rows`]/[`Evaluator::rotate_columns`])
+	/// rather than CKKS's single flat vector, so a cyclic whole-vector rotation has no meaning here.
+	fn rotate_vector(
+		&self, _a: &Ciphertext, _steps: i32, _galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		Err(Error::UnsupportedOperation)
+	}
+
+	/// Always returns [`Error::UnsupportedOperation`] for the same reason
+	/// [`BFVEvaluator::rotate_vector`] does.
+	fn rotate_vector_inplace(
+		&self, _a: &Ciphertext, _steps: i32, _galois_keys: &GaloisKey,
+	) -> Result<()> {
+		Err(Error::UnsupportedOperation)
+	}
+
+	/// Always returns [`Error::UnsupportedOperation`]: complex conjugation is a CKKS-only
+	/// notion, since BFV slots hold integers rather than complex numbers.
+	fn complex_conjugate(
+		&self, _a: &Ciphertext, _galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		Err(Error::UnsupportedOperation)
+	}
+
+	/// Always returns [`Error::UnsupportedOperation`] for the same reason
+	/// [`BFVEvaluator::complex_conjugate`] does.
+	fn complex_conjugate_inplace(
+		&self, _a: &Ciphertext, _galois_keys: &GaloisKey,
+	) -> Result<()> {
+		Err(Error::UnsupportedOperation)
+	}
+
+	fn inner_sum(&self, a: &Ciphertext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		let mut acc = a.clone();
+
+		// The BFV batched matrix is 2-by-(N/2), so rotate_rows only sums within each row; fold
+		// the two rows together with a single rotate_columns to cover every slot.
+		let mut step = 1;
+
+		while step < self.slot_count() {
+			let rotated = self.rotate_rows(&acc, step as i32, galois_keys)?;
+			self.add_inplace(&mut acc, &rotated)?;
+			step *= 2;
+		}
+
+		let folded = self.rotate_columns(&acc, galois_keys)?;
+		self.add_inplace(&mut acc, &folded)?;
+
+		Ok(acc)
+	}
+
+	fn dot_product(
+		&self, a: &Ciphertext, b: &Ciphertext, relin_keys: &RelinearizationKey,
+		galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		let mut product = self.multiply(a, b)?;
+
+		self.relinearize_inplace(&mut product, relin_keys)?;
+		self.inner_sum(&product, galois_keys)
+	}
+
+	fn dot_plain(&self, a: &Ciphertext, b: &Plaintext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		let product = self.multiply_plain(a, b)?;
+
+		self.inner_sum(&product, galois_keys)
+	}
+}
+
+impl BFVEvaluator {
+	/// Swaps the two rows of an encrypted `2`-by-`(N/2)` batched matrix.
+	///
+	/// This is exactly [`Evaluator::rotate_columns`] under a name that matches how
+	/// [`crate::BFVEncoder::encode_matrix`] describes the slot layout: SEAL calls this
+	/// operation "rotating columns", but since BFV's Galois group only gives the matrix 2
+	/// rows, rotating its columns has no effect other than swapping them.
+	pub fn swap_columns(&self, a: &Ciphertext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		self.rotate_columns(a, galois_keys)
+	}
+
+	/// In-place version of [`Self::swap_columns`].
+	pub fn swap_columns_inplace(&self, a: &Ciphertext, galois_keys: &GaloisKey) -> Result<()> {
+		self.rotate_columns_inplace(a, galois_keys)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[test]
+	fn inner_sum_replicates_the_total_across_every_slot() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let plain_modulus = PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap().value();
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let total: u64 = data.iter().sum::<u64>() % plain_modulus;
+
+		let plaintext = encoder.encode_u64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let summed = evaluator.inner_sum(&ciphertext, &galois_keys).unwrap();
+		let decrypted = decryptor.decrypt(&summed).unwrap();
+		let decoded = encoder.decode_u64(&decrypted).unwrap();
+
+		assert!(decoded.iter().all(|&slot| slot == total));
+	}
+
+	#[test]
+	fn mod_switch_to_is_a_no_op_when_already_at_the_target_level() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let parms_id = ciphertext.parms_id().unwrap();
+		let switched = evaluator.mod_switch_to(&ciphertext, &parms_id).unwrap();
+
+		assert_eq!(switched.parms_id().unwrap(), parms_id);
+	}
+
+	#[test]
+	fn rotate_vector_is_unsupported() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(matches!(
+			evaluator.rotate_vector(&ciphertext, 1, &galois_keys),
+			Err(Error::UnsupportedOperation)
+		));
+	}
+
+	#[test]
+	fn complex_conjugate_is_unsupported() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(matches!(
+			evaluator.complex_conjugate(&ciphertext, &galois_keys),
+			Err(Error::UnsupportedOperation)
+		));
+	}
+
+	#[test]
+	fn dot_product_replicates_the_total_across_every_slot() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let relin_keys = gen.create_relinearization_keys().unwrap();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let a: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let b = vec![2u64; encoder.get_slot_count()];
+		let plain_modulus = PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap().value();
+		let expected: u64 = a.iter().zip(&b).map(|(x, y)| x * y).sum::<u64>() % plain_modulus;
+
+		let cipher_a = encryptor.encrypt(&encoder.encode_u64(&a).unwrap()).unwrap();
+		let cipher_b = encryptor.encrypt(&encoder.encode_u64(&b).unwrap()).unwrap();
+
+		let product = evaluator
+			.dot_product(&cipher_a, &cipher_b, &relin_keys, &galois_keys)
+			.unwrap();
+		let decrypted = decryptor.decrypt(&product).unwrap();
+		let decoded = encoder.decode_u64(&decrypted).unwrap();
+
+		assert!(decoded.iter().all(|&slot| slot == expected));
+	}
+
+	#[test]
+	fn dot_plain_replicates_the_total_across_every_slot() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let a: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let weights = vec![2u64; encoder.get_slot_count()];
+		let plain_modulus = PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap().value();
+		let expected: u64 = a.iter().zip(&weights).map(|(x, y)| x * y).sum::<u64>() % plain_modulus;
+
+		let cipher_a = encryptor.encrypt(&encoder.encode_u64(&a).unwrap()).unwrap();
+		let plain_weights = encoder.encode_u64(&weights).unwrap();
+
+		let product = evaluator
+			.dot_plain(&cipher_a, &plain_weights, &galois_keys)
+			.unwrap();
+		let decrypted = decryptor.decrypt(&product).unwrap();
+		let decoded = encoder.decode_u64(&decrypted).unwrap();
+
+		assert!(decoded.iter().all(|&slot| slot == expected));
+	}
+
+	#[test]
+	fn swap_columns_exchanges_the_two_matrix_rows() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let row_0 = vec![1i64, 2, 3, 4];
+		let row_1 = vec![5i64, 6, 7, 8];
+
+		let plaintext = encoder.encode_matrix(&[&row_0, &row_1]).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let swapped = evaluator.swap_columns(&ciphertext, &galois_keys).unwrap();
+		let decrypted = decryptor.decrypt(&swapped).unwrap();
+		let decoded = encoder.decode_matrix(&decrypted).unwrap();
+
+		assert_eq!(&decoded[0][..row_1.len()], &row_1[..]);
+		assert_eq!(&decoded[1][..row_0.len()], &row_0[..]);
+	}
+
+	#[test]
+	fn transform_to_and_from_ntt_round_trips_and_decrypts() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(!ciphertext.is_ntt_form());
+
+		let in_ntt_form = evaluator.transform_to_ntt(&ciphertext).unwrap();
+		assert!(in_ntt_form.is_ntt_form());
+
+		let back = evaluator.transform_from_ntt(&in_ntt_form).unwrap();
+		assert!(!back.is_ntt_form());
+
+		let decrypted = decryptor.decrypt(&back).unwrap();
+		let decoded = encoder.decode_u64(&decrypted).unwrap();
+
+		assert_eq!(decoded, data);
+	}
+
+	#[test]
+	fn multiply_plain_ntt_multiplies_a_ciphertext_by_a_pre_transformed_plaintext() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).map(|i| i % 4).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let weights: Vec<u64> = (0..encoder.get_slot_count() as u64).map(|_| 3).collect();
+		let weight_plain = encoder.encode_u64(&weights).unwrap();
+
+		let ciphertext_ntt = evaluator.transform_to_ntt(&ciphertext).unwrap();
+		let weight_plain_ntt = evaluator
+			.transform_to_ntt_plaintext(&weight_plain, &ciphertext.parms_id().unwrap())
+			.unwrap();
+
+		let product_ntt = evaluator
+			.multiply_plain_ntt(&ciphertext_ntt, &weight_plain_ntt)
+			.unwrap();
+		let product = evaluator.transform_from_ntt(&product_ntt).unwrap();
+
+		let decrypted = decryptor.decrypt(&product).unwrap();
+		let decoded = encoder.decode_u64(&decrypted).unwrap();
+
+		for (got, expected) in decoded.iter().zip(data.iter()) {
+			assert_eq!(*got, expected * 3);
+		}
+	}
+
+	#[test]
+	fn multiply_plain_ntt_rejects_operands_not_in_ntt_form() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let evaluator = BFVEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let data: Vec<u64> = (0..encoder.get_slot_count() as u64).collect();
+		let plaintext = encoder.encode_u64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let weight_plain = encoder.encode_u64(&data).unwrap();
+
+		assert!(matches!(
+			evaluator.multiply_plain_ntt(&ciphertext, &weight_plain),
+			Err(Error::InvalidParams)
+		));
+	}
+}
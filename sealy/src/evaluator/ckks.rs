@@ -2,8 +2,8 @@ use std::ptr::null_mut;
 
 use crate::evaluator::base::EvaluatorBase;
 use crate::{
-	bindgen, error::convert_seal_error, Ciphertext, Context, Evaluator, GaloisKey, Plaintext,
-	RelinearizationKey, Result,
+	bindgen, error::convert_seal_error, CKKSEncoder, Ciphertext, Context, Error, Evaluator,
+	GaloisKey, MemoryPool, Plaintext, RelinearizationKey, Result,
 };
 
 /// An evaluator that contains additional operations specific to the CKKS scheme.
@@ -23,6 +23,612 @@ impl CKKSEvaluator {
 	pub fn new(ctx: &Context) -> Result<CKKSEvaluator> {
 		Ok(CKKSEvaluator(EvaluatorBase::new(ctx)?))
 	}
+
+	/// Rescales a ciphertext by dropping the last prime `q_L` of its coefficient modulus while
+	/// dividing the ciphertext by `q_L`, which is how CKKS keeps the scale from growing
+	/// unboundedly across `multiply`/`square` operations. A ciphertext with scale `S` built
+	/// under coefficient modulus primes close to a base scale (e.g. 40-bit primes and scale
+	/// `2^40`) ends up with scale `S / q_L`, i.e. back near the base scale.
+	pub fn rescale_to_next(&self, a: &Ciphertext) -> Result<Ciphertext> {
+		self.0.rescale_to_next(a)
+	}
+
+	/// In-place variant of [`Self::rescale_to_next`].
+	pub fn rescale_to_next_inplace(&self, a: &Ciphertext) -> Result<()> {
+		self.0.rescale_to_next_inplace(a)
+	}
+
+	/// Like [`Self::rescale_to_next`], but threads an explicit [`crate::MemoryPool`] through the
+	/// call instead of letting SEAL allocate scratch memory from its global pool every time.
+	/// Worth doing when rescaling in a tight loop, e.g. repeated calls from the same worker
+	/// thread.
+	pub fn rescale_to_next_with_pool(
+		&self,
+		a: &Ciphertext,
+		pool: &MemoryPool,
+	) -> Result<Ciphertext> {
+		self.0.rescale_to_next_with_pool(a, pool)
+	}
+
+	/// In-place variant of [`Self::rescale_to_next_with_pool`].
+	pub fn rescale_to_next_inplace_with_pool(
+		&self,
+		a: &Ciphertext,
+		pool: &MemoryPool,
+	) -> Result<()> {
+		self.0.rescale_to_next_inplace_with_pool(a, pool)
+	}
+
+	/// Rescales a ciphertext down to the modulus switching chain level identified by
+	/// `parms_id`, by repeatedly calling [`Self::rescale_to_next`] until the result is
+	/// associated with those parameters. `parms_id` must name a level at or below the
+	/// ciphertext's current one, or this will rescale past it and fail once no primes remain.
+	/// A no-op if `a` is already at `parms_id`.
+	pub fn rescale_to(&self, a: &Ciphertext, parms_id: &[u64]) -> Result<Ciphertext> {
+		if a.parms_id()?.as_slice() == parms_id {
+			return Ok(a.clone());
+		}
+
+		let mut current = self.rescale_to_next(a)?;
+
+		while current.parms_id()?.as_slice() != parms_id {
+			current = self.rescale_to_next(&current)?;
+		}
+
+		Ok(current)
+	}
+
+	/// Returns whether `a` and `b` carry matching scales, within a relative tolerance of
+	/// `1e-8` to absorb the floating-point rounding a `rescale_to_next` call introduces.
+	/// `add`/`add_plain`/`sub`/`sub_plain` all require their operands' scales to match (in
+	/// addition to their `parms_id`s), so callers composing several rescaled ciphertexts should
+	/// check this before combining them rather than relying on SEAL's own error to catch a
+	/// mismatch.
+	pub fn scales_match(
+		&self,
+		a: &Ciphertext,
+		b: &Ciphertext,
+	) -> bool {
+		let (scale_a, scale_b) = (a.scale(), b.scale());
+
+		(scale_a - scale_b).abs() <= 1e-8 * scale_a.abs().max(scale_b.abs())
+	}
+
+	/// Mod-switches whichever of `a`/`b` sits at a higher level (more primes in its current
+	/// coefficient modulus, per [`Ciphertext::coeff_modulus_size`]) down to match the other's
+	/// level, so the pair can be passed to `add`/`sub`/`multiply`, which all require both
+	/// operands to share a `parms_id`. Also checks the (now level-matched) pair's scales via
+	/// [`Self::scales_match`], since mod-switching alone can't fix a scale mismatch, returning
+	/// [`crate::Error::IncompatibleParameters`] if they still disagree.
+	pub fn align(&self, a: &Ciphertext, b: &Ciphertext) -> Result<(Ciphertext, Ciphertext)> {
+		let (a, b) = match a.coeff_modulus_size().cmp(&b.coeff_modulus_size()) {
+			std::cmp::Ordering::Equal => (a.clone(), b.clone()),
+			std::cmp::Ordering::Greater => (self.mod_switch_to(a, &b.parms_id()?)?, b.clone()),
+			std::cmp::Ordering::Less => (a.clone(), self.mod_switch_to(b, &a.parms_id()?)?),
+		};
+
+		if !self.scales_match(&a, &b) {
+			return Err(Error::IncompatibleParameters);
+		}
+
+		Ok((a, b))
+	}
+
+	/// [`Self::add`], but calls [`Self::align`] on `a`/`b` first, so callers composing
+	/// ciphertexts from asymmetric multiply/rescale chains don't have to track levels and scales
+	/// by hand.
+	pub fn add_aligned(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
+		let (a, b) = self.align(a, b)?;
+
+		self.add(&a, &b)
+	}
+
+	/// [`Self::sub`], but calls [`Self::align`] on `a`/`b` first. See [`Self::add_aligned`].
+	pub fn sub_aligned(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
+		let (a, b) = self.align(a, b)?;
+
+		self.sub(&a, &b)
+	}
+
+	/// Alias for [`Evaluator::inner_sum`] under the name used in the packed-reduction
+	/// literature: sums every packed slot via the logarithmic rotate-and-add trick, replicating
+	/// the total across every output slot. Requires Galois keys generated for power-of-two
+	/// rotation steps.
+	pub fn sum_slots(&self, x: &Ciphertext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		self.inner_sum(x, galois_keys)
+	}
+
+	/// Alias for [`Evaluator::dot_product`]: an element-wise `multiply` (relinearized) followed
+	/// by [`Self::sum_slots`], replicating the full dot product across every output slot.
+	pub fn inner_product(
+		&self,
+		a: &Ciphertext,
+		b: &Ciphertext,
+		relin_keys: &RelinearizationKey,
+		galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		self.dot_product(a, b, relin_keys, galois_keys)
+	}
+
+	/// Alias for [`Self::sum_slots`] under the name used for the rotate-and-add "total reduction"
+	/// building block: rotations cost no noise budget, so summing by repeatedly rotating by
+	/// `1, 2, 4, ..., slot_count/2` and adding the rotated copy back in is cheap relative to the
+	/// depth it would cost to extract and re-sum slots any other way.
+	pub fn sum_all_slots(&self, x: &Ciphertext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		self.sum_slots(x, galois_keys)
+	}
+
+	/// Like [`Self::sum_all_slots`], but reduces within fixed-size `block_size`-wide windows
+	/// instead of across the whole packed vector, by stopping the same rotate-and-add doubling at
+	/// `block_size` rather than the full slot count.
+	///
+	/// After `log2(block_size)` steps, slot `i` holds the sum of the `block_size`-wide cyclic
+	/// window starting at `i`; every block-aligned slot (`i` a multiple of `block_size`) therefore
+	/// holds the exact total of that block, which is what a batched-inner-product caller reads
+	/// out (e.g. many independent dot products packed side by side, one per block). Unlike
+	/// [`Self::sum_all_slots`], non-aligned slots hold a sliding-window sum that spans two
+	/// adjacent blocks rather than a second copy of either block's total, so only the
+	/// block-aligned slots are meaningful here.
+	///
+	/// `block_size` must be a power of two that evenly divides the slot count.
+	pub fn sum_slots_in_blocks(
+		&self,
+		x: &Ciphertext,
+		block_size: usize,
+		galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		let slot_count = self.slot_count();
+
+		if block_size == 0 || !block_size.is_power_of_two() || slot_count % block_size != 0 {
+			return Err(Error::InvalidParams);
+		}
+
+		let mut acc = x.clone();
+		let mut step = 1;
+
+		while step < block_size {
+			let rotated = self.rotate_vector(&acc, step as i32, galois_keys)?;
+			self.add_inplace(&mut acc, &rotated)?;
+			step *= 2;
+		}
+
+		Ok(acc)
+	}
+
+	/// Evaluates `sum_i coeffs[i] * x^i` homomorphically using Horner's method, handling
+	/// relinearization, rescaling, and level-matched coefficient encoding automatically.
+	///
+	/// Each ciphertext-ciphertext multiply is followed by `relinearize` and `rescale_to_next`
+	/// to keep the scale near its base value, which also drops the accumulator one level in the
+	/// modulus switching chain; `x` is mod-switched down to match before every such multiply,
+	/// since `multiply` requires both operands to share a `parms_id`. Each `coeffs[i]` is
+	/// encoded at the accumulator's level and scale at the point it's added in, since `add_plain`
+	/// requires the same of its plaintext operand.
+	pub fn evaluate_polynomial(
+		&self,
+		x: &Ciphertext,
+		coeffs: &[f64],
+		relin_keys: &RelinearizationKey,
+		encoder: &CKKSEncoder,
+	) -> Result<Ciphertext> {
+		let pool = MemoryPool::new()?;
+
+		self.evaluate_polynomial_with_pool(x, coeffs, relin_keys, encoder, &pool)
+	}
+
+	/// Like [`Self::evaluate_polynomial`], but threads a single, caller-supplied
+	/// [`crate::MemoryPool`] through every multiply/rescale/encode in the Horner's method loop
+	/// instead of letting each one allocate its own. Worth doing for repeated polynomial
+	/// evaluation from the same worker thread, where reallocating a pool per call is measurable
+	/// overhead.
+	pub fn evaluate_polynomial_with_pool(
+		&self,
+		x: &Ciphertext,
+		coeffs: &[f64],
+		relin_keys: &RelinearizationKey,
+		encoder: &CKKSEncoder,
+		pool: &MemoryPool,
+	) -> Result<Ciphertext> {
+		let (leading, rest) = match coeffs.split_last() {
+			Some(split) => split,
+			None => return Err(Error::InvalidParams),
+		};
+
+		let slot_count = encoder.get_slot_count();
+
+		// Seed the accumulator with the leading coefficient as a ciphertext: multiplying `x` by
+		// a zero plaintext gives an encrypted zero at `x`'s level (minus the rescale below),
+		// which `add_plain` then turns into an encrypted constant.
+		let zero = encoder.encode_f64_for_with_pool(&vec![0.0; slot_count], x, pool)?;
+		let mut acc = self.0.multiply_plain_with_pool(x, &zero, pool)?;
+		self.rescale_to_next_inplace_with_pool(&acc, pool)?;
+
+		let leading_plain = encoder.encode_f64_for_with_pool(&vec![*leading; slot_count], &acc, pool)?;
+		self.add_plain_inplace(&mut acc, &leading_plain)?;
+
+		let mut power = x.clone();
+
+		for &coeff in rest.iter().rev() {
+			self.mod_switch_to_next_inplace(&power)?;
+
+			acc = self.0.multiply_with_pool(&acc, &power, pool)?;
+			self.relinearize_inplace(&mut acc, relin_keys)?;
+			self.rescale_to_next_inplace_with_pool(&acc, pool)?;
+
+			let coeff_plain = encoder.encode_f64_for_with_pool(&vec![coeff; slot_count], &acc, pool)?;
+			self.add_plain_inplace(&mut acc, &coeff_plain)?;
+		}
+
+		Ok(acc)
+	}
+
+	/// Evaluates `sum_i coeffs[i] * x^i` homomorphically using the Paterson-Stockmeyer
+	/// baby-step/giant-step scheme, which needs roughly `2*sqrt(n)` non-scalar ciphertext
+	/// multiplications for a degree-`n` polynomial instead of the `n` that
+	/// [`Self::evaluate_polynomial`]'s plain Horner's method needs.
+	///
+	/// With `k = ceil(sqrt(coeffs.len()))`, this computes the "baby" powers `x^1..x^k` once,
+	/// splits `coeffs` into chunks of (at most) `k` coefficients each, evaluates every chunk
+	/// `q_i` as a linear combination of the baby powers, then folds the chunk results together
+	/// Horner-style using repeated multiplication by the "giant" step `x^k`. Every
+	/// ciphertext-ciphertext multiply is followed by `relinearize`/`rescale_to_next`, and every
+	/// scalar multiply by `rescale_to_next`, so two operands about to be combined can end up at
+	/// different levels in the modulus switching chain; this tracks each intermediate result's
+	/// depth explicitly and mod-switches the shallower one down before every `add`/`multiply`,
+	/// the same invariant [`Self::evaluate_polynomial`] maintains with a single accumulator.
+	pub fn evaluate_polynomial_bsgs(
+		&self,
+		x: &Ciphertext,
+		coeffs: &[f64],
+		relin_keys: &RelinearizationKey,
+		encoder: &CKKSEncoder,
+	) -> Result<Ciphertext> {
+		let pool = MemoryPool::new()?;
+
+		self.evaluate_polynomial_bsgs_with_pool(x, coeffs, relin_keys, encoder, &pool)
+	}
+
+	/// Like [`Self::evaluate_polynomial_bsgs`], but threads a single, caller-supplied
+	/// [`crate::MemoryPool`] through every multiply/rescale/encode instead of letting each one
+	/// allocate its own.
+	pub fn evaluate_polynomial_bsgs_with_pool(
+		&self,
+		x: &Ciphertext,
+		coeffs: &[f64],
+		relin_keys: &RelinearizationKey,
+		encoder: &CKKSEncoder,
+		pool: &MemoryPool,
+	) -> Result<Ciphertext> {
+		if coeffs.is_empty() {
+			return Err(Error::InvalidParams);
+		}
+
+		let slot_count = encoder.get_slot_count();
+		let k = Self::bsgs_baby_step_count(coeffs.len());
+
+		// Baby powers x^1..x^k; baby_powers[i] holds (x^(i + 1), its depth), where "depth" is
+		// the number of rescales applied to reach it, starting from x's depth of 0.
+		let mut baby_powers: Vec<(Ciphertext, usize)> = Vec::with_capacity(k);
+		baby_powers.push((x.clone(), 0));
+
+		for _ in 1..k {
+			let (prev, prev_depth) = baby_powers.last().cloned().expect("baby_powers is non-empty");
+			let next = self.multiply_matched(&prev, prev_depth, x, 0, relin_keys, pool)?;
+			baby_powers.push(next);
+		}
+
+		// Evaluate each degree-<k chunk q_i(x) = coeffs[i*k] + coeffs[i*k + 1] * x + ... from
+		// the baby powers, highest power first so the accumulator settles at that term's depth;
+		// every shallower term is mod-switched down to match before it's added in.
+		let chunk_results = coeffs
+			.chunks(k)
+			.map(|chunk| -> Result<(Ciphertext, usize)> {
+				if chunk.len() == 1 {
+					return self.encrypted_constant(chunk[0], x, encoder, slot_count, pool);
+				}
+
+				let top = chunk.len() - 1;
+				let (top_power, top_depth) = baby_powers[top - 1].clone();
+				let (mut acc, mut acc_depth) =
+					self.multiply_scalar(&top_power, top_depth, chunk[top], encoder, slot_count, pool)?;
+
+				for power in (1..top).rev() {
+					let (bp, bp_depth) = baby_powers[power - 1].clone();
+					let term =
+						self.multiply_scalar(&bp, bp_depth, chunk[power], encoder, slot_count, pool)?;
+					let sum = self.add_matched(&acc, acc_depth, &term.0, term.1)?;
+					acc = sum.0;
+					acc_depth = sum.1;
+				}
+
+				let const_plain = encoder.encode_f64_for_with_pool(&vec![chunk[0]; slot_count], &acc, pool)?;
+				self.add_plain_inplace(&mut acc, &const_plain)?;
+
+				Ok((acc, acc_depth))
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		// Fold the chunks together Horner-style via the giant step x^k, starting from the last
+		// (highest-order) chunk and working back down to the first.
+		let (giant_step, giant_step_depth) = baby_powers[k - 1].clone();
+		let (last, rest) = chunk_results
+			.split_last()
+			.expect("coeffs is non-empty, so chunk_results is too");
+
+		let mut result = last.clone();
+
+		for chunk_result in rest.iter().rev() {
+			let scaled = self.multiply_matched(
+				&result.0,
+				result.1,
+				&giant_step,
+				giant_step_depth,
+				relin_keys,
+				pool,
+			)?;
+
+			result = self.add_matched(&scaled.0, scaled.1, &chunk_result.0, chunk_result.1)?;
+		}
+
+		Ok(result.0)
+	}
+
+	/// Returns the baby-step count `k = ceil(sqrt(n))` that [`Self::evaluate_polynomial_bsgs`]
+	/// splits a degree-`(n - 1)` polynomial's `n` coefficients around.
+	fn bsgs_baby_step_count(n: usize) -> usize {
+		let mut k = (n as f64).sqrt().ceil() as usize;
+
+		while k * k < n {
+			k += 1;
+		}
+
+		k.max(1)
+	}
+
+	/// Mod-switches whichever of `a` (at depth `a_depth`) or `b` (at depth `b_depth`) is
+	/// shallower down to match the other's depth, so the pair can be passed to `add`/`multiply`,
+	/// both of which require their operands to share a `parms_id`.
+	fn match_depth(
+		&self,
+		a: &Ciphertext,
+		a_depth: usize,
+		b: &Ciphertext,
+		b_depth: usize,
+	) -> Result<(Ciphertext, Ciphertext, usize)> {
+		match a_depth.cmp(&b_depth) {
+			std::cmp::Ordering::Equal => Ok((a.clone(), b.clone(), a_depth)),
+			std::cmp::Ordering::Less => {
+				let matched = a.clone();
+
+				for _ in a_depth..b_depth {
+					self.mod_switch_to_next_inplace(&matched)?;
+				}
+
+				Ok((matched, b.clone(), b_depth))
+			}
+			std::cmp::Ordering::Greater => {
+				let matched = b.clone();
+
+				for _ in b_depth..a_depth {
+					self.mod_switch_to_next_inplace(&matched)?;
+				}
+
+				Ok((a.clone(), matched, a_depth))
+			}
+		}
+	}
+
+	/// Depth-matches `a` and `b`, then adds them.
+	fn add_matched(
+		&self,
+		a: &Ciphertext,
+		a_depth: usize,
+		b: &Ciphertext,
+		b_depth: usize,
+	) -> Result<(Ciphertext, usize)> {
+		let (a, b, depth) = self.match_depth(a, a_depth, b, b_depth)?;
+
+		Ok((self.add(&a, &b)?, depth))
+	}
+
+	/// Depth-matches `a` and `b`, multiplies them, then relinearizes and rescales the product,
+	/// which lands one level deeper than the matched depth.
+	fn multiply_matched(
+		&self,
+		a: &Ciphertext,
+		a_depth: usize,
+		b: &Ciphertext,
+		b_depth: usize,
+		relin_keys: &RelinearizationKey,
+		pool: &MemoryPool,
+	) -> Result<(Ciphertext, usize)> {
+		let (a, b, depth) = self.match_depth(a, a_depth, b, b_depth)?;
+
+		let mut product = self.0.multiply_with_pool(&a, &b, pool)?;
+		self.relinearize_inplace(&mut product, relin_keys)?;
+		self.rescale_to_next_inplace_with_pool(&product, pool)?;
+
+		Ok((product, depth + 1))
+	}
+
+	/// Multiplies `a` (at depth `a_depth`) by the scalar `value`, encoded at `a`'s level and
+	/// scale, then rescales, landing one level deeper than `a`.
+	fn multiply_scalar(
+		&self,
+		a: &Ciphertext,
+		a_depth: usize,
+		value: f64,
+		encoder: &CKKSEncoder,
+		slot_count: usize,
+		pool: &MemoryPool,
+	) -> Result<(Ciphertext, usize)> {
+		let plain = encoder.encode_f64_for_with_pool(&vec![value; slot_count], a, pool)?;
+		let product = self.0.multiply_plain_with_pool(a, &plain, pool)?;
+		self.rescale_to_next_inplace_with_pool(&product, pool)?;
+
+		Ok((product, a_depth + 1))
+	}
+
+	/// Builds an encrypted constant `value` at depth 1 by multiplying `x` by an encoded zero
+	/// (landing at depth 1 after the rescale) and adding `value` in as a plaintext, the same
+	/// trick [`Self::evaluate_polynomial_with_pool`] uses to seed its accumulator.
+	fn encrypted_constant(
+		&self,
+		value: f64,
+		x: &Ciphertext,
+		encoder: &CKKSEncoder,
+		slot_count: usize,
+		pool: &MemoryPool,
+	) -> Result<(Ciphertext, usize)> {
+		let zero = encoder.encode_f64_for_with_pool(&vec![0.0; slot_count], x, pool)?;
+		let mut out = self.0.multiply_plain_with_pool(x, &zero, pool)?;
+		self.rescale_to_next_inplace_with_pool(&out, pool)?;
+
+		let value_plain = encoder.encode_f64_for_with_pool(&vec![value; slot_count], &out, pool)?;
+		self.add_plain_inplace(&mut out, &value_plain)?;
+
+		Ok((out, 1))
+	}
+
+	/// Computes `A·v` for an `n×n` matrix `A` given as its `n` diagonals (the Halevi-Shoup
+	/// diagonal method), where diagonal `d_i[j] = A[j][(j + i) mod n]` has already been encoded
+	/// into a full-slot plaintext at `v`'s level and scale. `A·v = Σ_i d_i ⊙ rot(v, i)`: rotate
+	/// `v` by `i` slots, multiply by `d_i`, and accumulate. Needs `n` rotations; see
+	/// [`Self::matrix_vector_multiply_bsgs`] for an `O(√n)`-rotation variant.
+	pub fn matrix_vector_multiply(
+		&self,
+		diagonals: &[Plaintext],
+		v: &Ciphertext,
+		galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		let (first, rest) = diagonals.split_first().ok_or(Error::InvalidParams)?;
+
+		let mut acc = self.multiply_plain(v, first)?;
+
+		for (offset, d_i) in rest.iter().enumerate() {
+			let rotated = self.rotate_vector(v, (offset + 1) as i32, galois_keys)?;
+			let term = self.multiply_plain(&rotated, d_i)?;
+			self.add_inplace(&mut acc, &term)?;
+		}
+
+		Ok(acc)
+	}
+
+	/// Like [`Self::matrix_vector_multiply`], but cuts the rotation count from `n` to roughly
+	/// `2*sqrt(n)` using baby-step/giant-step: with `n1 = ceil(sqrt(n))`, write `i = n1*g + j`;
+	/// precompute the `n1` baby-step rotations `rot(v, 0..n1)` once, then for each giant step `g`
+	/// multiply each baby rotation `rot(v, j)` by diagonal `d_{n1*g+j}` pre-rotated by `-n1*g`
+	/// slots, sum that block, rotate the block sum forward by `n1*g` (undoing the pre-rotation
+	/// for every term at once), and accumulate the blocks.
+	///
+	/// Unlike [`Self::matrix_vector_multiply`], `diagonals` holds raw, not-yet-encoded
+	/// coefficient rows (each of `v`'s slot count, zero-padded if `n` doesn't evenly fill them):
+	/// encoding happens here, after the pre-rotation, since shifting a plaintext's *data* is a
+	/// plain slice rotation, while shifting an already-encoded `Plaintext` would need a Galois
+	/// key the same as rotating a ciphertext does.
+	pub fn matrix_vector_multiply_bsgs(
+		&self,
+		diagonals: &[Vec<f64>],
+		v: &Ciphertext,
+		galois_keys: &GaloisKey,
+		encoder: &CKKSEncoder,
+	) -> Result<Ciphertext> {
+		if diagonals.is_empty() {
+			return Err(Error::InvalidParams);
+		}
+
+		let n = diagonals.len();
+		let baby_step_count = Self::bsgs_baby_step_count(n);
+		let giant_step_count = (n + baby_step_count - 1) / baby_step_count;
+
+		let mut baby_rotations = Vec::with_capacity(baby_step_count);
+		baby_rotations.push(v.clone());
+
+		for j in 1..baby_step_count {
+			baby_rotations.push(self.rotate_vector(v, j as i32, galois_keys)?);
+		}
+
+		let mut total: Option<Ciphertext> = None;
+
+		for g in 0..giant_step_count {
+			let giant_offset = g * baby_step_count;
+			let mut block: Option<Ciphertext> = None;
+
+			for j in 0..baby_step_count {
+				let i = giant_offset + j;
+
+				if i >= n {
+					break;
+				}
+
+				let rotated_diagonal = Self::cyclic_rotate(&diagonals[i], -(giant_offset as i32));
+				let plain = encoder.encode_f64(&rotated_diagonal)?;
+				let term = self.multiply_plain(&baby_rotations[j], &plain)?;
+
+				block = Some(match block {
+					Some(acc) => self.add(&acc, &term)?,
+					None => term,
+				});
+			}
+
+			let Some(block) = block else {
+				continue;
+			};
+
+			let shifted = if giant_offset == 0 {
+				block
+			} else {
+				self.rotate_vector(&block, giant_offset as i32, galois_keys)?
+			};
+
+			total = Some(match total {
+				Some(acc) => self.add(&acc, &shifted)?,
+				None => shifted,
+			});
+		}
+
+		total.ok_or(Error::InvalidParams)
+	}
+
+	/// Like [`Self::matrix_vector_multiply_bsgs`], but takes the dense `n×n` matrix `A` directly
+	/// instead of its pre-split diagonals, deriving diagonal `d_i[j] = A[j][(j + i) mod n]` for
+	/// every `i` before delegating. `n` need not be a perfect square (or match `v`'s slot count):
+	/// [`Self::matrix_vector_multiply_bsgs`] already copes with a final, partially-filled giant
+	/// step by stopping each block's inner loop at `n`, so no explicit zero-padding is needed here
+	/// beyond what encoding a diagonal shorter than the slot count already does.
+	pub fn matrix_vector_multiply_from_matrix(
+		&self,
+		matrix: &[Vec<f64>],
+		v: &Ciphertext,
+		galois_keys: &GaloisKey,
+		encoder: &CKKSEncoder,
+	) -> Result<Ciphertext> {
+		let n = matrix.len();
+
+		if n == 0 || matrix.iter().any(|row| row.len() != n) {
+			return Err(Error::InvalidParams);
+		}
+
+		let diagonals: Vec<Vec<f64>> = (0..n)
+			.map(|i| (0..n).map(|j| matrix[j][(j + i) % n]).collect())
+			.collect();
+
+		self.matrix_vector_multiply_bsgs(&diagonals, v, galois_keys, encoder)
+	}
+
+	/// Cyclically rotates `data` left by `amount` slots (negative rotates right), wrapping
+	/// modulo `data.len()`.
+	fn cyclic_rotate(data: &[f64], amount: i32) -> Vec<f64> {
+		if data.is_empty() {
+			return Vec::new();
+		}
+
+		let shift = amount.rem_euclid(data.len() as i32) as usize;
+		let mut out = data.to_vec();
+		out.rotate_left(shift);
+
+		out
+	}
 }
 
 impl Evaluator for CKKSEvaluator {
@@ -95,6 +701,38 @@ impl Evaluator for CKKSEvaluator {
 		self.0.mod_switch_to_next_inplace_plaintext(a)
 	}
 
+	fn mod_switch_to(&self, a: &Ciphertext, parms_id: &[u64]) -> Result<Ciphertext> {
+		self.0.mod_switch_to(a, parms_id)
+	}
+
+	fn transform_to_ntt(&self, a: &Ciphertext) -> Result<Ciphertext> {
+		self.0.transform_to_ntt(a)
+	}
+
+	fn transform_to_ntt_inplace(&self, a: &Ciphertext) -> Result<()> {
+		self.0.transform_to_ntt_inplace(a)
+	}
+
+	fn transform_from_ntt(&self, a: &Ciphertext) -> Result<Ciphertext> {
+		self.0.transform_from_ntt(a)
+	}
+
+	fn transform_from_ntt_inplace(&self, a: &Ciphertext) -> Result<()> {
+		self.0.transform_from_ntt_inplace(a)
+	}
+
+	fn transform_to_ntt_plaintext(&self, a: &Plaintext, parms_id: &[u64]) -> Result<Plaintext> {
+		self.0.transform_plain_to_ntt(a, parms_id)
+	}
+
+	fn transform_to_ntt_inplace_plaintext(&self, a: &Plaintext, parms_id: &[u64]) -> Result<()> {
+		self.0.transform_plain_to_ntt_inplace(a, parms_id)
+	}
+
+	fn multiply_plain_ntt(&self, a: &Ciphertext, b: &Plaintext) -> Result<Ciphertext> {
+		self.0.multiply_plain_ntt(a, b)
+	}
+
 	fn exponentiate(
 		&self, a: &Ciphertext, exponent: u64, relin_keys: &RelinearizationKey,
 	) -> Result<Ciphertext> {
@@ -228,7 +866,780 @@ impl Evaluator for CKKSEvaluator {
 
 		Ok(())
 	}
+
+	fn rotate_vector(
+		&self, a: &Ciphertext, steps: i32, galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_RotateVector(
+				self.get_handle(),
+				a.get_handle(),
+				steps,
+				galois_keys.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	fn rotate_vector_inplace(
+		&self, a: &Ciphertext, steps: i32, galois_keys: &GaloisKey,
+	) -> Result<()> {
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_RotateVector(
+				self.get_handle(),
+				a.get_handle(),
+				steps,
+				galois_keys.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	fn complex_conjugate(&self, a: &Ciphertext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		let out = Ciphertext::new()?;
+
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_ComplexConjugate(
+				self.get_handle(),
+				a.get_handle(),
+				galois_keys.get_handle(),
+				out.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(out)
+	}
+
+	fn complex_conjugate_inplace(&self, a: &Ciphertext, galois_keys: &GaloisKey) -> Result<()> {
+		convert_seal_error(unsafe {
+			bindgen::Evaluator_ComplexConjugate(
+				self.get_handle(),
+				a.get_handle(),
+				galois_keys.get_handle(),
+				a.get_handle(),
+				null_mut(),
+			)
+		})?;
+
+		Ok(())
+	}
+
+	fn inner_sum(&self, a: &Ciphertext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		let mut acc = a.clone();
+		let mut step = 1;
+
+		while step < self.slot_count() {
+			let rotated = self.rotate_vector(&acc, step as i32, galois_keys)?;
+			self.add_inplace(&mut acc, &rotated)?;
+			step *= 2;
+		}
+
+		Ok(acc)
+	}
+
+	fn dot_product(
+		&self, a: &Ciphertext, b: &Ciphertext, relin_keys: &RelinearizationKey,
+		galois_keys: &GaloisKey,
+	) -> Result<Ciphertext> {
+		let mut product = self.multiply(a, b)?;
+
+		self.relinearize_inplace(&mut product, relin_keys)?;
+		self.inner_sum(&product, galois_keys)
+	}
+
+	fn dot_plain(&self, a: &Ciphertext, b: &Plaintext, galois_keys: &GaloisKey) -> Result<Ciphertext> {
+		let product = self.multiply_plain(a, b)?;
+
+		self.inner_sum(&product, galois_keys)
+	}
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::ckks_ctx()
+	}
+
+	#[test]
+	fn inner_sum_replicates_the_total_across_every_slot() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64).collect();
+		let total: f64 = data.iter().sum();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let summed = evaluator.inner_sum(&ciphertext, &galois_keys).unwrap();
+		let decrypted = decryptor.decrypt(&summed).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for slot in decoded {
+			assert!((slot - total).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn dot_plain_replicates_the_total_across_every_slot() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64).collect();
+		let weights = vec![2.0f64; encoder.get_slot_count()];
+		let total: f64 = data.iter().zip(&weights).map(|(x, y)| x * y).sum();
+
+		let ciphertext = encryptor.encrypt(&encoder.encode_f64(&data).unwrap()).unwrap();
+		let plain_weights = encoder.encode_f64(&weights).unwrap();
+
+		let product = evaluator
+			.dot_plain(&ciphertext, &plain_weights, &galois_keys)
+			.unwrap();
+		let decrypted = decryptor.decrypt(&product).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for slot in decoded {
+			assert!((slot - total).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn rotate_vector_shifts_slots_cyclically() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let slot_count = encoder.get_slot_count();
+		let data: Vec<f64> = (0..slot_count).map(|i| i as f64).collect();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let rotated = evaluator.rotate_vector(&ciphertext, 1, &galois_keys).unwrap();
+		let decrypted = decryptor.decrypt(&rotated).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (i, slot) in decoded.iter().enumerate() {
+			let expected = ((i + 1) % slot_count) as f64;
+			assert!((slot - expected).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn complex_conjugate_negates_the_imaginary_part() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<(f64, f64)> = (0..encoder.get_slot_count())
+			.map(|i| (i as f64, (i as f64) + 1.0))
+			.collect();
+
+		let plaintext = encoder.encode_complex(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let conjugated = evaluator
+			.complex_conjugate(&ciphertext, &galois_keys)
+			.unwrap();
+		let decrypted = decryptor.decrypt(&conjugated).unwrap();
+		let decoded = encoder.decode_complex(&decrypted).unwrap();
+
+		for ((re, im), (expected_re, expected_im)) in decoded.iter().zip(&data) {
+			assert!((re - expected_re).abs() < 1.0);
+			assert!((im + expected_im).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn rescale_to_next_restores_the_scale_after_a_multiply() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64 * 0.5).collect();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let squared = evaluator.square(&ciphertext).unwrap();
+		assert!((squared.scale() - scale * scale).abs() / (scale * scale) < 1e-6);
+
+		let rescaled = evaluator.rescale_to_next(&squared).unwrap();
+		assert!((rescaled.scale() - scale).abs() / scale < 1e-2);
+
+		let decrypted = decryptor.decrypt(&rescaled).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (slot, expected) in decoded.iter().zip(data.iter()) {
+			assert!((slot - expected * expected).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn rescale_to_is_a_no_op_when_already_at_the_target_level() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64 * 0.5).collect();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let parms_id = ciphertext.parms_id().unwrap();
+		let rescaled = evaluator.rescale_to(&ciphertext, &parms_id).unwrap();
+
+		assert_eq!(rescaled.parms_id().unwrap(), parms_id);
+	}
+
+	#[test]
+	fn scales_match_detects_a_mismatch_after_squaring() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64 * 0.5).collect();
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let other_plaintext = encoder.encode_f64(&data).unwrap();
+		let other_ciphertext = encryptor.encrypt(&other_plaintext).unwrap();
+
+		assert!(evaluator.scales_match(&ciphertext, &other_ciphertext));
+
+		let squared = evaluator.square(&ciphertext).unwrap();
+		assert!(!evaluator.scales_match(&squared, &other_ciphertext));
+	}
+
+	#[test]
+	fn add_aligned_matches_levels_before_adding() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64 * 0.5).collect();
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let x = encryptor.encrypt(&plaintext).unwrap();
+
+		// `mod_switch_to_next` drops a level without touching the scale, so `y` is a clean
+		// level-mismatch (but scale-matched) partner for `x`.
+		let y = evaluator.mod_switch_to_next(&x).unwrap();
+		assert_ne!(x.coeff_modulus_size(), y.coeff_modulus_size());
+		assert!(evaluator.scales_match(&x, &y));
+
+		let summed = evaluator.add_aligned(&x, &y).unwrap();
+		let decrypted = decryptor.decrypt(&summed).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (slot, value) in decoded.iter().zip(data.iter()) {
+			assert!((slot - value * 2.0).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn align_rejects_a_genuine_scale_mismatch() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64 * 0.5).collect();
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let x = encryptor.encrypt(&plaintext).unwrap();
+
+		let squared = evaluator.square(&x).unwrap();
+
+		assert!(matches!(
+			evaluator.align(&x, &squared),
+			Err(Error::IncompatibleParameters)
+		));
+	}
+
+	#[test]
+	fn sum_slots_and_inner_product_match_their_aliases() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let relin_keys = gen.create_relinearization_keys().unwrap();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64).collect();
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let summed = evaluator.sum_slots(&ciphertext, &galois_keys).unwrap();
+		let inner_summed = evaluator.inner_sum(&ciphertext, &galois_keys).unwrap();
+		let summed_decoded = encoder.decode_f64(&decryptor.decrypt(&summed).unwrap()).unwrap();
+		let inner_summed_decoded = encoder
+			.decode_f64(&decryptor.decrypt(&inner_summed).unwrap())
+			.unwrap();
+
+		for (a, b) in summed_decoded.iter().zip(inner_summed_decoded.iter()) {
+			assert!((a - b).abs() < 1e-6);
+		}
+
+		let product = evaluator
+			.inner_product(&ciphertext, &ciphertext, &relin_keys, &galois_keys)
+			.unwrap();
+		let dot_product = evaluator
+			.dot_product(&ciphertext, &ciphertext, &relin_keys, &galois_keys)
+			.unwrap();
+		let product_decoded = encoder.decode_f64(&decryptor.decrypt(&product).unwrap()).unwrap();
+		let dot_product_decoded = encoder
+			.decode_f64(&decryptor.decrypt(&dot_product).unwrap())
+			.unwrap();
+
+		for (a, b) in product_decoded.iter().zip(dot_product_decoded.iter()) {
+			assert!((a - b).abs() < 1e-6);
+		}
+	}
+
+	#[test]
+	fn sum_all_slots_matches_sum_slots() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64).collect();
+		let total: f64 = data.iter().sum();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let summed = evaluator.sum_all_slots(&ciphertext, &galois_keys).unwrap();
+		let decrypted = decryptor.decrypt(&summed).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for slot in decoded {
+			assert!((slot - total).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn sum_slots_in_blocks_sums_each_block_at_its_aligned_slot() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let block_size = 8;
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64).collect();
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let summed = evaluator
+			.sum_slots_in_blocks(&ciphertext, block_size, &galois_keys)
+			.unwrap();
+		let decrypted = decryptor.decrypt(&summed).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		// Only block-aligned slots hold an exact block total; check the first few blocks.
+		for block in 0..4 {
+			let start = block * block_size;
+			let expected: f64 = data[start..start + block_size].iter().sum();
+			assert!((decoded[start] - expected).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn sum_slots_in_blocks_rejects_a_non_power_of_two_block_size() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let plaintext = encoder.encode_f64(&vec![0.0; encoder.get_slot_count()]).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(matches!(
+			evaluator.sum_slots_in_blocks(&ciphertext, 3, &galois_keys),
+			Err(Error::InvalidParams)
+		));
+	}
+
+	#[test]
+	fn evaluate_polynomial_computes_pi_x_cubed_plus_0_4_x_plus_1() {
+		// A degree-3 polynomial needs four multiplicative levels (the zero-seed plus one
+		// ciphertext-ciphertext multiply per remaining Horner step), so this context carries
+		// more primes than the other tests in this module, over D16384 to fit them at TC128.
+		let params = CkksEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D16384)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D16384, &[60, 40, 40, 40, 40, 60])
+					.unwrap(),
+			)
+			.build()
+			.unwrap();
+		let ctx = Context::new(&params, true, SecurityLevel::TC128).unwrap();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let relin_keys = gen.create_relinearization_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let coeffs = [1.0, 0.4, 0.0, std::f64::consts::PI];
+		let data: Vec<f64> = (0..encoder.get_slot_count())
+			.map(|i| (i as f64 / encoder.get_slot_count() as f64) - 0.5)
+			.collect();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let x = encryptor.encrypt(&plaintext).unwrap();
+
+		let result = evaluator
+			.evaluate_polynomial(&x, &coeffs, &relin_keys, &encoder)
+			.unwrap();
+
+		let decrypted = decryptor.decrypt(&result).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (slot, value) in decoded.iter().zip(data.iter()) {
+			let expected = coeffs[3] * value.powi(3) + coeffs[1] * value + coeffs[0];
+			assert!((slot - expected).abs() < 1e-2);
+		}
+	}
+
+	#[test]
+	fn evaluate_polynomial_bsgs_matches_a_plaintext_evaluation() {
+		// Six coefficients (degree 5) forces k = ceil(sqrt(6)) = 3 baby steps and m = 2 chunks,
+		// so this exercises both the baby-power and giant-step combination paths; three
+		// multiplicative levels are needed (x^3 sits at depth 2, and the giant-step fold adds
+		// one more), so this context carries three 40-bit primes, over D16384 to fit them at TC128.
+		let params = CkksEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D16384)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D16384, &[60, 40, 40, 40, 60]).unwrap(),
+			)
+			.build()
+			.unwrap();
+		let ctx = Context::new(&params, true, SecurityLevel::TC128).unwrap();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let relin_keys = gen.create_relinearization_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let coeffs = [1.0, 0.4, 0.0, 0.3, 0.0, 0.1];
+		let data: Vec<f64> = (0..encoder.get_slot_count())
+			.map(|i| (i as f64 / encoder.get_slot_count() as f64) - 0.5)
+			.collect();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let x = encryptor.encrypt(&plaintext).unwrap();
+
+		let result = evaluator
+			.evaluate_polynomial_bsgs(&x, &coeffs, &relin_keys, &encoder)
+			.unwrap();
+
+		let decrypted = decryptor.decrypt(&result).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (slot, value) in decoded.iter().zip(data.iter()) {
+			let expected: f64 = coeffs
+				.iter()
+				.enumerate()
+				.map(|(i, c)| c * value.powi(i as i32))
+				.sum();
+			assert!((slot - expected).abs() < 1e-2);
+		}
+	}
+
+	#[test]
+	fn matrix_vector_multiply_computes_a_diagonal_encoded_product() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let slot_count = encoder.get_slot_count();
+		let data: Vec<f64> = (0..slot_count).map(|i| i as f64).collect();
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let v = encryptor.encrypt(&plaintext).unwrap();
+
+		// A 4x4 matrix with only diagonals 0 and 1 nonzero (A[j][j] = 1, A[j][(j+1) mod 4] = 2).
+		// Only the first three output slots are checked: for j + i < 4 the simple diagonal
+		// method's real, full-slot-count rotation agrees with the "mod 4" matrix formula, but
+		// beyond that it doesn't, since this helper doesn't handle the wraparound case (that
+		// needs either full-slot packing or a doubled-copy encoding the caller would supply).
+		let d0 = encoder.encode_f64(&vec![1.0; slot_count]).unwrap();
+		let d1 = encoder.encode_f64(&vec![2.0; slot_count]).unwrap();
+		let d2 = encoder.encode_f64(&vec![0.0; slot_count]).unwrap();
+		let d3 = encoder.encode_f64(&vec![0.0; slot_count]).unwrap();
+
+		let result = evaluator
+			.matrix_vector_multiply(&[d0, d1, d2, d3], &v, &galois_keys)
+			.unwrap();
+
+		let decrypted = decryptor.decrypt(&result).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (j, value) in decoded.iter().enumerate().take(3) {
+			let expected = data[j] + 2.0 * data[j + 1];
+			assert!((value - expected).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn matrix_vector_multiply_bsgs_matches_the_diagonal_formula() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let slot_count = encoder.get_slot_count();
+		let data: Vec<f64> = (0..slot_count).map(|i| i as f64).collect();
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let v = encryptor.encrypt(&plaintext).unwrap();
+
+		// A 100-diagonal matrix with only d_0 = 1 and d_70 = 0.5 nonzero. baby_step_count =
+		// ceil(sqrt(100)) = 10, so d_70 falls in giant step g = 7, exercising the giant-step
+		// combination path. As in `matrix_vector_multiply`'s test, only output slots that never
+		// hit the wraparound edge case (j + 70 < 100 here) are checked.
+		let n = 100;
+		let mut diagonals = vec![vec![0.0; slot_count]; n];
+		diagonals[0] = vec![1.0; slot_count];
+		diagonals[70] = vec![0.5; slot_count];
+
+		let result = evaluator
+			.matrix_vector_multiply_bsgs(&diagonals, &v, &galois_keys, &encoder)
+			.unwrap();
+
+		let decrypted = decryptor.decrypt(&result).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (j, value) in decoded.iter().enumerate().take(5) {
+			let expected = data[j] + 0.5 * data[j + 70];
+			assert!((value - expected).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn matrix_vector_multiply_from_matrix_matches_a_plaintext_product() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		// A 5x5 matrix (not a perfect square, exercising the bsgs method's partial final giant
+		// step) small enough that the input vector fits in its first 5 slots untouched, so the
+		// wraparound edge case never comes up.
+		let n = 5;
+		let data: Vec<f64> = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+		let mut padded = vec![0.0; encoder.get_slot_count()];
+		padded[..n].copy_from_slice(&data);
+		let plaintext = encoder.encode_f64(&padded).unwrap();
+		let v = encryptor.encrypt(&plaintext).unwrap();
+
+		let matrix: Vec<Vec<f64>> = (0..n)
+			.map(|row| (0..n).map(|col| if row == col { 2.0 } else { 0.0 }).collect())
+			.collect();
+
+		let result = evaluator
+			.matrix_vector_multiply_from_matrix(&matrix, &v, &galois_keys, &encoder)
+			.unwrap();
+
+		let decrypted = decryptor.decrypt(&result).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (j, value) in decoded.iter().enumerate().take(n) {
+			assert!((value - 2.0 * data[j]).abs() < 1.0);
+		}
+	}
+
+	#[test]
+	fn matrix_vector_multiply_from_matrix_rejects_a_non_square_matrix() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let galois_keys = gen.create_galois_keys().unwrap();
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let plaintext = encoder.encode_f64(&vec![0.0; encoder.get_slot_count()]).unwrap();
+		let v = encryptor.encrypt(&plaintext).unwrap();
+
+		let ragged_matrix = vec![vec![1.0, 2.0], vec![3.0]];
+
+		assert!(matches!(
+			evaluator.matrix_vector_multiply_from_matrix(&ragged_matrix, &v, &galois_keys, &encoder),
+			Err(Error::InvalidParams)
+		));
+	}
+
+	#[test]
+	fn rescale_to_next_with_pool_matches_the_default_pool() {
+		use crate::MemoryPool;
+
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let scale = 2.0f64.powi(40);
+		let encoder = CKKSEncoder::new(&ctx, scale).unwrap();
+		let evaluator = CKKSEvaluator::new(&ctx).unwrap();
+
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = Decryptor::new(&ctx, &secret_key).unwrap();
+
+		let data: Vec<f64> = (0..encoder.get_slot_count()).map(|i| i as f64 * 0.5).collect();
+
+		let plaintext = encoder.encode_f64(&data).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let squared = evaluator.square(&ciphertext).unwrap();
+
+		let pool = MemoryPool::new().unwrap();
+		let rescaled = evaluator.rescale_to_next_with_pool(&squared, &pool).unwrap();
+		assert!((rescaled.scale() - scale).abs() / scale < 1e-2);
+
+		let decrypted = decryptor.decrypt(&rescaled).unwrap();
+		let decoded = encoder.decode_f64(&decrypted).unwrap();
+
+		for (slot, expected) in decoded.iter().zip(data.iter()) {
+			assert!((slot - expected * expected).abs() < 1.0);
+		}
+	}
+}
@@ -6,6 +6,17 @@ pub mod bfv;
 pub mod ckks;
 
 /// An interface for an evaluator.
+///
+/// # Noise budget and remaining levels
+///
+/// An `Evaluator` deliberately has no way to report how much noise budget a ciphertext has
+/// left: SEAL's `Evaluator` object never sees the secret key, so it can't decrypt and measure
+/// the actual noise. The real noise budget is only available from
+/// [`crate::Decryptor::invariant_noise_budget`] (BFV/BGV; CKKS has no invariant-noise notion
+/// since its "noise" is the approximation error, not a hard decryption-failure threshold). For
+/// a cheaper, scheme-agnostic upper bound that doesn't need the secret key — how many more
+/// `mod_switch_to_next`/`rescale_to_next` calls a ciphertext can still absorb before it runs out
+/// of primes — see [`crate::Context::remaining_levels`].
 pub trait Evaluator {
 	/// The plaintext type.
 	/// This is the type of the plaintext that the evaluator can operate on.
@@ -157,6 +168,82 @@ pub trait Evaluator {
 		a: &Self::Plaintext,
 	) -> Result<()>;
 
+	/// Switches `a` down to the modulus switching chain level identified by `parms_id`, by
+	/// repeatedly mod-switching one level at a time until the result lands on those parameters.
+	/// `parms_id` must name a level at or below `a`'s current one.
+	///
+	/// Lets two ciphertexts (or a ciphertext and a plaintext it will be combined with) be
+	/// brought to the same chain level before an operation like `add`/`add_plain` that requires
+	/// matching parameters, mirroring [`crate::ContextData::chain_index`]/
+	/// [`crate::ContextData::parms_id`] for locating the target level.
+	fn mod_switch_to(
+		&self,
+		a: &Self::Ciphertext,
+		parms_id: &[u64],
+	) -> Result<Self::Ciphertext>;
+
+	/// Transforms `a` into NTT (Number Theoretic Transform) representation.
+	///
+	/// Polynomial multiplication is much cheaper in NTT form, so users doing manual
+	/// polynomial-level optimization can transform operands into NTT form before a batch of
+	/// multiplies and transform back with [`Evaluator::transform_from_ntt`] afterwards.
+	/// [`crate::Decryptor::decrypt`] requires its input in the scheme's default form (non-NTT for
+	/// BFV/BGV, NTT for CKKS), so a BFV/BGV ciphertext left in NTT form must be transformed back
+	/// before decryption.
+	fn transform_to_ntt(
+		&self,
+		a: &Self::Ciphertext,
+	) -> Result<Self::Ciphertext>;
+
+	/// Transforms `a` into NTT representation. This variant does so in-place. See
+	/// [`Evaluator::transform_to_ntt`].
+	fn transform_to_ntt_inplace(
+		&self,
+		a: &Self::Ciphertext,
+	) -> Result<()>;
+
+	/// Transforms `a` out of NTT representation and back into the default form required by
+	/// [`crate::Decryptor::decrypt`].
+	fn transform_from_ntt(
+		&self,
+		a: &Self::Ciphertext,
+	) -> Result<Self::Ciphertext>;
+
+	/// Transforms `a` out of NTT representation. This variant does so in-place. See
+	/// [`Evaluator::transform_from_ntt`].
+	fn transform_from_ntt_inplace(
+		&self,
+		a: &Self::Ciphertext,
+	) -> Result<()>;
+
+	/// Transforms a plaintext into NTT representation at the modulus switching chain level
+	/// identified by `parms_id`. Unlike [`Evaluator::transform_to_ntt`], a plaintext has no
+	/// `parms_id` of its own until this call places it at one, so the target level must be
+	/// named explicitly — typically the level of the ciphertext it will be multiplied against.
+	fn transform_to_ntt_plaintext(
+		&self,
+		a: &Self::Plaintext,
+		parms_id: &[u64],
+	) -> Result<Self::Plaintext>;
+
+	/// Transforms a plaintext into NTT representation. This variant does so in-place. See
+	/// [`Evaluator::transform_to_ntt_plaintext`].
+	fn transform_to_ntt_inplace_plaintext(
+		&self,
+		a: &Self::Plaintext,
+		parms_id: &[u64],
+	) -> Result<()>;
+
+	/// Multiplies a ciphertext by a plaintext that is already in NTT form, skipping the forward
+	/// transform [`Evaluator::multiply_plain`] would otherwise redo on every call. See
+	/// [`crate::evaluator::base::EvaluatorBase::multiply_plain_ntt`] for the error this raises
+	/// on mismatched NTT state.
+	fn multiply_plain_ntt(
+		&self,
+		a: &Self::Ciphertext,
+		b: &Self::Plaintext,
+	) -> Result<Self::Ciphertext>;
+
 	/// This functions raises encrypted to a power and stores the result in the destination parameter. Dynamic
 	/// memory allocations in the process are allocated from the memory pool pointed to by the given
 	/// MemoryPoolHandle. The exponentiation is done in a depth-optimal order, and relinearization is performed
@@ -314,4 +401,109 @@ pub trait Evaluator {
 		a: &Self::Ciphertext,
 		galois_keys: &GaloisKey,
 	) -> Result<()>;
+
+	/// Rotates a CKKS-encoded vector of complex/real slots cyclically.
+	///
+	/// Unlike [`Evaluator::rotate_rows`]/[`Evaluator::rotate_columns`], which operate on the
+	/// 2-by-(N/2) batched matrix used by BFV/BGV, CKKS slots form a single flat vector, which
+	/// this rotates cyclically to the left (`steps > 0`) or right (`steps < 0`).
+	///
+	/// Unlike BFV/BGV row rotation, an arbitrary `steps` here resolves to a single Galois
+	/// automorphism rather than a sum of power-of-two ones, so `galois_keys` only needs to cover
+	/// the specific step(s) actually rotated by, not every power of two.
+	///
+	/// * `a` - the ciphertext to rotate
+	/// * `steps` - the number of slots to rotate (positive left, negative right)
+	/// * `galois_keys` - the Galois keys
+	fn rotate_vector(
+		&self,
+		a: &Self::Ciphertext,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Self::Ciphertext>;
+
+	/// Rotates a CKKS-encoded vector of complex/real slots cyclically. This variant does so
+	/// in-place. See [`Evaluator::rotate_vector`].
+	///
+	/// * `a` - the ciphertext to rotate
+	/// * `steps` - the number of slots to rotate (positive left, negative right)
+	/// * `galois_keys` - the Galois keys
+	fn rotate_vector_inplace(
+		&self,
+		a: &Self::Ciphertext,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<()>;
+
+	/// Replaces each CKKS slot with its complex conjugate, using the Galois automorphism that
+	/// corresponds to conjugation. For slots holding purely real data this is a no-op; it
+	/// matters when a computation has produced genuinely complex intermediate values.
+	///
+	/// * `a` - the ciphertext to conjugate
+	/// * `galois_keys` - the Galois keys
+	fn complex_conjugate(
+		&self,
+		a: &Self::Ciphertext,
+		galois_keys: &GaloisKey,
+	) -> Result<Self::Ciphertext>;
+
+	/// Replaces each CKKS slot with its complex conjugate. This variant does so in-place. See
+	/// [`Evaluator::complex_conjugate`].
+	fn complex_conjugate_inplace(
+		&self,
+		a: &Self::Ciphertext,
+		galois_keys: &GaloisKey,
+	) -> Result<()>;
+
+	/// Sums `a` across all of its packed slots, returning a ciphertext in which every slot
+	/// holds that total.
+	///
+	/// This is the classic rotate-and-add total-sum: starting from an accumulator equal to
+	/// `a`, for each `step = 1, 2, 4, … < n` (where `n` is the number of slots), the
+	/// accumulator is rotated by `step` and added back into itself, doubling `step` each
+	/// iteration. After `ceil(log2(n))` steps, every slot holds the sum of all of the
+	/// original slots.
+	///
+	/// * `a` - the ciphertext to sum across slots.
+	/// * `galois_keys` - the Galois keys used to rotate `a`.
+	fn inner_sum(
+		&self,
+		a: &Self::Ciphertext,
+		galois_keys: &GaloisKey,
+	) -> Result<Self::Ciphertext>;
+
+	/// Computes the slot-wise dot product of `a` and `b`, returning a ciphertext in which
+	/// every slot holds the total.
+	///
+	/// This multiplies `a` and `b` elementwise, relinearizes the product, then reduces it
+	/// across slots with [`Evaluator::inner_sum`].
+	///
+	/// * `a` - the first operand.
+	/// * `b` - the second operand.
+	/// * `relin_keys` - the relinearization keys used after multiplying.
+	/// * `galois_keys` - the Galois keys used to rotate the product while summing.
+	fn dot_product(
+		&self,
+		a: &Self::Ciphertext,
+		b: &Self::Ciphertext,
+		relin_keys: &RelinearizationKey,
+		galois_keys: &GaloisKey,
+	) -> Result<Self::Ciphertext>;
+
+	/// Computes the slot-wise dot product of a ciphertext and a plaintext weight vector,
+	/// returning a ciphertext in which every slot holds the total.
+	///
+	/// This multiplies `a` and `b` elementwise with [`Evaluator::multiply_plain`], then reduces
+	/// the product across slots with [`Evaluator::inner_sum`]. Since multiplying by a plaintext
+	/// doesn't grow the ciphertext's size, no relinearization is needed along the way.
+	///
+	/// * `a` - the ciphertext.
+	/// * `b` - the plaintext weight vector.
+	/// * `galois_keys` - the Galois keys used to rotate the product while summing.
+	fn dot_plain(
+		&self,
+		a: &Self::Ciphertext,
+		b: &Self::Plaintext,
+		galois_keys: &GaloisKey,
+	) -> Result<Self::Ciphertext>;
 }
@@ -1,6 +1,9 @@
 use super::Batch;
 use crate::{Ciphertext, Context, Decryptor, Plaintext, Result, SecretKey};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// Decrypts batches of ciphertexts.
 pub struct BatchDecryptor {
 	decryptor: Decryptor,
@@ -25,3 +28,22 @@ impl BatchDecryptor {
 			.collect()
 	}
 }
+
+#[cfg(feature = "parallel")]
+impl BatchDecryptor {
+	/// Decrypts every ciphertext in the batch on a separate thread.
+	///
+	/// Unlike [`super::encryptor::BatchEncryptor::par_encrypt`], workers here share the global
+	/// memory pool rather than one per thread: SEAL's `Decryptor_Decrypt` takes no
+	/// `MemoryPoolHandle` argument, so there is no scratch allocation to isolate per worker.
+	///
+	/// * `ciphertext_batch` - The batch of ciphertexts to decrypt.
+	pub fn par_decrypt(&self, ciphertext_batch: &Batch<Ciphertext>) -> Result<Batch<Plaintext>> {
+		ciphertext_batch
+			.0
+			.par_iter()
+			.map(|ciphertext| self.decryptor.decrypt(ciphertext))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+}
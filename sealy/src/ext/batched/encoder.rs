@@ -1,6 +1,9 @@
 use super::Batch;
 use crate::{Encoder, Plaintext, Result, SlotCount};
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// An encoder that encodes data in batches.
 #[derive(Clone)]
 pub struct BatchEncoder<T, E> {
@@ -78,6 +81,60 @@ where
 	}
 }
 
+#[cfg(feature = "parallel")]
+impl<T, E> BatchEncoder<T, E>
+where
+	T: Sync,
+	E: Encoder<T> + Sync,
+	E::Encoded: Into<Plaintext> + Send,
+	for<'a> &'a E::Encoded: From<&'a Plaintext>,
+{
+	/// Encodes the given data into a plaintext, encoding each `batch_size`-sized chunk on a
+	/// separate thread rather than [`BatchEncoder::encode`]'s sequential loop. Output order
+	/// matches the input order.
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	///
+	/// # Returns
+	/// The encoded plaintext.
+	pub fn par_encode(
+		&self,
+		data: &[T],
+	) -> Result<Batch<Plaintext>> {
+		let batch_size = self.get_slot_count();
+
+		let plaintexts = data
+			.par_chunks(batch_size)
+			.map(|chunk| self.encoder.encode(chunk).map(Into::into))
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(Batch(plaintexts))
+	}
+
+	/// Decodes the given plaintext into data, decoding each plaintext on a separate thread
+	/// rather than [`BatchEncoder::decode`]'s sequential loop. Output order matches the input
+	/// plaintext order.
+	///
+	/// # Arguments
+	/// * `batch` - The encoded data.
+	///
+	/// # Returns
+	/// The decoded data.
+	pub fn par_decode(
+		&self,
+		batch: &Batch<Plaintext>,
+	) -> Result<Vec<T>> {
+		let chunks = batch
+			.0
+			.par_iter()
+			.map(|plaintext| self.encoder.decode(plaintext.into()))
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(chunks.into_iter().flatten().collect())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -116,4 +173,38 @@ mod tests {
 
 		assert_eq!(data, data_2);
 	}
+
+	#[cfg(feature = "parallel")]
+	#[test]
+	fn par_encode_and_par_decode_match_the_sequential_versions() {
+		let params = BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulus::create(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulus::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		let ctx = Context::new(&params, false, SecurityLevel::TC128).unwrap();
+
+		let bfv_encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let encoder = BatchEncoder::new(bfv_encoder);
+
+		let mut data = Vec::with_capacity(32_768);
+
+		for i in 0..32_768 {
+			data.push(i as i64);
+		}
+
+		let plaintext = encoder.encode(data.as_slice()).unwrap();
+		let par_plaintext = encoder.par_encode(data.as_slice()).unwrap();
+
+		let data_2: Vec<i64> = encoder.decode(&plaintext).unwrap();
+		let par_data_2: Vec<i64> = encoder.par_decode(&par_plaintext).unwrap();
+
+		assert_eq!(data, data_2);
+		assert_eq!(data, par_data_2);
+	}
 }
@@ -1,8 +1,11 @@
 use crate::{
-	enc_marker, Asym, AsymmetricComponents, Ciphertext, Context, Encryptor, Plaintext, PublicKey,
-	Result, SecretKey, Sym, SymAsym, SymmetricComponents,
+	enc_marker, Asym, AsymmetricComponents, Ciphertext, CompactCiphertext, Context, Encryptor,
+	MemoryPool, Plaintext, PublicKey, Result, SecretKey, Sym, SymAsym, SymmetricComponents,
 };
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use super::Batch;
 
 /// Encryptor that can encrypt multiple messages at once.
@@ -90,6 +93,107 @@ impl<T: enc_marker::Asym> BatchEncryptor<T> {
 	}
 }
 
+#[cfg(feature = "parallel")]
+impl<T: enc_marker::Asym> BatchEncryptor<T> {
+	/// Encrypts every plaintext in the batch with the public key on a separate thread. Each
+	/// worker draws scratch space from its own [`MemoryPool::thread_local`] handle rather than
+	/// the global pool, so threads encrypting different elements don't contend on the same
+	/// allocations.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	pub fn par_encrypt(&self, plaintext_batch: &Batch<Plaintext>) -> Result<Batch<Ciphertext>> {
+		plaintext_batch
+			.0
+			.par_iter()
+			.map(|plaintext| {
+				let pool = MemoryPool::thread_local()?;
+				self.encryptor.encrypt_with_pool(plaintext, &pool)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Encrypts every plaintext in the batch with the public key on a separate thread, also
+	/// returning the u and e values used in encrypting each element. See [`Self::par_encrypt`]
+	/// for the per-worker memory pool rationale. Output ordering matches `plaintext_batch`.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	pub fn par_encrypt_return_components(
+		&self, plaintext_batch: &Batch<Plaintext>,
+	) -> Result<Batch<(Ciphertext, AsymmetricComponents)>> {
+		plaintext_batch
+			.0
+			.par_iter()
+			.map(|plaintext| {
+				let pool = MemoryPool::thread_local()?;
+				self.encryptor
+					.encrypt_return_components_with_pool(plaintext, &pool)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+}
+
+#[cfg(feature = "deterministic")]
+impl<T: enc_marker::Asym> BatchEncryptor<T> {
+	/// DO NOT USE THIS FUNCTION IN PRODUCTION: IT PRODUCES DETERMINISTIC
+	/// ENCRYPTIONS. IT IS INHERENTLY INSECURE, AND ONLY MEANT FOR TESTING OR
+	/// DEMONSTRATION PURPOSES.
+	///
+	/// Encrypts every plaintext in the batch with the public key, deriving each element's PRNG
+	/// seed from `seed` via [`element_seed`] so that no two elements in the batch reuse the same
+	/// randomness — reusing one seed across distinct messages under the same key leaks relations
+	/// between them — while the whole batch still comes out byte-identical across runs given the
+	/// same `seed`.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	/// * `seed` - The base seed each element's sub-seed is derived from.
+	pub fn encrypt_deterministic(
+		&self, plaintext_batch: &Batch<Plaintext>, seed: &[u64; 8],
+	) -> Result<Batch<Ciphertext>> {
+		plaintext_batch
+			.0
+			.iter()
+			.enumerate()
+			.map(|(index, plaintext)| {
+				self.encryptor
+					.encrypt_deterministic(plaintext, &element_seed(seed, index))
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+}
+
+#[cfg(all(feature = "parallel", feature = "deterministic"))]
+impl<T: enc_marker::Asym> BatchEncryptor<T> {
+	/// DO NOT USE THIS FUNCTION IN PRODUCTION: IT PRODUCES DETERMINISTIC
+	/// ENCRYPTIONS. IT IS INHERENTLY INSECURE, AND ONLY MEANT FOR TESTING OR
+	/// DEMONSTRATION PURPOSES.
+	///
+	/// Encrypts every plaintext in the batch with the public key on a separate thread, deriving
+	/// each element's PRNG seed from `seed` via [`element_seed`]. Because each element's sub-seed
+	/// is a pure function of its index rather than the order workers finish in, the output is
+	/// byte-identical to [`Self::encrypt_deterministic`] regardless of how the threads are
+	/// scheduled.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	/// * `seed` - The base seed each element's sub-seed is derived from.
+	pub fn par_encrypt_deterministic(
+		&self, plaintext_batch: &Batch<Plaintext>, seed: &[u64; 8],
+	) -> Result<Batch<Ciphertext>> {
+		plaintext_batch
+			.0
+			.par_iter()
+			.enumerate()
+			.map(|(index, plaintext)| {
+				self.encryptor
+					.encrypt_deterministic(plaintext, &element_seed(seed, index))
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+}
+
 impl<T: enc_marker::Sym> BatchEncryptor<T> {
 	/// Encrypts a plaintext with the secret key and returns the ciphertext as
 	/// a serializable object.
@@ -129,4 +233,143 @@ impl<T: enc_marker::Sym> BatchEncryptor<T> {
 			})
 			.collect()
 	}
+
+	/// Encrypts every plaintext in the batch with the secret key using SEAL's seed-compression,
+	/// returning a [`Batch<CompactCiphertext>`] whose noise polynomials have been replaced by the
+	/// PRNG seeds that generated them, roughly halving the serialized size of the batch. A
+	/// receiver holding the matching [`Context`] must call [`Batch::expand_compact`] (or
+	/// [`CompactCiphertext::expand`] element-by-element) before evaluating or decrypting any
+	/// element.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	pub fn encrypt_symmetric_compact(
+		&self, plaintext_batch: &Batch<Plaintext>,
+	) -> Result<Batch<CompactCiphertext>> {
+		plaintext_batch
+			.map(|plaintext| self.encryptor.encrypt_symmetric_compact(plaintext))
+			.collect()
+	}
+}
+
+impl Batch<CompactCiphertext> {
+	/// Expands every seed-compressed ciphertext in this batch back into a full [`Ciphertext`]
+	/// that can be used in homomorphic evaluation or decryption. See
+	/// [`CompactCiphertext::expand`] for the per-element operation.
+	///
+	/// * `context` - The context the batch was encrypted under.
+	pub fn expand_compact(&self, context: &Context) -> Result<Batch<Ciphertext>> {
+		self.map(|compact| compact.expand(context)).collect()
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl<T: enc_marker::Sym> BatchEncryptor<T> {
+	/// Encrypts every plaintext in the batch with the secret key on a separate thread. See
+	/// [`BatchEncryptor::par_encrypt`] for the asymmetric counterpart and the per-worker memory
+	/// pool rationale.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	pub fn par_encrypt_symmetric(
+		&self, plaintext_batch: &Batch<Plaintext>,
+	) -> Result<Batch<Ciphertext>> {
+		plaintext_batch
+			.0
+			.par_iter()
+			.map(|plaintext| {
+				let pool = MemoryPool::thread_local()?;
+				self.encryptor.encrypt_symmetric_with_pool(plaintext, &pool)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Encrypts every plaintext in the batch with the secret key on a separate thread, also
+	/// returning the e and r values used in encrypting each element. See
+	/// [`BatchEncryptor::par_encrypt_return_components`] for the asymmetric counterpart.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	pub fn par_encrypt_symmetric_return_components(
+		&self, plaintext_batch: &Batch<Plaintext>,
+	) -> Result<Batch<(Ciphertext, SymmetricComponents)>> {
+		plaintext_batch
+			.0
+			.par_iter()
+			.map(|plaintext| {
+				let pool = MemoryPool::thread_local()?;
+				self.encryptor
+					.encrypt_symmetric_return_components_with_pool(plaintext, &pool)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+}
+
+#[cfg(all(feature = "parallel", feature = "deterministic"))]
+impl<T: enc_marker::Sym> BatchEncryptor<T> {
+	/// DO NOT USE THIS FUNCTION IN PRODUCTION: IT PRODUCES DETERMINISTIC
+	/// ENCRYPTIONS. IT IS INHERENTLY INSECURE, AND ONLY MEANT FOR TESTING OR
+	/// DEMONSTRATION PURPOSES.
+	///
+	/// Encrypts every plaintext in the batch with the secret key on a separate thread. See
+	/// [`BatchEncryptor::par_encrypt_deterministic`] for the asymmetric counterpart and the
+	/// per-element sub-seed rationale.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	/// * `seed` - The base seed each element's sub-seed is derived from.
+	pub fn par_encrypt_symmetric_deterministic(
+		&self, plaintext_batch: &Batch<Plaintext>, seed: &[u64; 8],
+	) -> Result<Batch<Ciphertext>> {
+		plaintext_batch
+			.0
+			.par_iter()
+			.enumerate()
+			.map(|(index, plaintext)| {
+				self.encryptor
+					.encrypt_symmetric_deterministic(plaintext, &element_seed(seed, index))
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+}
+
+#[cfg(feature = "deterministic")]
+impl<T: enc_marker::Sym> BatchEncryptor<T> {
+	/// DO NOT USE THIS FUNCTION IN PRODUCTION: IT PRODUCES DETERMINISTIC
+	/// ENCRYPTIONS. IT IS INHERENTLY INSECURE, AND ONLY MEANT FOR TESTING OR
+	/// DEMONSTRATION PURPOSES.
+	///
+	/// Encrypts every plaintext in the batch with the secret key. See
+	/// [`BatchEncryptor::encrypt_deterministic`] for the asymmetric counterpart and the
+	/// per-element sub-seed rationale.
+	///
+	/// * `plaintext_batch` - The plaintext to encrypt.
+	/// * `seed` - The base seed each element's sub-seed is derived from.
+	pub fn encrypt_symmetric_deterministic(
+		&self, plaintext_batch: &Batch<Plaintext>, seed: &[u64; 8],
+	) -> Result<Batch<Ciphertext>> {
+		plaintext_batch
+			.0
+			.iter()
+			.enumerate()
+			.map(|(index, plaintext)| {
+				self.encryptor
+					.encrypt_symmetric_deterministic(plaintext, &element_seed(seed, index))
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+}
+
+/// Derives the PRNG seed for batch element `index` from a shared base `seed`, by XORing the
+/// index into the seed's last 64-bit word. This keeps every element's randomness distinct (so
+/// no two ciphertexts in a deterministically-encrypted batch share the same `c1`/ephemeral
+/// polynomial) while staying a pure function of `(seed, index)`, so the whole batch is still
+/// byte-identical across runs given the same base `seed`.
+#[cfg(feature = "deterministic")]
+fn element_seed(
+	seed: &[u64; 8], index: usize,
+) -> [u64; 8] {
+	let mut element_seed = *seed;
+	element_seed[7] ^= index as u64;
+	element_seed
 }
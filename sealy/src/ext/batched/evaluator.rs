@@ -0,0 +1,456 @@
+use super::Batch;
+use crate::{Ciphertext, Evaluator, GaloisKey, Plaintext, RelinearizationKey, Result};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// An evaluator that applies the operations of the wrapped [`Evaluator`] to every element of
+/// a [`Batch`], elementwise.
+pub struct BatchEvaluator<E> {
+	evaluator: E,
+}
+
+impl<E> BatchEvaluator<E> {
+	/// Creates a new BatchEvaluator wrapping the given evaluator.
+	pub fn new(evaluator: E) -> Self {
+		Self {
+			evaluator,
+		}
+	}
+}
+
+impl<E> BatchEvaluator<E>
+where
+	E: Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+{
+	/// Negates every ciphertext in the batch.
+	///  * `a` - the batch to negate
+	pub fn negate(
+		&self,
+		a: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.map(|c| self.evaluator.negate(c)).collect()
+	}
+
+	/// Adds two batches of ciphertexts elementwise.
+	///  * `a` - first operand
+	///  * `b` - second operand
+	pub fn add(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.add(a, b)).collect()
+	}
+
+	/// Performs an elementwise addition reduction of multiple batches of ciphertexts.
+	///  * `a` - a slice of batches to sum.
+	pub fn add_many(
+		&self,
+		a: &[Batch<Ciphertext>],
+	) -> Result<Batch<Ciphertext>> {
+		let len = a.first().map(|t| t.len()).unwrap_or(0);
+
+		(0..len)
+			.map(|i| {
+				let ciphertexts = a
+					.iter()
+					.map(|batch| batch.get(i).expect("batch length mismatch"))
+					.cloned()
+					.collect::<Vec<_>>();
+
+				self.evaluator.add_many(&ciphertexts)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Subtracts `b` from `a` elementwise.
+	///  * `a` - the left operand
+	///  * `b` - the right operand
+	pub fn sub(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.sub(a, b)).collect()
+	}
+
+	/// Multiplies two batches of ciphertexts elementwise.
+	///  * `a` - the left operand
+	///  * `b` - the right operand
+	pub fn multiply(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.multiply(a, b)).collect()
+	}
+
+	/// Performs an elementwise multiplication reduction of multiple batches of ciphertexts,
+	/// relinearizing after each operation.
+	///  * `a` - a slice of batches to multiply.
+	///  * `relin_keys` - the relinearization keys.
+	pub fn multiply_many(
+		&self,
+		a: &[Batch<Ciphertext>],
+		relin_keys: &RelinearizationKey,
+	) -> Result<Batch<Ciphertext>> {
+		let len = a.first().map(|t| t.len()).unwrap_or(0);
+
+		(0..len)
+			.map(|i| {
+				let ciphertexts = a
+					.iter()
+					.map(|batch| batch.get(i).expect("batch length mismatch"))
+					.cloned()
+					.collect::<Vec<_>>();
+
+				self.evaluator.multiply_many(&ciphertexts, relin_keys)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Squares every ciphertext in the batch.
+	///  * `a` - the batch to square
+	pub fn square(
+		&self,
+		a: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.map(|c| self.evaluator.square(c)).collect()
+	}
+
+	/// Adds a batch of ciphertexts and a batch of plaintexts elementwise.
+	///  * `a` - the ciphertext batch
+	///  * `b` - the plaintext batch
+	pub fn add_plain(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Plaintext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.add_plain(a, b)).collect()
+	}
+
+	/// Subtracts a batch of plaintexts from a batch of ciphertexts elementwise.
+	///  * `a` - the ciphertext batch
+	///  * `b` - the plaintext batch
+	pub fn sub_plain(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Plaintext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.sub_plain(a, b)).collect()
+	}
+
+	/// Multiplies a batch of ciphertexts by a batch of plaintexts elementwise.
+	///  * `a` - the ciphertext batch
+	///  * `b` - the plaintext batch
+	pub fn multiply_plain(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Plaintext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.multiply_plain(a, b))
+			.collect()
+	}
+
+	/// Relinearizes every ciphertext in the batch, reducing each to 2 polynomials.
+	///  * `a` - the batch to relinearize
+	///  * `relin_keys` - the relinearization keys
+	pub fn relinearize(
+		&self,
+		a: &Batch<Ciphertext>,
+		relin_keys: &RelinearizationKey,
+	) -> Result<Batch<Ciphertext>> {
+		a.map(|c| self.evaluator.relinearize(c, relin_keys)).collect()
+	}
+
+	/// Rotates the plaintext matrix rows of every ciphertext in the batch cyclically.
+	///  * `a` - the batch to rotate
+	///  * `steps` - the number of steps to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_rows(
+		&self,
+		a: &Batch<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Batch<Ciphertext>> {
+		a.map(|c| self.evaluator.rotate_rows(c, steps, galois_keys))
+			.collect()
+	}
+
+	/// Rotates the plaintext matrix columns of every ciphertext in the batch cyclically.
+	///  * `a` - the batch to rotate
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_columns(
+		&self,
+		a: &Batch<Ciphertext>,
+		galois_keys: &GaloisKey,
+	) -> Result<Batch<Ciphertext>> {
+		a.map(|c| self.evaluator.rotate_columns(c, galois_keys))
+			.collect()
+	}
+
+	/// Rotates the packed vector of every ciphertext in the batch cyclically.
+	///  * `a` - the batch to rotate
+	///  * `steps` - the number of slots to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_vector(
+		&self,
+		a: &Batch<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Batch<Ciphertext>> {
+		a.map(|c| self.evaluator.rotate_vector(c, steps, galois_keys))
+			.collect()
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl<E> BatchEvaluator<E>
+where
+	E: Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext> + Sync,
+{
+	/// Negates every ciphertext in the batch on a separate thread.
+	///  * `a` - the batch to negate
+	pub fn par_negate(
+		&self,
+		a: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.map(|c| self.evaluator.negate(c))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Adds two batches of ciphertexts elementwise, on separate threads.
+	///  * `a` - first operand
+	///  * `b` - second operand
+	pub fn par_add(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.zip(b.0.par_iter())
+			.map(|(a, b)| self.evaluator.add(a, b))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Performs an elementwise addition reduction of multiple batches of ciphertexts, on
+	/// separate threads.
+	///  * `a` - a slice of batches to sum.
+	pub fn par_add_many(
+		&self,
+		a: &[Batch<Ciphertext>],
+	) -> Result<Batch<Ciphertext>> {
+		let len = a.first().map(|t| t.len()).unwrap_or(0);
+
+		(0..len)
+			.into_par_iter()
+			.map(|i| {
+				let ciphertexts = a
+					.iter()
+					.map(|batch| batch.get(i).expect("batch length mismatch"))
+					.cloned()
+					.collect::<Vec<_>>();
+
+				self.evaluator.add_many(&ciphertexts)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Subtracts `b` from `a` elementwise, on separate threads.
+	///  * `a` - the left operand
+	///  * `b` - the right operand
+	pub fn par_sub(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.zip(b.0.par_iter())
+			.map(|(a, b)| self.evaluator.sub(a, b))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Multiplies two batches of ciphertexts elementwise, on separate threads.
+	///  * `a` - the left operand
+	///  * `b` - the right operand
+	pub fn par_multiply(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.zip(b.0.par_iter())
+			.map(|(a, b)| self.evaluator.multiply(a, b))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Performs an elementwise multiplication reduction of multiple batches of ciphertexts,
+	/// relinearizing after each operation, on separate threads.
+	///  * `a` - a slice of batches to multiply.
+	///  * `relin_keys` - the relinearization keys.
+	pub fn par_multiply_many(
+		&self,
+		a: &[Batch<Ciphertext>],
+		relin_keys: &RelinearizationKey,
+	) -> Result<Batch<Ciphertext>> {
+		let len = a.first().map(|t| t.len()).unwrap_or(0);
+
+		(0..len)
+			.into_par_iter()
+			.map(|i| {
+				let ciphertexts = a
+					.iter()
+					.map(|batch| batch.get(i).expect("batch length mismatch"))
+					.cloned()
+					.collect::<Vec<_>>();
+
+				self.evaluator.multiply_many(&ciphertexts, relin_keys)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Squares every ciphertext in the batch, on separate threads.
+	///  * `a` - the batch to square
+	pub fn par_square(
+		&self,
+		a: &Batch<Ciphertext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.map(|c| self.evaluator.square(c))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Adds a batch of ciphertexts and a batch of plaintexts elementwise, on separate threads.
+	///  * `a` - the ciphertext batch
+	///  * `b` - the plaintext batch
+	pub fn par_add_plain(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Plaintext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.zip(b.0.par_iter())
+			.map(|(a, b)| self.evaluator.add_plain(a, b))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Subtracts a batch of plaintexts from a batch of ciphertexts elementwise, on separate
+	/// threads.
+	///  * `a` - the ciphertext batch
+	///  * `b` - the plaintext batch
+	pub fn par_sub_plain(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Plaintext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.zip(b.0.par_iter())
+			.map(|(a, b)| self.evaluator.sub_plain(a, b))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Multiplies a batch of ciphertexts by a batch of plaintexts elementwise, on separate
+	/// threads.
+	///  * `a` - the ciphertext batch
+	///  * `b` - the plaintext batch
+	pub fn par_multiply_plain(
+		&self,
+		a: &Batch<Ciphertext>,
+		b: &Batch<Plaintext>,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.zip(b.0.par_iter())
+			.map(|(a, b)| self.evaluator.multiply_plain(a, b))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Relinearizes every ciphertext in the batch, reducing each to 2 polynomials, on separate
+	/// threads.
+	///  * `a` - the batch to relinearize
+	///  * `relin_keys` - the relinearization keys
+	pub fn par_relinearize(
+		&self,
+		a: &Batch<Ciphertext>,
+		relin_keys: &RelinearizationKey,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.map(|c| self.evaluator.relinearize(c, relin_keys))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Rotates the plaintext matrix rows of every ciphertext in the batch cyclically, on
+	/// separate threads.
+	///  * `a` - the batch to rotate
+	///  * `steps` - the number of steps to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn par_rotate_rows(
+		&self,
+		a: &Batch<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.map(|c| self.evaluator.rotate_rows(c, steps, galois_keys))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Rotates the plaintext matrix columns of every ciphertext in the batch cyclically, on
+	/// separate threads.
+	///  * `a` - the batch to rotate
+	///  * `galois_keys` - the Galois keys
+	pub fn par_rotate_columns(
+		&self,
+		a: &Batch<Ciphertext>,
+		galois_keys: &GaloisKey,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.map(|c| self.evaluator.rotate_columns(c, galois_keys))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+
+	/// Rotates the packed vector of every ciphertext in the batch cyclically, on separate
+	/// threads.
+	///  * `a` - the batch to rotate
+	///  * `steps` - the number of slots to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn par_rotate_vector(
+		&self,
+		a: &Batch<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Batch<Ciphertext>> {
+		a.0
+			.par_iter()
+			.map(|c| self.evaluator.rotate_vector(c, steps, galois_keys))
+			.collect::<Result<Vec<_>>>()
+			.map(Batch)
+	}
+}
@@ -2,6 +2,10 @@ use crate::Context;
 use crate::FromBytes;
 use crate::Result;
 use crate::ToBytes;
+use crate::{FromChunk, ToChunk};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 pub mod decryptor;
 pub mod encoder;
@@ -99,6 +103,39 @@ impl<T> Batch<T> {
 	}
 }
 
+#[cfg(feature = "parallel")]
+impl<T> Batch<T>
+where
+	T: Sync,
+{
+	/// Applies the given function to each element in this batch on a separate thread,
+	/// returning a new batch with the results.
+	pub fn par_map<U, F>(&self, f: F) -> Batch<U>
+	where
+		F: Fn(&T) -> U + Sync + Send,
+		U: Send,
+	{
+		Batch(self.0.par_iter().map(f).collect())
+	}
+
+	/// zips two batches together, applying the given function to each pair of elements on a
+	/// separate thread.
+	pub fn par_zip<U, V, F>(&self, other: &Batch<U>, f: F) -> Batch<V>
+	where
+		U: Sync,
+		F: Fn(&T, &U) -> V + Sync + Send,
+		V: Send,
+	{
+		Batch(
+			self.0
+				.par_iter()
+				.zip(other.0.par_iter())
+				.map(|(a, b)| f(a, b))
+				.collect(),
+		)
+	}
+}
+
 impl<T> FromBatchedBytes for Batch<T>
 where
 	T: FromBytes,
@@ -121,6 +158,58 @@ where
 	}
 }
 
+#[cfg(feature = "parallel")]
+impl<T> Batch<T>
+where
+	T: ToBytes + Sync,
+{
+	/// Serializes every element of this batch to bytes on a separate thread, preserving the
+	/// same per-element ordering as [`ToBatchedBytes::as_batched_bytes`].
+	pub fn par_as_batched_bytes(&self) -> Result<Vec<Vec<u8>>> {
+		self.0.par_iter().map(|value| value.as_bytes()).collect()
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl<T> Batch<T>
+where
+	T: FromBytes + Send,
+{
+	/// Deserializes every element of `batched` under `context` on a separate thread, preserving
+	/// the same per-element ordering as [`FromBatchedBytes::from_batched_bytes`].
+	pub fn par_from_batched_bytes(
+		context: &Context,
+		batched: &[Vec<u8>],
+	) -> Result<Self> {
+		let values = batched
+			.par_iter()
+			.map(|bytes| T::from_bytes(context, bytes))
+			.collect::<Result<Vec<_>>>()?;
+		Ok(Batch(values))
+	}
+}
+
+impl<T> ToChunk for Batch<T>
+where
+	T: ToBytes,
+{
+	fn to_chunk(&self) -> Result<Vec<Vec<u8>>> {
+		self.as_batched_bytes()
+	}
+}
+
+impl<T> FromChunk for Batch<T>
+where
+	T: FromBytes,
+{
+	fn from_chunk(
+		context: &Context,
+		chunks: &[Vec<u8>],
+	) -> Result<Self> {
+		Self::from_batched_bytes(context, chunks)
+	}
+}
+
 impl<T> Batch<T>
 where
 	T: Clone,
@@ -1,5 +1,9 @@
 //! Extension modules for seal bindings.
 
+/// Operations on batches of ciphertexts. Functionally equivalent to [`tensor`], but written
+/// against a flat `Vec<T>` rather than a shape-aware tensor.
+pub mod batched;
+
 /// Operations in tensor of ciphertexts. It allows us to perform operations
 /// on multiple ciphertexts at once, dribbling the size limits of the scheme.
 pub mod tensor;
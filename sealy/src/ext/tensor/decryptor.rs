@@ -0,0 +1,79 @@
+use super::Tensor;
+use crate::{Ciphertext, Context, Decryptor, Plaintext, Result, SecretKey};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Decrypts tensors of ciphertexts.
+pub struct TensorDecryptor {
+	decryptor: Decryptor,
+}
+
+impl TensorDecryptor {
+	/// Creates a new tensor decryptor.
+	pub fn new(
+		ctx: &Context,
+		secret_key: &SecretKey,
+	) -> Result<Self> {
+		Ok(Self {
+			decryptor: Decryptor::new(ctx, secret_key)?,
+		})
+	}
+}
+
+impl TensorDecryptor {
+	/// Decrypts a tensor of ciphertexts and returns the tensor of plaintexts.
+	///
+	/// * `ciphertext_tensor` - The tensor of ciphertexts to decrypt.
+	pub fn decrypt(
+		&self,
+		ciphertext_tensor: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Plaintext>> {
+		ciphertext_tensor
+			.map(|ciphertext| self.decryptor.decrypt(ciphertext))
+			.collect()
+	}
+
+	/// Lazily decrypts each ciphertext yielded by `ciphertexts`, one plaintext at a time,
+	/// instead of requiring the whole [`Tensor<Ciphertext>`] to already be in memory. Pairs
+	/// with [`super::encryptor::TensorEncryptor::encrypt_stream`] on the sending side and
+	/// [`super::encoder::TensorEncoder::decode_f64_stream`] downstream, so a caller can pipe
+	/// encode→encrypt→network→decrypt→decode without ever holding the whole tensor's
+	/// ciphertexts in RAM.
+	///
+	/// * `ciphertexts` - The ciphertexts to decrypt, e.g. as they arrive off a network socket.
+	pub fn decrypt_stream<'a, I>(
+		&'a self,
+		ciphertexts: I,
+	) -> impl Iterator<Item = Result<Plaintext>> + 'a
+	where
+		I: IntoIterator<Item = Result<Ciphertext>> + 'a,
+	{
+		ciphertexts
+			.into_iter()
+			.map(move |ciphertext| ciphertext.and_then(|ciphertext| self.decryptor.decrypt(&ciphertext)))
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl TensorDecryptor {
+	/// Decrypts every ciphertext in the tensor on a separate thread.
+	///
+	/// Unlike [`super::encoder::TensorEncoder::par_encode_f64`] and
+	/// [`super::encryptor::TensorEncryptor::par_encrypt`], workers here share the global memory
+	/// pool rather than one per thread: SEAL's `Decryptor_Decrypt` takes no `MemoryPoolHandle`
+	/// argument, so there is no scratch allocation to isolate per worker.
+	///
+	/// * `ciphertext_tensor` - The tensor of ciphertexts to decrypt.
+	pub fn par_decrypt(
+		&self,
+		ciphertext_tensor: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Plaintext>> {
+		ciphertext_tensor
+			.0
+			.par_iter()
+			.map(|ciphertext| self.decryptor.decrypt(ciphertext))
+			.collect::<Result<Vec<_>>>()
+			.map(Tensor)
+	}
+}
@@ -0,0 +1,783 @@
+use super::{ShapedTensor, Tensor};
+use crate::{BFVEncoder, CKKSEncoder, Error, MemoryPool, Plaintext, Result, SlotCount};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// An encoder that encodes data into tensors of plaintexts, splitting the data across as
+/// many plaintexts as are needed to hold it given the encoder's slot count.
+///
+/// With the `parallel` feature enabled, [`TensorEncoder::par_encode_f64`]/
+/// [`TensorEncoder::par_decode_f64`] dispatch across chunks/plaintexts using rayon instead of a
+/// sequential `for` loop. By default this uses rayon's global thread pool; call
+/// [`TensorEncoder::set_parallelism`] to confine it to a pool of a fixed size instead, e.g. to
+/// avoid starving other work sharing the process.
+pub struct TensorEncoder<E> {
+	encoder: E,
+	#[cfg(feature = "parallel")]
+	pool: Option<rayon::ThreadPool>,
+}
+
+impl<E> TensorEncoder<E> {
+	/// Creates a new TensorEncoder.
+	pub fn new(encoder: E) -> Self {
+		Self {
+			encoder,
+			#[cfg(feature = "parallel")]
+			pool: None,
+		}
+	}
+}
+
+impl<E: SlotCount> SlotCount for TensorEncoder<E> {
+	fn get_slot_count(&self) -> usize {
+		self.encoder.get_slot_count()
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl<E> TensorEncoder<E> {
+	/// Confines every parallel dispatch on this encoder to a dedicated pool of `num_threads`
+	/// worker threads, instead of rayon's process-wide global pool. Pass `0` to revert to the
+	/// global pool.
+	pub fn set_parallelism(
+		&mut self,
+		num_threads: usize,
+	) -> Result<()> {
+		if num_threads == 0 {
+			self.pool = None;
+			return Ok(());
+		}
+
+		let pool = rayon::ThreadPoolBuilder::new()
+			.num_threads(num_threads)
+			.build()
+			.map_err(|_| Error::Unexpected)?;
+
+		self.pool = Some(pool);
+
+		Ok(())
+	}
+
+	/// Runs `f` on this encoder's dedicated pool if [`TensorEncoder::set_parallelism`] was
+	/// called, otherwise on rayon's global pool.
+	fn dispatch<R: Send>(
+		&self,
+		f: impl FnOnce() -> R + Send,
+	) -> R {
+		match &self.pool {
+			Some(pool) => pool.install(f),
+			None => f(),
+		}
+	}
+}
+
+impl TensorEncoder<CKKSEncoder> {
+	/// Returns the number of slots in this encoder produces.
+	pub fn get_slot_count(&self) -> usize {
+		self.encoder.get_slot_count()
+	}
+
+	/// Encodes the given data into a tensor of plaintexts, splitting it into as many
+	/// plaintexts as are needed to hold it given the encoder's slot count.
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	///
+	/// # Returns
+	/// The encoded tensor of plaintexts.
+	pub fn encode_f64(
+		&self,
+		data: &[f64],
+	) -> Result<Tensor<Plaintext>> {
+		let mut plaintexts = Vec::new();
+
+		let slot_count = self.get_slot_count();
+
+		for chunk in data.chunks(slot_count) {
+			plaintexts.push(self.encoder.encode_f64(chunk)?);
+		}
+
+		Ok(Tensor(plaintexts))
+	}
+
+	/// Decodes the given tensor of plaintexts into data.
+	///
+	/// # Arguments
+	/// * `tensor` - The encoded tensor of plaintexts.
+	///
+	/// # Returns
+	/// The decoded data.
+	pub fn decode_f64(
+		&self,
+		tensor: &Tensor<Plaintext>,
+	) -> Result<Vec<f64>> {
+		let mut data = Vec::new();
+
+		for plaintext in tensor {
+			data.extend(self.encoder.decode_f64(plaintext)?);
+		}
+
+		Ok(data)
+	}
+
+	/// Encodes the given data into a tensor of plaintexts, allocating scratch memory from
+	/// `pool` instead of the global memory pool for every chunk.
+	///
+	/// Reusing one `pool` across a batch of rounds (instead of letting each chunk allocate
+	/// its own scratch space) lets callers cap and reclaim working memory deterministically,
+	/// which matters once `data` is large enough that per-round allocator growth shows up.
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	/// * `pool` - The memory pool to allocate scratch space from.
+	///
+	/// # Returns
+	/// The encoded tensor of plaintexts.
+	pub fn encode_f64_with_pool(
+		&self,
+		data: &[f64],
+		pool: &MemoryPool,
+	) -> Result<Tensor<Plaintext>> {
+		let mut plaintexts = Vec::new();
+
+		let slot_count = self.get_slot_count();
+
+		for chunk in data.chunks(slot_count) {
+			plaintexts.push(self.encoder.encode_f64_with_pool(chunk, pool)?);
+		}
+
+		Ok(Tensor(plaintexts))
+	}
+
+	/// Lazily encodes `data` into plaintexts one slot-count-sized chunk at a time, instead of
+	/// collecting the whole [`Tensor<Plaintext>`] up front. Pairs with
+	/// [`super::encryptor::TensorEncryptor::encrypt_stream`] and
+	/// [`super::decryptor::TensorDecryptor::decrypt_stream`] so a caller can pipe
+	/// encode→encrypt→network→decrypt→decode without ever holding every chunk of a large
+	/// tensor in memory at once.
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	pub fn encode_f64_stream<'a>(
+		&'a self,
+		data: &'a [f64],
+	) -> impl Iterator<Item = Result<Plaintext>> + 'a {
+		data.chunks(self.get_slot_count())
+			.map(move |chunk| self.encoder.encode_f64(chunk))
+	}
+
+	/// Lazily decodes each plaintext yielded by `plaintexts`, one chunk at a time, instead of
+	/// requiring the whole [`Tensor<Plaintext>`] to already be in memory. See
+	/// [`Self::encode_f64_stream`] for the matching encode-side adapter.
+	///
+	/// # Arguments
+	/// * `plaintexts` - The plaintexts to decode, as produced by
+	///   [`super::decryptor::TensorDecryptor::decrypt_stream`].
+	pub fn decode_f64_stream<'a, I>(
+		&'a self,
+		plaintexts: I,
+	) -> impl Iterator<Item = Result<Vec<f64>>> + 'a
+	where
+		I: IntoIterator<Item = Result<Plaintext>> + 'a,
+	{
+		plaintexts
+			.into_iter()
+			.map(move |plaintext| plaintext.and_then(|plaintext| self.encoder.decode_f64(&plaintext)))
+	}
+
+	/// Decodes the given tensor of plaintexts into data, allocating scratch memory from
+	/// `pool` instead of the global memory pool for every plaintext.
+	///
+	/// # Arguments
+	/// * `tensor` - The encoded tensor of plaintexts.
+	/// * `pool` - The memory pool to allocate scratch space from.
+	///
+	/// # Returns
+	/// The decoded data.
+	pub fn decode_f64_with_pool(
+		&self,
+		tensor: &Tensor<Plaintext>,
+		pool: &MemoryPool,
+	) -> Result<Vec<f64>> {
+		let mut data = Vec::new();
+
+		for plaintext in tensor {
+			data.extend(self.encoder.decode_f64_with_pool(plaintext, pool)?);
+		}
+
+		Ok(data)
+	}
+
+	/// Encodes `data` into a [`ShapedTensor`], recording `shape` as the logical dimensions
+	/// (e.g. `[batch, features]`) it was drawn from.
+	///
+	/// # Errors
+	/// Returns [`crate::Error::InvalidArgument`] if `shape.iter().product()` doesn't equal
+	/// `data.len()`.
+	pub fn encode_f64_shaped(
+		&self,
+		data: &[f64],
+		shape: Vec<usize>,
+	) -> Result<ShapedTensor<Plaintext>> {
+		let expected: usize = shape.iter().product();
+
+		if expected != data.len() {
+			return Err(Error::InvalidArgument);
+		}
+
+		let tensor = self.encode_f64(data)?;
+
+		Ok(ShapedTensor::from_parts_unchecked(tensor, shape))
+	}
+
+	/// Decodes a [`ShapedTensor`] produced by [`TensorEncoder::encode_f64_shaped`], returning
+	/// the flat data alongside the shape it was encoded with.
+	pub fn decode_f64_shaped(
+		&self,
+		tensor: &ShapedTensor<Plaintext>,
+	) -> Result<(Vec<f64>, Vec<usize>)> {
+		let data = self.decode_f64(tensor.tensor())?;
+
+		Ok((data, tensor.dims().to_vec()))
+	}
+}
+
+impl TensorEncoder<BFVEncoder> {
+	/// Returns the number of slots this encoder produces.
+	pub fn get_slot_count(&self) -> usize {
+		self.encoder.get_slot_count()
+	}
+
+	/// Encodes the given data into a tensor of plaintexts, splitting it into as many
+	/// plaintexts as are needed to hold it given the encoder's slot count.
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	///
+	/// # Returns
+	/// The encoded tensor of plaintexts.
+	pub fn encode_i64(
+		&self,
+		data: &[i64],
+	) -> Result<Tensor<Plaintext>> {
+		let mut plaintexts = Vec::new();
+
+		let slot_count = self.get_slot_count();
+
+		for chunk in data.chunks(slot_count) {
+			plaintexts.push(self.encoder.encode_i64(chunk)?);
+		}
+
+		Ok(Tensor(plaintexts))
+	}
+
+	/// Decodes the given tensor of plaintexts into data.
+	///
+	/// # Arguments
+	/// * `tensor` - The encoded tensor of plaintexts.
+	///
+	/// # Returns
+	/// The decoded data.
+	pub fn decode_i64(
+		&self,
+		tensor: &Tensor<Plaintext>,
+	) -> Result<Vec<i64>> {
+		let mut data = Vec::new();
+
+		for plaintext in tensor {
+			data.extend(self.encoder.decode_i64(plaintext)?);
+		}
+
+		Ok(data)
+	}
+
+	/// Encodes `data` into a [`ShapedTensor`], recording `shape` as the logical dimensions it
+	/// was drawn from. See [`TensorEncoder::encode_f64_shaped`] for the CKKS equivalent.
+	///
+	/// # Errors
+	/// Returns [`crate::Error::InvalidArgument`] if `shape.iter().product()` doesn't equal
+	/// `data.len()`.
+	pub fn encode_i64_shaped(
+		&self,
+		data: &[i64],
+		shape: Vec<usize>,
+	) -> Result<ShapedTensor<Plaintext>> {
+		let expected: usize = shape.iter().product();
+
+		if expected != data.len() {
+			return Err(Error::InvalidArgument);
+		}
+
+		let tensor = self.encode_i64(data)?;
+
+		Ok(ShapedTensor::from_parts_unchecked(tensor, shape))
+	}
+
+	/// Decodes a [`ShapedTensor`] produced by [`TensorEncoder::encode_i64_shaped`], returning
+	/// the flat data alongside the shape it was encoded with.
+	pub fn decode_i64_shaped(
+		&self,
+		tensor: &ShapedTensor<Plaintext>,
+	) -> Result<(Vec<i64>, Vec<usize>)> {
+		let data = self.decode_i64(tensor.tensor())?;
+
+		Ok((data, tensor.dims().to_vec()))
+	}
+}
+
+/// Names a column's target plaintext representation for [`MixedTensorEncoder`], mirroring the
+/// declarative `"int"`/`"float"`/`"bool"`/`"bytes"` conversion tables storage layers commonly use
+/// to map a schema field onto a concrete encode/decode operation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Conversion {
+	/// Encodes as a batched `i64` via [`TensorEncoder<BFVEncoder>`], so it needs a BFV-scheme
+	/// encoder.
+	Int,
+
+	/// Encodes as a batched, scaled `f64` via [`TensorEncoder<CKKSEncoder>`], so it needs a
+	/// CKKS-scheme encoder.
+	Float,
+
+	/// Encodes as a batched `i64` of `0`/`1` via [`TensorEncoder<BFVEncoder>`], so it needs a
+	/// BFV-scheme encoder.
+	Bool,
+
+	/// Passed through unencoded, e.g. for data the caller will encrypt or store separately.
+	Bytes,
+}
+
+impl Conversion {
+	/// Parses a conversion name, accepted case-insensitively: `"int"`, `"float"`, `"bool"`, or
+	/// `"bytes"`.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidArgument`] if `name` isn't one of those four.
+	pub fn from_name(name: &str) -> Result<Self> {
+		match name.to_ascii_lowercase().as_str() {
+			"int" => Ok(Self::Int),
+			"float" => Ok(Self::Float),
+			"bool" => Ok(Self::Bool),
+			"bytes" => Ok(Self::Bytes),
+			_ => Err(Error::InvalidArgument),
+		}
+	}
+
+	/// Returns this conversion's canonical name, as accepted by [`Self::from_name`].
+	pub fn name(&self) -> &'static str {
+		match self {
+			Self::Int => "int",
+			Self::Float => "float",
+			Self::Bool => "bool",
+			Self::Bytes => "bytes",
+		}
+	}
+}
+
+/// One column's worth of plaintext-side values, tagged with the [`Conversion`] it was (or will
+/// be) encoded under. Passed to [`MixedTensorEncoder::encode`] and returned by
+/// [`MixedTensorEncoder::decode`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+	/// Values for a [`Conversion::Int`] column.
+	Int(Vec<i64>),
+
+	/// Values for a [`Conversion::Float`] column.
+	Float(Vec<f64>),
+
+	/// Values for a [`Conversion::Bool`] column.
+	Bool(Vec<bool>),
+
+	/// Raw bytes for a [`Conversion::Bytes`] column.
+	Bytes(Vec<u8>),
+}
+
+/// One column's worth of SEAL-side plaintexts, as produced by [`MixedTensorEncoder::encode`] and
+/// consumed by [`MixedTensorEncoder::decode`].
+#[derive(Debug, Clone)]
+pub enum EncodedColumn {
+	/// A [`Conversion::Int`] column, encoded via a BFV-scheme encoder.
+	Int(Tensor<Plaintext>),
+
+	/// A [`Conversion::Float`] column, encoded via a CKKS-scheme encoder.
+	Float(Tensor<Plaintext>),
+
+	/// A [`Conversion::Bool`] column, encoded via a BFV-scheme encoder.
+	Bool(Tensor<Plaintext>),
+
+	/// A [`Conversion::Bytes`] column, passed through unencoded.
+	Bytes(Vec<u8>),
+}
+
+/// Encodes a record of heterogeneously-typed columns, dispatching each one to whichever real
+/// [`TensorEncoder`] its declared [`Conversion`] actually needs, instead of making the caller
+/// juggle [`TensorEncoder<BFVEncoder>`] and [`TensorEncoder<CKKSEncoder>`] directly.
+///
+/// A SEAL [`crate::Context`] is tied to exactly one [`crate::SchemeType`] at construction time,
+/// so a single `MixedTensorEncoder` can only ever hold one of the two real encoders at once:
+/// [`Conversion::Int`]/[`Conversion::Bool`] columns need a BFV-backed encoder, [`Conversion::Float`]
+/// columns need a CKKS-backed one. There is no encoder that can encode both in the same call;
+/// encoding or decoding a column whose [`Conversion`] doesn't match both its [`Column`]/
+/// [`EncodedColumn`] variant and the scheme this was built with returns
+/// [`Error::InvalidArgument`] rather than silently reinterpreting it.
+pub enum MixedTensorEncoder {
+	/// Backed by a BFV [`TensorEncoder`], serving [`Conversion::Int`] and [`Conversion::Bool`]
+	/// columns.
+	Bfv(TensorEncoder<BFVEncoder>),
+
+	/// Backed by a CKKS [`TensorEncoder`], serving [`Conversion::Float`] columns.
+	Ckks(TensorEncoder<CKKSEncoder>),
+}
+
+impl MixedTensorEncoder {
+	/// Wraps a BFV-backed tensor encoder, serving [`Conversion::Int`]/[`Conversion::Bool`]
+	/// columns.
+	pub fn bfv(encoder: TensorEncoder<BFVEncoder>) -> Self {
+		Self::Bfv(encoder)
+	}
+
+	/// Wraps a CKKS-backed tensor encoder, serving [`Conversion::Float`] columns.
+	pub fn ckks(encoder: TensorEncoder<CKKSEncoder>) -> Self {
+		Self::Ckks(encoder)
+	}
+
+	/// Returns the number of slots the backing encoder produces.
+	pub fn get_slot_count(&self) -> usize {
+		match self {
+			Self::Bfv(encoder) => encoder.get_slot_count(),
+			Self::Ckks(encoder) => encoder.get_slot_count(),
+		}
+	}
+
+	/// Encodes `column` under `conversion`, dispatching to whichever real encoder this was built
+	/// with.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidArgument`] if `conversion` doesn't match both `column`'s variant
+	/// and the scheme this encoder was constructed for.
+	pub fn encode(
+		&self,
+		conversion: Conversion,
+		column: &Column,
+	) -> Result<EncodedColumn> {
+		match (self, conversion, column) {
+			(Self::Bfv(encoder), Conversion::Int, Column::Int(values)) => {
+				Ok(EncodedColumn::Int(encoder.encode_i64(values)?))
+			}
+			(Self::Bfv(encoder), Conversion::Bool, Column::Bool(values)) => {
+				let as_i64: Vec<i64> = values.iter().map(|&v| v as i64).collect();
+				Ok(EncodedColumn::Bool(encoder.encode_i64(&as_i64)?))
+			}
+			(Self::Ckks(encoder), Conversion::Float, Column::Float(values)) => {
+				Ok(EncodedColumn::Float(encoder.encode_f64(values)?))
+			}
+			(_, Conversion::Bytes, Column::Bytes(data)) => Ok(EncodedColumn::Bytes(data.clone())),
+			_ => Err(Error::InvalidArgument),
+		}
+	}
+
+	/// Decodes `column`, which must have been produced by [`Self::encode`] using this same
+	/// encoder.
+	///
+	/// # Errors
+	/// Returns [`Error::InvalidArgument`] if `column`'s variant doesn't match the scheme this
+	/// encoder was constructed for.
+	pub fn decode(
+		&self,
+		column: &EncodedColumn,
+	) -> Result<Column> {
+		match (self, column) {
+			(Self::Bfv(encoder), EncodedColumn::Int(tensor)) => {
+				Ok(Column::Int(encoder.decode_i64(tensor)?))
+			}
+			(Self::Bfv(encoder), EncodedColumn::Bool(tensor)) => {
+				let values = encoder.decode_i64(tensor)?;
+				Ok(Column::Bool(values.into_iter().map(|v| v != 0).collect()))
+			}
+			(Self::Ckks(encoder), EncodedColumn::Float(tensor)) => {
+				Ok(Column::Float(encoder.decode_f64(tensor)?))
+			}
+			(_, EncodedColumn::Bytes(data)) => Ok(Column::Bytes(data.clone())),
+			_ => Err(Error::InvalidArgument),
+		}
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl TensorEncoder<CKKSEncoder> {
+	/// Encodes the given data into a tensor of plaintexts, splitting it into as many
+	/// plaintexts as are needed to hold it given the encoder's slot count, encoding each
+	/// chunk on a separate thread.
+	///
+	/// Each worker draws scratch space from its own [`MemoryPool::thread_local`] handle rather
+	/// than the global pool, so threads encoding different chunks don't contend on the same
+	/// allocations.
+	///
+	/// # Arguments
+	/// * `data` - The data to encode.
+	///
+	/// # Returns
+	/// The encoded tensor of plaintexts.
+	pub fn par_encode_f64(
+		&self,
+		data: &[f64],
+	) -> Result<Tensor<Plaintext>> {
+		let slot_count = self.get_slot_count();
+
+		self.dispatch(|| {
+			data.par_chunks(slot_count)
+				.map(|chunk| {
+					let pool = MemoryPool::thread_local()?;
+					self.encoder.encode_f64_with_pool(chunk, &pool)
+				})
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Decodes the given tensor of plaintexts into data, decoding each plaintext on a
+	/// separate thread.
+	///
+	/// Each worker draws scratch space from its own [`MemoryPool::thread_local`] handle rather
+	/// than the global pool, so threads decoding different plaintexts don't contend on the
+	/// same allocations.
+	///
+	/// # Arguments
+	/// * `tensor` - The encoded tensor of plaintexts.
+	///
+	/// # Returns
+	/// The decoded data.
+	pub fn par_decode_f64(
+		&self,
+		tensor: &Tensor<Plaintext>,
+	) -> Result<Vec<f64>> {
+		let chunks = self.dispatch(|| {
+			tensor
+				.0
+				.par_iter()
+				.map(|plaintext| {
+					let pool = MemoryPool::thread_local()?;
+					self.encoder.decode_f64_with_pool(plaintext, &pool)
+				})
+				.collect::<Result<Vec<_>>>()
+		})?;
+
+		Ok(chunks.into_iter().flatten().collect())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::ckks_ctx()
+	}
+
+	fn mk_bfv_ctx() -> Context {
+		let params = BFVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[test]
+	fn encode_f64_shaped_round_trips_the_shape() {
+		let ctx = mk_ctx();
+		let encoder = TensorEncoder::new(CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap());
+
+		let data: Vec<f64> = (0..6).map(|i| i as f64).collect();
+		let tensor = encoder.encode_f64_shaped(&data, vec![2, 3]).unwrap();
+
+		assert_eq!(tensor.dims(), &[2, 3]);
+
+		let (decoded, shape) = encoder.decode_f64_shaped(&tensor).unwrap();
+
+		assert_eq!(shape, vec![2, 3]);
+		for (expected, actual) in data.iter().zip(decoded.iter()) {
+			assert!((expected - actual).abs() < 1e-4);
+		}
+	}
+
+	#[cfg(feature = "parallel")]
+	#[test]
+	fn par_encode_f64_round_trips_through_par_decode_f64() {
+		let ctx = mk_ctx();
+		let mut encoder = TensorEncoder::new(CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap());
+		encoder.set_parallelism(2).unwrap();
+
+		let slot_count = encoder.get_slot_count();
+		let data: Vec<f64> = (0..slot_count * 3).map(|i| i as f64).collect();
+
+		let tensor = encoder.par_encode_f64(&data).unwrap();
+		let decoded = encoder.par_decode_f64(&tensor).unwrap();
+
+		for (expected, actual) in data.iter().zip(decoded.iter()) {
+			assert!((expected - actual).abs() < 1e-2);
+		}
+	}
+
+	#[test]
+	fn encode_f64_shaped_rejects_a_mismatched_shape() {
+		let ctx = mk_ctx();
+		let encoder = TensorEncoder::new(CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap());
+
+		let data: Vec<f64> = (0..6).map(|i| i as f64).collect();
+		let result = encoder.encode_f64_shaped(&data, vec![4, 4]);
+
+		assert!(matches!(result, Err(Error::InvalidArgument)));
+	}
+
+	#[test]
+	fn streams_a_tensor_through_encode_encrypt_decrypt_decode_without_materializing_it() {
+		let ctx = mk_ctx();
+		let encoder = TensorEncoder::new(CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap());
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = TensorEncryptor::with_public_and_secret_key(
+			&ctx,
+			&key_gen.create_public_key(),
+			&key_gen.secret_key(),
+		)
+		.unwrap();
+		let decryptor = TensorDecryptor::new(&ctx, &key_gen.secret_key()).unwrap();
+
+		let slot_count = encoder.get_slot_count();
+		let data: Vec<f64> = (0..slot_count * 3).map(|i| i as f64).collect();
+
+		let encoded = encoder.encode_f64_stream(&data);
+		let encrypted = encryptor.encrypt_stream(encoded);
+		let decrypted = decryptor.decrypt_stream(encrypted);
+		let decoded = encoder
+			.decode_f64_stream(decrypted)
+			.collect::<Result<Vec<_>>>()
+			.unwrap();
+
+		for (expected, actual) in data.iter().zip(decoded.into_iter().flatten()) {
+			assert!((expected - actual).abs() < 1e-2);
+		}
+	}
+
+	#[test]
+	fn bfv_tensor_encoder_round_trips_i64_across_multiple_plaintexts() {
+		let ctx = mk_bfv_ctx();
+		let encoder = TensorEncoder::new(BFVEncoder::new(&ctx).unwrap());
+
+		let slot_count = encoder.get_slot_count();
+		let data: Vec<i64> = (0..slot_count * 3).map(|i| i as i64).collect();
+
+		let tensor = encoder.encode_i64(&data).unwrap();
+		let decoded = encoder.decode_i64(&tensor).unwrap();
+
+		assert_eq!(data, decoded);
+	}
+
+	#[test]
+	fn encode_i64_shaped_round_trips_the_shape() {
+		let ctx = mk_bfv_ctx();
+		let encoder = TensorEncoder::new(BFVEncoder::new(&ctx).unwrap());
+
+		let data: Vec<i64> = (0..6).collect();
+		let tensor = encoder.encode_i64_shaped(&data, vec![2, 3]).unwrap();
+
+		assert_eq!(tensor.dims(), &[2, 3]);
+
+		let (decoded, shape) = encoder.decode_i64_shaped(&tensor).unwrap();
+
+		assert_eq!(shape, vec![2, 3]);
+		assert_eq!(data, decoded);
+	}
+
+	#[test]
+	fn encode_i64_shaped_rejects_a_mismatched_shape() {
+		let ctx = mk_bfv_ctx();
+		let encoder = TensorEncoder::new(BFVEncoder::new(&ctx).unwrap());
+
+		let data: Vec<i64> = (0..6).collect();
+		let result = encoder.encode_i64_shaped(&data, vec![4, 4]);
+
+		assert!(matches!(result, Err(Error::InvalidArgument)));
+	}
+
+	#[test]
+	fn tensor_encoder_slot_count_matches_the_inherent_method() {
+		let ctx = mk_bfv_ctx();
+		let encoder = TensorEncoder::new(BFVEncoder::new(&ctx).unwrap());
+
+		assert_eq!(SlotCount::get_slot_count(&encoder), encoder.get_slot_count());
+	}
+
+	#[test]
+	fn conversion_from_name_round_trips_through_name() {
+		for conversion in [
+			Conversion::Int,
+			Conversion::Float,
+			Conversion::Bool,
+			Conversion::Bytes,
+		] {
+			assert_eq!(Conversion::from_name(conversion.name()).unwrap(), conversion);
+		}
+
+		assert!(matches!(
+			Conversion::from_name("unknown"),
+			Err(Error::InvalidArgument)
+		));
+	}
+
+	#[test]
+	fn mixed_tensor_encoder_round_trips_int_and_bool_columns_under_bfv() {
+		let ctx = mk_bfv_ctx();
+		let encoder = MixedTensorEncoder::bfv(TensorEncoder::new(BFVEncoder::new(&ctx).unwrap()));
+
+		let ints = Column::Int(vec![1, 2, 3]);
+		let encoded_ints = encoder.encode(Conversion::Int, &ints).unwrap();
+		assert_eq!(encoder.decode(&encoded_ints).unwrap(), ints);
+
+		let bools = Column::Bool(vec![true, false, true]);
+		let encoded_bools = encoder.encode(Conversion::Bool, &bools).unwrap();
+		assert_eq!(encoder.decode(&encoded_bools).unwrap(), bools);
+
+		let bytes = Column::Bytes(vec![1, 2, 3, 4]);
+		let encoded_bytes = encoder.encode(Conversion::Bytes, &bytes).unwrap();
+		assert_eq!(encoder.decode(&encoded_bytes).unwrap(), bytes);
+	}
+
+	#[test]
+	fn mixed_tensor_encoder_round_trips_float_columns_under_ckks() {
+		let ctx = mk_ctx();
+		let encoder =
+			MixedTensorEncoder::ckks(TensorEncoder::new(CKKSEncoder::new(&ctx, 2.0f64.powi(40)).unwrap()));
+
+		let floats = Column::Float(vec![1.5, 2.5, 3.5]);
+		let encoded = encoder.encode(Conversion::Float, &floats).unwrap();
+
+		let Column::Float(decoded) = encoder.decode(&encoded).unwrap() else {
+			panic!("expected a float column");
+		};
+
+		match &floats {
+			Column::Float(expected) => {
+				for (expected, actual) in expected.iter().zip(decoded.iter()) {
+					assert!((expected - actual).abs() < 1e-4);
+				}
+			}
+			_ => unreachable!(),
+		}
+	}
+
+	#[test]
+	fn mixed_tensor_encoder_rejects_a_conversion_mismatched_with_its_scheme() {
+		let ctx = mk_bfv_ctx();
+		let encoder = MixedTensorEncoder::bfv(TensorEncoder::new(BFVEncoder::new(&ctx).unwrap()));
+
+		let floats = Column::Float(vec![1.0]);
+		let result = encoder.encode(Conversion::Float, &floats);
+
+		assert!(matches!(result, Err(Error::InvalidArgument)));
+	}
+}
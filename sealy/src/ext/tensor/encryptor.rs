@@ -1,8 +1,11 @@
 use crate::{
-	component_marker, Asym, AsymmetricComponents, Ciphertext, Context, Encryptor, Plaintext,
-	PublicKey, Result, SecretKey, Sym, SymAsym, SymmetricComponents,
+	component_marker, Asym, AsymmetricComponents, Ciphertext, Context, Encryptor, MemoryPool,
+	Plaintext, PublicKey, Result, SecretKey, Sym, SymAsym, SymmetricComponents,
 };
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use super::Tensor;
 
 /// Encryptor that can encrypt multiple messages at once.
@@ -81,6 +84,43 @@ impl<T: component_marker::Asym> TensorEncryptor<T> {
 			.collect()
 	}
 
+	/// Encrypts a plaintext with the public key and returns the ciphertext as a serializable
+	/// object, allocating scratch memory from `pool` instead of the global memory pool for
+	/// every chunk.
+	///
+	/// * `plaintext_tensor` - The plaintext to encrypt.
+	/// * `pool` - The memory pool to allocate scratch space from.
+	pub fn encrypt_with_pool(
+		&self,
+		plaintext_tensor: &Tensor<Plaintext>,
+		pool: &MemoryPool,
+	) -> Result<Tensor<Ciphertext>> {
+		plaintext_tensor
+			.map(|plaintext| self.encryptor.encrypt_with_pool(plaintext, pool))
+			.collect()
+	}
+
+	/// Lazily encrypts each plaintext yielded by `plaintexts`, one ciphertext at a time,
+	/// instead of collecting them all into a [`Tensor<Ciphertext>`] first. Pairs with
+	/// [`super::encoder::TensorEncoder::encode_f64_stream`] on the way in and
+	/// [`super::decryptor::TensorDecryptor::decrypt_stream`] on the way out, so a caller can
+	/// pipe encode→encrypt→network→decrypt→decode without ever holding the whole tensor's
+	/// ciphertexts in RAM.
+	///
+	/// * `plaintexts` - The plaintexts to encrypt, as produced by
+	///   [`super::encoder::TensorEncoder::encode_f64_stream`].
+	pub fn encrypt_stream<'a, I>(
+		&'a self,
+		plaintexts: I,
+	) -> impl Iterator<Item = Result<Ciphertext>> + 'a
+	where
+		I: IntoIterator<Item = Result<Plaintext>> + 'a,
+	{
+		plaintexts
+			.into_iter()
+			.map(move |plaintext| plaintext.and_then(|plaintext| self.encryptor.encrypt(&plaintext)))
+	}
+
 	/// Encrypts a plaintext with the public key and returns the ciphertext as a
 	/// serializable object. Also returns the u and e values used in encrypting
 	/// the value.
@@ -102,6 +142,30 @@ impl<T: component_marker::Asym> TensorEncryptor<T> {
 	}
 }
 
+#[cfg(feature = "parallel")]
+impl<T: component_marker::Asym> TensorEncryptor<T> {
+	/// Encrypts every plaintext in the tensor with the public key on a separate thread. Each
+	/// worker draws scratch space from its own [`MemoryPool::thread_local`] handle rather than
+	/// the global pool, so threads encrypting different chunks don't contend on the same
+	/// allocations.
+	///
+	/// * `plaintext_tensor` - The plaintext to encrypt.
+	pub fn par_encrypt(
+		&self,
+		plaintext_tensor: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		plaintext_tensor
+			.0
+			.par_iter()
+			.map(|plaintext| {
+				let pool = MemoryPool::thread_local()?;
+				self.encryptor.encrypt_with_pool(plaintext, &pool)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Tensor)
+	}
+}
+
 impl<T: component_marker::Sym> TensorEncryptor<T> {
 	/// Encrypts a plaintext with the secret key and returns the ciphertext as
 	/// a serializable object.
@@ -122,6 +186,40 @@ impl<T: component_marker::Sym> TensorEncryptor<T> {
 			.collect()
 	}
 
+	/// Encrypts a plaintext with the secret key and returns the ciphertext as a serializable
+	/// object, allocating scratch memory from `pool` instead of the global memory pool for
+	/// every chunk.
+	///
+	/// * `plaintext_tensor` - The plaintext to encrypt.
+	/// * `pool` - The memory pool to allocate scratch space from.
+	pub fn encrypt_symmetric_with_pool(
+		&self,
+		plaintext_tensor: &Tensor<Plaintext>,
+		pool: &MemoryPool,
+	) -> Result<Tensor<Ciphertext>> {
+		plaintext_tensor
+			.map(|plaintext| self.encryptor.encrypt_symmetric_with_pool(plaintext, pool))
+			.collect()
+	}
+
+	/// Lazily encrypts each plaintext yielded by `plaintexts` with the secret key, one
+	/// ciphertext at a time. See [`TensorEncryptor::encrypt_stream`] for the asymmetric
+	/// counterpart and the motivating streaming pipeline.
+	///
+	/// * `plaintexts` - The plaintexts to encrypt, as produced by
+	///   [`super::encoder::TensorEncoder::encode_f64_stream`].
+	pub fn encrypt_symmetric_stream<'a, I>(
+		&'a self,
+		plaintexts: I,
+	) -> impl Iterator<Item = Result<Ciphertext>> + 'a
+	where
+		I: IntoIterator<Item = Result<Plaintext>> + 'a,
+	{
+		plaintexts.into_iter().map(move |plaintext| {
+			plaintext.and_then(|plaintext| self.encryptor.encrypt_symmetric(&plaintext))
+		})
+	}
+
 	/// Encrypts a plaintext with the secret key and returns the ciphertext as a
 	/// serializable object. Also returns the e (noise) and r (remainder) values used in
 	/// encrypting the value.
@@ -144,3 +242,26 @@ impl<T: component_marker::Sym> TensorEncryptor<T> {
 			.collect()
 	}
 }
+
+#[cfg(feature = "parallel")]
+impl<T: component_marker::Sym> TensorEncryptor<T> {
+	/// Encrypts every plaintext in the tensor with the secret key on a separate thread. See
+	/// [`TensorEncryptor::par_encrypt`] for the asymmetric counterpart and the per-worker
+	/// memory pool rationale.
+	///
+	/// * `plaintext_tensor` - The plaintext to encrypt.
+	pub fn par_encrypt_symmetric(
+		&self,
+		plaintext_tensor: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		plaintext_tensor
+			.0
+			.par_iter()
+			.map(|plaintext| {
+				let pool = MemoryPool::thread_local()?;
+				self.encryptor.encrypt_symmetric_with_pool(plaintext, &pool)
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Tensor)
+	}
+}
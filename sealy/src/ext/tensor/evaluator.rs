@@ -0,0 +1,852 @@
+use super::Tensor;
+use crate::{CKKSEvaluator, Ciphertext, Evaluator, GaloisKey, Plaintext, RelinearizationKey, Result};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Combines `operands` with `op`, pairing up adjacent elements (`operands[0] <op> operands[1]`,
+/// `operands[2] <op> operands[3]`, ...) and carrying any odd trailing element up unchanged,
+/// until a single element remains. This gives a reduction of multiplicative/noise depth
+/// `ceil(log2(operands.len()))` rather than the `operands.len() - 1` depth of a left-fold.
+fn tree_reduce<F>(
+	mut operands: Vec<Ciphertext>,
+	mut op: F,
+) -> Result<Ciphertext>
+where
+	F: FnMut(&Ciphertext, &Ciphertext) -> Result<Ciphertext>,
+{
+	assert!(!operands.is_empty(), "tree_reduce requires at least one operand");
+
+	while operands.len() > 1 {
+		let mut next = Vec::with_capacity((operands.len() + 1) / 2);
+		let mut pairs = operands.into_iter();
+
+		while let Some(a) = pairs.next() {
+			next.push(match pairs.next() {
+				Some(b) => op(&a, &b)?,
+				None => a,
+			});
+		}
+
+		operands = next;
+	}
+
+	Ok(operands.into_iter().next().unwrap())
+}
+
+/// An evaluator that applies the operations of the wrapped [`Evaluator`] to every element of
+/// a [`Tensor`], elementwise.
+///
+/// With the `parallel` feature enabled, every method below dispatches across the tensor's
+/// elements using rayon instead of a sequential `for` loop, which pays off once the
+/// underlying homomorphic operations are expensive enough to outweigh the thread-pool
+/// overhead. By default this uses rayon's global thread pool; call
+/// [`TensorEvaluator::set_parallelism`] to confine it to a pool of a fixed size instead, e.g.
+/// to avoid starving other work sharing the process.
+pub struct TensorEvaluator<E> {
+	evaluator: E,
+	#[cfg(feature = "parallel")]
+	pool: Option<rayon::ThreadPool>,
+}
+
+impl<E> TensorEvaluator<E> {
+	/// Creates a new TensorEvaluator wrapping the given evaluator.
+	pub fn new(evaluator: E) -> Self {
+		Self {
+			evaluator,
+			#[cfg(feature = "parallel")]
+			pool: None,
+		}
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl<E> TensorEvaluator<E> {
+	/// Confines every parallel dispatch on this evaluator to a dedicated pool of
+	/// `num_threads` worker threads, instead of rayon's process-wide global pool. Pass `0` to
+	/// revert to the global pool.
+	pub fn set_parallelism(
+		&mut self,
+		num_threads: usize,
+	) -> Result<()> {
+		if num_threads == 0 {
+			self.pool = None;
+			return Ok(());
+		}
+
+		let pool = rayon::ThreadPoolBuilder::new()
+			.num_threads(num_threads)
+			.build()
+			.map_err(|_| crate::Error::Unexpected)?;
+
+		self.pool = Some(pool);
+
+		Ok(())
+	}
+
+	/// Runs `f` on this evaluator's dedicated pool if [`TensorEvaluator::set_parallelism`] was
+	/// called, otherwise on rayon's global pool.
+	fn dispatch<R: Send>(
+		&self,
+		f: impl FnOnce() -> R + Send,
+	) -> R {
+		match &self.pool {
+			Some(pool) => pool.install(f),
+			None => f(),
+		}
+	}
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<E> TensorEvaluator<E>
+where
+	E: Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext>,
+{
+	/// Negates every ciphertext in the tensor.
+	///  * `a` - the tensor to negate
+	pub fn negate(
+		&self,
+		a: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.negate(c)).collect()
+	}
+
+	/// Adds two tensors of ciphertexts elementwise.
+	///  * `a` - first operand
+	///  * `b` - second operand
+	pub fn add(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.add(a, b)).collect()
+	}
+
+	/// Performs an elementwise addition reduction of multiple tensors of ciphertexts, using a
+	/// balanced pairwise tree rather than a left-fold to limit rescale/mod-switch error
+	/// accumulation under CKKS.
+	///  * `a` - a slice of tensors to sum.
+	pub fn add_many(
+		&self,
+		a: &[Tensor<Ciphertext>],
+	) -> Result<Tensor<Ciphertext>> {
+		let len = a.first().map(|t| t.len()).unwrap_or(0);
+
+		(0..len)
+			.map(|i| {
+				let ciphertexts = a
+					.iter()
+					.map(|tensor| tensor.get(i).expect("tensor length mismatch"))
+					.cloned()
+					.collect::<Vec<_>>();
+
+				tree_reduce(ciphertexts, |a, b| self.evaluator.add(a, b))
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Tensor)
+	}
+
+	/// Subtracts `b` from `a` elementwise.
+	///  * `a` - the left operand
+	///  * `b` - the right operand
+	pub fn sub(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.sub(a, b)).collect()
+	}
+
+	/// Multiplies two tensors of ciphertexts elementwise.
+	///  * `a` - the left operand
+	///  * `b` - the right operand
+	pub fn multiply(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.multiply(a, b)).collect()
+	}
+
+	/// Performs an elementwise multiplication reduction of multiple tensors of ciphertexts,
+	/// using a balanced pairwise tree (relinearizing after each multiply) rather than a
+	/// left-fold, so the result has multiplicative depth `ceil(log2(k))` instead of `k - 1`
+	/// for `k` operands.
+	///  * `a` - a slice of tensors to multiply.
+	///  * `relin_keys` - the relinearization keys.
+	pub fn multiply_many(
+		&self,
+		a: &[Tensor<Ciphertext>],
+		relin_keys: &RelinearizationKey,
+	) -> Result<Tensor<Ciphertext>> {
+		let len = a.first().map(|t| t.len()).unwrap_or(0);
+
+		(0..len)
+			.map(|i| {
+				let ciphertexts = a
+					.iter()
+					.map(|tensor| tensor.get(i).expect("tensor length mismatch"))
+					.cloned()
+					.collect::<Vec<_>>();
+
+				tree_reduce(ciphertexts, |a, b| {
+					let product = self.evaluator.multiply(a, b)?;
+					self.evaluator.relinearize(&product, relin_keys)
+				})
+			})
+			.collect::<Result<Vec<_>>>()
+			.map(Tensor)
+	}
+
+	/// Squares every ciphertext in the tensor.
+	///  * `a` - the tensor to square
+	pub fn square(
+		&self,
+		a: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.square(c)).collect()
+	}
+
+	/// Adds a tensor of ciphertexts and a tensor of plaintexts elementwise.
+	///  * `a` - the ciphertext tensor
+	///  * `b` - the plaintext tensor
+	pub fn add_plain(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.add_plain(a, b)).collect()
+	}
+
+	/// Subtracts a tensor of plaintexts from a tensor of ciphertexts elementwise.
+	///  * `a` - the ciphertext tensor
+	///  * `b` - the plaintext tensor
+	pub fn sub_plain(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.sub_plain(a, b)).collect()
+	}
+
+	/// Multiplies a tensor of ciphertexts by a tensor of plaintexts elementwise.
+	///  * `a` - the ciphertext tensor
+	///  * `b` - the plaintext tensor
+	pub fn multiply_plain(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.zip(b, |a, b| self.evaluator.multiply_plain(a, b))
+			.collect()
+	}
+
+	/// Relinearizes every ciphertext in the tensor, reducing each to 2 polynomials.
+	///  * `a` - the tensor to relinearize
+	///  * `relin_keys` - the relinearization keys
+	pub fn relinearize(
+		&self,
+		a: &Tensor<Ciphertext>,
+		relin_keys: &RelinearizationKey,
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.relinearize(c, relin_keys)).collect()
+	}
+
+	/// Rotates the plaintext matrix rows of every ciphertext in the tensor cyclically.
+	///  * `a` - the tensor to rotate
+	///  * `steps` - the number of steps to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_rows(
+		&self,
+		a: &Tensor<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.rotate_rows(c, steps, galois_keys))
+			.collect()
+	}
+
+	/// Rotates the plaintext matrix columns of every ciphertext in the tensor cyclically.
+	///  * `a` - the tensor to rotate
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_columns(
+		&self,
+		a: &Tensor<Ciphertext>,
+		galois_keys: &GaloisKey,
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.rotate_columns(c, galois_keys))
+			.collect()
+	}
+
+	/// Rotates the CKKS-encoded slot vector of every ciphertext in the tensor cyclically. For a
+	/// [`TensorEvaluator`] wrapping a BFV evaluator, this returns
+	/// [`Error::UnsupportedOperation`](crate::Error::UnsupportedOperation) for every element,
+	/// since BFV has no flat-vector rotation distinct from [`TensorEvaluator::rotate_rows`]/
+	/// [`TensorEvaluator::rotate_columns`].
+	///  * `a` - the tensor to rotate
+	///  * `steps` - the number of slots to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_vector(
+		&self,
+		a: &Tensor<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.rotate_vector(c, steps, galois_keys))
+			.collect()
+	}
+
+	/// Collapses the batched slots of a single ciphertext into a running total, using the
+	/// standard rotate-and-add reduction: for `shift` in `1, 2, 4, ..., slot_count / 2`, the
+	/// ciphertext is rotated by `shift` and added to itself, so after `log2(slot_count)` steps
+	/// every slot holds the sum of all the original slots. A CKKS ciphertext is rotated as one
+	/// flat vector; a BFV ciphertext, whose slots form two rows of `slot_count / 2`, is rotated
+	/// within its rows, and its last step swaps the two rows instead.
+	///  * `a` - the ciphertext whose slots should be summed
+	///  * `galois_keys` - the Galois keys
+	///  * `slot_count` - the number of batched slots in `a`
+	pub fn sum_slots(
+		&self,
+		a: &Ciphertext,
+		galois_keys: &GaloisKey,
+		slot_count: usize,
+	) -> Result<Ciphertext> {
+		let mut sum = a.clone();
+		let mut shift = 1;
+
+		while shift < slot_count {
+			let rotated = match self.evaluator.rotate_vector(&sum, shift as i32, galois_keys) {
+				Err(crate::Error::UnsupportedOperation) if shift * 2 >= slot_count => {
+					self.evaluator.rotate_columns(&sum, galois_keys)?
+				}
+				Err(crate::Error::UnsupportedOperation) => {
+					self.evaluator.rotate_rows(&sum, shift as i32, galois_keys)?
+				}
+				other => other?,
+			};
+			sum = self.evaluator.add(&sum, &rotated)?;
+			shift *= 2;
+		}
+
+		Ok(sum)
+	}
+
+	/// Computes the encrypted inner product of a tensor of ciphertexts and a tensor of
+	/// plaintexts: an elementwise `multiply_plain` followed by a `sum_slots` reduction of each
+	/// resulting ciphertext, with the per-element totals then combined with a balanced-tree
+	/// `add`. The result is a single ciphertext whose every slot holds the dot product.
+	///  * `a` - the ciphertext tensor
+	///  * `b` - the plaintext tensor
+	///  * `galois_keys` - the Galois keys
+	///  * `slot_count` - the number of batched slots in each ciphertext
+	pub fn inner_product(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Plaintext>,
+		galois_keys: &GaloisKey,
+		slot_count: usize,
+	) -> Result<Ciphertext> {
+		let products = a
+			.zip(b, |a, b| self.evaluator.multiply_plain(a, b))
+			.collect::<Result<Tensor<Ciphertext>>>()?
+			.map(|c| self.sum_slots(c, galois_keys, slot_count))
+			.collect::<Result<Tensor<Ciphertext>>>()?;
+
+		tree_reduce(products.0, |a, b| self.evaluator.add(a, b))
+	}
+}
+
+#[cfg(not(feature = "parallel"))]
+impl TensorEvaluator<CKKSEvaluator> {
+	/// Rescales every ciphertext in the tensor down to the next coefficient modulus in the
+	/// chain, restoring its scale to roughly what it was before the multiply that grew it. See
+	/// [`CKKSEvaluator::rescale_to_next`].
+	///  * `a` - the tensor to rescale
+	pub fn rescale_to_next(
+		&self,
+		a: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.rescale_to_next(c)).collect()
+	}
+
+	/// Switches every ciphertext in the tensor down to the next coefficient modulus in the
+	/// chain, without rescaling.
+	///  * `a` - the tensor to switch down
+	pub fn mod_switch_to_next(
+		&self,
+		a: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.mod_switch_to_next(c)).collect()
+	}
+
+	/// Switches every ciphertext in the tensor down to the coefficient modulus identified by
+	/// `parms_id`, without rescaling.
+	///  * `a` - the tensor to switch down
+	///  * `parms_id` - the parms ID of the destination coefficient modulus
+	pub fn mod_switch_to(
+		&self,
+		a: &Tensor<Ciphertext>,
+		parms_id: &[u64],
+	) -> Result<Tensor<Ciphertext>> {
+		a.map(|c| self.evaluator.mod_switch_to(c, parms_id)).collect()
+	}
+
+	/// Rotates the CKKS-encoded slot vector of every ciphertext in the tensor cyclically by the
+	/// same number of steps. An alias for [`TensorEvaluator::rotate_vector`] kept under this
+	/// name for parity with [`TensorEvaluator::rotate_many`].
+	///  * `a` - the tensor to rotate
+	///  * `steps` - the number of slots to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate(
+		&self,
+		a: &Tensor<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Tensor<Ciphertext>> {
+		self.rotate_vector(a, steps, galois_keys)
+	}
+
+	/// Rotates the tensor by every step count in `steps`, returning one rotated tensor per step.
+	/// Useful for building the sliding-window views a convolution needs without re-deriving each
+	/// rotation by hand.
+	///  * `a` - the tensor to rotate
+	///  * `steps` - the step counts to rotate by
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_many(
+		&self,
+		a: &Tensor<Ciphertext>,
+		steps: &[i32],
+		galois_keys: &GaloisKey,
+	) -> Result<Vec<Tensor<Ciphertext>>> {
+		steps
+			.iter()
+			.map(|&step| self.rotate(a, step, galois_keys))
+			.collect()
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl<E> TensorEvaluator<E>
+where
+	E: Evaluator<Plaintext = Plaintext, Ciphertext = Ciphertext> + Sync,
+{
+	/// Negates every ciphertext in the tensor.
+	///  * `a` - the tensor to negate
+	pub fn negate(
+		&self,
+		a: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.negate(c))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Adds two tensors of ciphertexts elementwise.
+	///  * `a` - first operand
+	///  * `b` - second operand
+	pub fn add(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.zip(b.0.par_iter())
+				.map(|(a, b)| self.evaluator.add(a, b))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Performs an elementwise addition reduction of multiple tensors of ciphertexts.
+	///  * `a` - a slice of tensors to sum.
+	pub fn add_many(
+		&self,
+		a: &[Tensor<Ciphertext>],
+	) -> Result<Tensor<Ciphertext>> {
+		let len = a.first().map(|t| t.len()).unwrap_or(0);
+
+		self.dispatch(|| {
+			(0..len)
+				.into_par_iter()
+				.map(|i| {
+					let ciphertexts = a
+						.iter()
+						.map(|tensor| tensor.get(i).expect("tensor length mismatch"))
+						.cloned()
+						.collect::<Vec<_>>();
+
+					tree_reduce(ciphertexts, |a, b| self.evaluator.add(a, b))
+				})
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Subtracts `b` from `a` elementwise.
+	///  * `a` - the left operand
+	///  * `b` - the right operand
+	pub fn sub(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.zip(b.0.par_iter())
+				.map(|(a, b)| self.evaluator.sub(a, b))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Multiplies two tensors of ciphertexts elementwise.
+	///  * `a` - the left operand
+	///  * `b` - the right operand
+	pub fn multiply(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.zip(b.0.par_iter())
+				.map(|(a, b)| self.evaluator.multiply(a, b))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Performs an elementwise multiplication reduction of multiple tensors of ciphertexts,
+	/// using a balanced pairwise tree (relinearizing after each multiply) rather than a
+	/// left-fold, so the result has multiplicative depth `ceil(log2(k))` instead of `k - 1`
+	/// for `k` operands.
+	///  * `a` - a slice of tensors to multiply.
+	///  * `relin_keys` - the relinearization keys.
+	pub fn multiply_many(
+		&self,
+		a: &[Tensor<Ciphertext>],
+		relin_keys: &RelinearizationKey,
+	) -> Result<Tensor<Ciphertext>> {
+		let len = a.first().map(|t| t.len()).unwrap_or(0);
+
+		self.dispatch(|| {
+			(0..len)
+				.into_par_iter()
+				.map(|i| {
+					let ciphertexts = a
+						.iter()
+						.map(|tensor| tensor.get(i).expect("tensor length mismatch"))
+						.cloned()
+						.collect::<Vec<_>>();
+
+					tree_reduce(ciphertexts, |a, b| {
+						let product = self.evaluator.multiply(a, b)?;
+						self.evaluator.relinearize(&product, relin_keys)
+					})
+				})
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Squares every ciphertext in the tensor.
+	///  * `a` - the tensor to square
+	pub fn square(
+		&self,
+		a: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.square(c))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Adds a tensor of ciphertexts and a tensor of plaintexts elementwise.
+	///  * `a` - the ciphertext tensor
+	///  * `b` - the plaintext tensor
+	pub fn add_plain(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.zip(b.0.par_iter())
+				.map(|(a, b)| self.evaluator.add_plain(a, b))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Subtracts a tensor of plaintexts from a tensor of ciphertexts elementwise.
+	///  * `a` - the ciphertext tensor
+	///  * `b` - the plaintext tensor
+	pub fn sub_plain(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.zip(b.0.par_iter())
+				.map(|(a, b)| self.evaluator.sub_plain(a, b))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Multiplies a tensor of ciphertexts by a tensor of plaintexts elementwise.
+	///  * `a` - the ciphertext tensor
+	///  * `b` - the plaintext tensor
+	pub fn multiply_plain(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Plaintext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.zip(b.0.par_iter())
+				.map(|(a, b)| self.evaluator.multiply_plain(a, b))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Relinearizes every ciphertext in the tensor, reducing each to 2 polynomials.
+	///  * `a` - the tensor to relinearize
+	///  * `relin_keys` - the relinearization keys
+	pub fn relinearize(
+		&self,
+		a: &Tensor<Ciphertext>,
+		relin_keys: &RelinearizationKey,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.relinearize(c, relin_keys))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Rotates the plaintext matrix rows of every ciphertext in the tensor cyclically.
+	///  * `a` - the tensor to rotate
+	///  * `steps` - the number of steps to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_rows(
+		&self,
+		a: &Tensor<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.rotate_rows(c, steps, galois_keys))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Rotates the plaintext matrix columns of every ciphertext in the tensor cyclically.
+	///  * `a` - the tensor to rotate
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_columns(
+		&self,
+		a: &Tensor<Ciphertext>,
+		galois_keys: &GaloisKey,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.rotate_columns(c, galois_keys))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Rotates the CKKS-encoded slot vector of every ciphertext in the tensor cyclically.
+	///  * `a` - the tensor to rotate
+	///  * `steps` - the number of slots to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_vector(
+		&self,
+		a: &Tensor<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.rotate_vector(c, steps, galois_keys))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Collapses the batched slots of a single ciphertext into a running total, using the
+	/// standard rotate-and-add reduction: for `shift` in `1, 2, 4, ..., slot_count / 2`, the
+	/// ciphertext is rotated by `shift` and added to itself, so after `log2(slot_count)` steps
+	/// every slot holds the sum of all the original slots. A CKKS ciphertext is rotated as one
+	/// flat vector; a BFV ciphertext, whose slots form two rows of `slot_count / 2`, is rotated
+	/// within its rows, and its last step swaps the two rows instead.
+	///  * `a` - the ciphertext whose slots should be summed
+	///  * `galois_keys` - the Galois keys
+	///  * `slot_count` - the number of batched slots in `a`
+	pub fn sum_slots(
+		&self,
+		a: &Ciphertext,
+		galois_keys: &GaloisKey,
+		slot_count: usize,
+	) -> Result<Ciphertext> {
+		let mut sum = a.clone();
+		let mut shift = 1;
+
+		while shift < slot_count {
+			let rotated = match self.evaluator.rotate_vector(&sum, shift as i32, galois_keys) {
+				Err(crate::Error::UnsupportedOperation) if shift * 2 >= slot_count => {
+					self.evaluator.rotate_columns(&sum, galois_keys)?
+				}
+				Err(crate::Error::UnsupportedOperation) => {
+					self.evaluator.rotate_rows(&sum, shift as i32, galois_keys)?
+				}
+				other => other?,
+			};
+			sum = self.evaluator.add(&sum, &rotated)?;
+			shift *= 2;
+		}
+
+		Ok(sum)
+	}
+
+	/// Computes the encrypted inner product of a tensor of ciphertexts and a tensor of
+	/// plaintexts: an elementwise `multiply_plain` followed by a `sum_slots` reduction of each
+	/// resulting ciphertext (both run across the tensor in parallel), with the per-element
+	/// totals then combined with a balanced-tree `add`. The result is a single ciphertext whose
+	/// every slot holds the dot product.
+	///  * `a` - the ciphertext tensor
+	///  * `b` - the plaintext tensor
+	///  * `galois_keys` - the Galois keys
+	///  * `slot_count` - the number of batched slots in each ciphertext
+	pub fn inner_product(
+		&self,
+		a: &Tensor<Ciphertext>,
+		b: &Tensor<Plaintext>,
+		galois_keys: &GaloisKey,
+		slot_count: usize,
+	) -> Result<Ciphertext> {
+		let products = self.dispatch(|| {
+			a.0
+				.par_iter()
+				.zip(b.0.par_iter())
+				.map(|(a, b)| {
+					let product = self.evaluator.multiply_plain(a, b)?;
+					self.sum_slots(&product, galois_keys, slot_count)
+				})
+				.collect::<Result<Vec<_>>>()
+		})?;
+
+		tree_reduce(products, |a, b| self.evaluator.add(a, b))
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl TensorEvaluator<CKKSEvaluator> {
+	/// Rescales every ciphertext in the tensor down to the next coefficient modulus in the
+	/// chain, restoring its scale to roughly what it was before the multiply that grew it. See
+	/// [`CKKSEvaluator::rescale_to_next`].
+	///  * `a` - the tensor to rescale
+	pub fn rescale_to_next(
+		&self,
+		a: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.rescale_to_next(c))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Switches every ciphertext in the tensor down to the next coefficient modulus in the
+	/// chain, without rescaling.
+	///  * `a` - the tensor to switch down
+	pub fn mod_switch_to_next(
+		&self,
+		a: &Tensor<Ciphertext>,
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.mod_switch_to_next(c))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Switches every ciphertext in the tensor down to the coefficient modulus identified by
+	/// `parms_id`, without rescaling.
+	///  * `a` - the tensor to switch down
+	///  * `parms_id` - the parms ID of the destination coefficient modulus
+	pub fn mod_switch_to(
+		&self,
+		a: &Tensor<Ciphertext>,
+		parms_id: &[u64],
+	) -> Result<Tensor<Ciphertext>> {
+		self.dispatch(|| {
+			a.0
+				.par_iter()
+				.map(|c| self.evaluator.mod_switch_to(c, parms_id))
+				.collect::<Result<Vec<_>>>()
+				.map(Tensor)
+		})
+	}
+
+	/// Rotates the CKKS-encoded slot vector of every ciphertext in the tensor cyclically by the
+	/// same number of steps. An alias for [`TensorEvaluator::rotate_vector`] kept under this
+	/// name for parity with [`TensorEvaluator::rotate_many`].
+	///  * `a` - the tensor to rotate
+	///  * `steps` - the number of slots to rotate (positive left, negative right)
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate(
+		&self,
+		a: &Tensor<Ciphertext>,
+		steps: i32,
+		galois_keys: &GaloisKey,
+	) -> Result<Tensor<Ciphertext>> {
+		self.rotate_vector(a, steps, galois_keys)
+	}
+
+	/// Rotates the tensor by every step count in `steps`, returning one rotated tensor per step.
+	/// Each rotation still dispatches its own elementwise work across the tensor in parallel;
+	/// the steps themselves are applied sequentially.
+	///  * `a` - the tensor to rotate
+	///  * `steps` - the step counts to rotate by
+	///  * `galois_keys` - the Galois keys
+	pub fn rotate_many(
+		&self,
+		a: &Tensor<Ciphertext>,
+		steps: &[i32],
+		galois_keys: &GaloisKey,
+	) -> Result<Vec<Tensor<Ciphertext>>> {
+		steps
+			.iter()
+			.map(|&step| self.rotate(a, step, galois_keys))
+			.collect()
+	}
+}
@@ -0,0 +1,553 @@
+use std::io::{Read, Write};
+
+use crate::{Context, EncryptedChunk, EncryptionAlgorithm, Error, FromBytes, Result, ToBytes};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub mod decryptor;
+pub mod encoder;
+pub mod encryptor;
+pub mod evaluator;
+
+/// Struct to store a tensor of elements of the same type. A `Tensor` is functionally
+/// equivalent to a [`crate::ext::batched::Batch`], but is used by the extension
+/// modules under `ext::tensor` that operate on it.
+#[derive(Debug, Clone)]
+pub struct Tensor<T>(pub Vec<T>);
+
+/// A trait for converting a tensor of objects into a list of byte arrays.
+pub trait ToChunk {
+	/// Returns the object as a list of byte arrays, one per element of the tensor.
+	fn to_chunk(&self) -> Result<Vec<Vec<u8>>>;
+
+	/// Returns the object as a list of byte arrays, one per element of the tensor, each
+	/// compressed with the given `compression` codec.
+	///
+	/// The default implementation ignores `compression` and falls back to [`ToChunk::to_chunk`].
+	fn to_chunk_with_compression(
+		&self,
+		compression: crate::CompressionType,
+	) -> Result<Vec<Vec<u8>>> {
+		let _ = compression;
+		self.to_chunk()
+	}
+}
+
+/// A trait for converting data from a list of byte arrays under a given SEAL context.
+pub trait FromChunk {
+	/// Deserialize an object from the given list of byte arrays using the given
+	/// context.
+	fn from_chunk(context: &Context, chunks: &[Vec<u8>]) -> Result<Self>
+	where
+		Self: Sized;
+}
+
+impl<T> IntoIterator for Tensor<T> {
+	type Item = T;
+	type IntoIter = std::vec::IntoIter<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a Tensor<T> {
+	type Item = &'a T;
+	type IntoIter = std::slice::Iter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<T> Tensor<T> {
+	/// Returns the first element in this tensor.
+	pub fn first(&self) -> Option<&T> {
+		self.get(0)
+	}
+
+	/// Returns the element given by the index.
+	pub fn get(&self, index: usize) -> Option<&T> {
+		self.0.get(index)
+	}
+
+	/// Returns the number of elements in this tensor.
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Returns true if this tensor contains no elements.
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Returns an iterator over the elements of this tensor.
+	pub fn iter(&self) -> std::slice::Iter<T> {
+		self.0.iter()
+	}
+
+	/// Returns a mutable iterator over the elements of this tensor.
+	pub fn iter_mut(&mut self) -> std::slice::IterMut<T> {
+		self.0.iter_mut()
+	}
+
+	/// Applies the given function to each element in this tensor, returning a new tensor with the results.
+	pub fn map<U, F>(&self, f: F) -> Tensor<U>
+	where
+		F: FnMut(&T) -> U,
+	{
+		Tensor(self.0.iter().map(f).collect())
+	}
+
+	/// zips two tensors together, applying the given function to each pair of elements.
+	pub fn zip<U, V, F>(&self, other: &Tensor<U>, mut f: F) -> Tensor<V>
+	where
+		F: FnMut(&T, &U) -> V,
+	{
+		Tensor(
+			self.0
+				.iter()
+				.zip(other.0.iter())
+				.map(|(a, b)| f(a, b))
+				.collect(),
+		)
+	}
+}
+
+#[cfg(feature = "parallel")]
+impl<T> Tensor<T>
+where
+	T: Sync,
+{
+	/// Applies the given function to each element in this tensor on a separate thread,
+	/// returning a new tensor with the results.
+	pub fn par_map<U, F>(&self, f: F) -> Tensor<U>
+	where
+		F: Fn(&T) -> U + Sync + Send,
+		U: Send,
+	{
+		Tensor(self.0.par_iter().map(f).collect())
+	}
+
+	/// zips two tensors together, applying the given function to each pair of elements on a
+	/// separate thread.
+	pub fn par_zip<U, V, F>(&self, other: &Tensor<U>, f: F) -> Tensor<V>
+	where
+		U: Sync,
+		F: Fn(&T, &U) -> V + Sync + Send,
+		V: Send,
+	{
+		Tensor(
+			self.0
+				.par_iter()
+				.zip(other.0.par_iter())
+				.map(|(a, b)| f(a, b))
+				.collect(),
+		)
+	}
+}
+
+impl<T> FromChunk for Tensor<T>
+where
+	T: FromBytes,
+{
+	fn from_chunk(
+		context: &Context,
+		chunks: &[Vec<u8>],
+	) -> Result<Self> {
+		let values = chunks
+			.iter()
+			.map(|bytes| T::from_bytes(context, bytes))
+			.collect::<Result<Vec<_>>>()?;
+		Ok(Tensor(values))
+	}
+}
+
+impl<T> ToChunk for Tensor<T>
+where
+	T: ToBytes,
+{
+	fn to_chunk(&self) -> Result<Vec<Vec<u8>>> {
+		self.0.iter().map(|value| value.as_bytes()).collect()
+	}
+
+	fn to_chunk_with_compression(
+		&self,
+		compression: crate::CompressionType,
+	) -> Result<Vec<Vec<u8>>> {
+		self.0
+			.iter()
+			.map(|value| value.to_bytes_with_compression(compression))
+			.collect()
+	}
+}
+
+/// A [`Tensor`] paired with the logical multi-dimensional shape its elements were drawn from,
+/// e.g. `[batch, features]` for a matrix whose rows were flattened into plaintexts by
+/// [`crate::ext::tensor::encoder::TensorEncoder::encode_f64_shaped`]. Plain [`Tensor`] has no
+/// notion of dimensionality beyond its flat element count, so decoding a batched matrix back
+/// into its original shape otherwise requires the caller to track the shape out of band.
+#[derive(Debug, Clone)]
+pub struct ShapedTensor<T> {
+	tensor: Tensor<T>,
+	shape: Vec<usize>,
+}
+
+impl<T> ShapedTensor<T> {
+	/// Wraps `tensor` with `shape`, validating that `shape.iter().product()` equals the
+	/// number of elements in `tensor`.
+	pub fn new(
+		tensor: Tensor<T>,
+		shape: Vec<usize>,
+	) -> Result<Self> {
+		let expected: usize = shape.iter().product();
+
+		if expected != tensor.len() {
+			return Err(Error::InvalidArgument);
+		}
+
+		Ok(Self::from_parts_unchecked(tensor, shape))
+	}
+
+	/// Wraps `tensor` with `shape` without validating that the element count matches, for
+	/// callers (such as [`crate::ext::tensor::encoder::TensorEncoder::encode_f64_shaped`]) where
+	/// `shape` describes the logical dimensions of the data an element was encoded from rather
+	/// than a one-to-one mapping between shape cells and tensor elements.
+	pub(crate) fn from_parts_unchecked(
+		tensor: Tensor<T>,
+		shape: Vec<usize>,
+	) -> Self {
+		Self {
+			tensor,
+			shape,
+		}
+	}
+
+	/// Returns the logical shape of this tensor, e.g. `[batch, features]`.
+	pub fn dims(&self) -> &[usize] {
+		&self.shape
+	}
+
+	/// Returns a reference to the underlying flat [`Tensor`].
+	pub fn tensor(&self) -> &Tensor<T> {
+		&self.tensor
+	}
+
+	/// Discards the shape, returning the underlying flat [`Tensor`].
+	pub fn flatten(self) -> Tensor<T> {
+		self.tensor
+	}
+
+	/// Reinterprets this tensor under `shape`, validating that the total element count is
+	/// unchanged.
+	pub fn reshape(
+		self,
+		shape: Vec<usize>,
+	) -> Result<Self> {
+		Self::new(self.tensor, shape)
+	}
+
+	/// Applies `f` to each element, preserving this tensor's shape.
+	pub fn map<U, F>(&self, f: F) -> ShapedTensor<U>
+	where
+		F: FnMut(&T) -> U,
+	{
+		ShapedTensor::from_parts_unchecked(self.tensor.map(f), self.shape.clone())
+	}
+
+	/// Zips two shaped tensors together elementwise, applying `f` to each pair.
+	///
+	/// # Panics
+	/// Panics if `self` and `other` don't have the same shape.
+	pub fn zip<U, V, F>(
+		&self,
+		other: &ShapedTensor<U>,
+		f: F,
+	) -> ShapedTensor<V>
+	where
+		F: FnMut(&T, &U) -> V,
+	{
+		assert_eq!(
+			self.shape, other.shape,
+			"shape mismatch in ShapedTensor::zip"
+		);
+
+		ShapedTensor::from_parts_unchecked(self.tensor.zip(&other.tensor, f), self.shape.clone())
+	}
+}
+
+impl<T> ToChunk for ShapedTensor<T>
+where
+	Tensor<T>: ToChunk,
+{
+	fn to_chunk(&self) -> Result<Vec<Vec<u8>>> {
+		let mut shape_bytes = Vec::with_capacity(8 + self.shape.len() * 8);
+		shape_bytes.extend_from_slice(&(self.shape.len() as u64).to_le_bytes());
+
+		for dim in &self.shape {
+			shape_bytes.extend_from_slice(&(*dim as u64).to_le_bytes());
+		}
+
+		let mut chunks = vec![shape_bytes];
+		chunks.extend(self.tensor.to_chunk()?);
+
+		Ok(chunks)
+	}
+}
+
+impl<T> FromChunk for ShapedTensor<T>
+where
+	Tensor<T>: FromChunk,
+{
+	fn from_chunk(
+		context: &Context,
+		chunks: &[Vec<u8>],
+	) -> Result<Self> {
+		let (shape_bytes, rest) = chunks.split_first().ok_or(Error::InvalidSerializedData)?;
+
+		if shape_bytes.len() < 8 {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let rank = u64::from_le_bytes(shape_bytes[0..8].try_into().unwrap()) as usize;
+
+		if shape_bytes.len() != 8 + rank * 8 {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let shape = (0..rank)
+			.map(|i| {
+				let offset = 8 + i * 8;
+				u64::from_le_bytes(shape_bytes[offset..offset + 8].try_into().unwrap()) as usize
+			})
+			.collect();
+
+		let tensor = Tensor::from_chunk(context, rest)?;
+
+		Ok(ShapedTensor::from_parts_unchecked(tensor, shape))
+	}
+}
+
+/// Magic bytes identifying a stream produced by [`Tensor::write_to`].
+const STREAM_MAGIC: [u8; 4] = *b"SLYT";
+
+/// The current stream format. Bump this if the header layout ever changes.
+const STREAM_VERSION: u8 = 1;
+
+impl<T> Tensor<T>
+where
+	T: ToBytes,
+{
+	/// Writes this tensor to `w` as a single self-delimiting stream: a magic/version header,
+	/// a `u64` element count, then for each element a `u64` little-endian length prefix
+	/// followed by its [`ToBytes::as_bytes`] encoding. Unlike [`ToChunk::to_chunk`], which
+	/// hands back a `Vec<Vec<u8>>` the caller must frame themselves, this writes directly to
+	/// any [`Write`], such as a file or socket.
+	pub fn write_to<W: Write>(
+		&self,
+		mut w: W,
+	) -> Result<()> {
+		w.write_all(&STREAM_MAGIC).map_err(|_| Error::IoError)?;
+		w.write_all(&[STREAM_VERSION]).map_err(|_| Error::IoError)?;
+		w.write_all(&(self.len() as u64).to_le_bytes())
+			.map_err(|_| Error::IoError)?;
+
+		for value in &self.0 {
+			let bytes = value.as_bytes()?;
+			w.write_all(&(bytes.len() as u64).to_le_bytes())
+				.map_err(|_| Error::IoError)?;
+			w.write_all(&bytes).map_err(|_| Error::IoError)?;
+		}
+
+		Ok(())
+	}
+}
+
+impl<T> Tensor<T>
+where
+	T: FromBytes<State = Context>,
+{
+	/// Reads a tensor previously written by [`Tensor::write_to`] from `r`, reconstructing each
+	/// element through [`FromBytes::from_bytes`] one frame at a time rather than buffering the
+	/// whole stream in memory first.
+	///
+	/// Returns [`Error::InvalidSerializedData`] if the header is malformed or the stream ends
+	/// before the declared element count has been read, so a truncated stream surfaces as an
+	/// error rather than a silently short tensor.
+	pub fn read_from<R: Read>(
+		context: &Context,
+		mut r: R,
+	) -> Result<Self> {
+		let mut header = [0u8; 4 + 1 + 8];
+		r.read_exact(&mut header).map_err(|_| Error::InvalidSerializedData)?;
+
+		if header[0..4] != STREAM_MAGIC {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		if header[4] != STREAM_VERSION {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let count = u64::from_le_bytes(header[5..13].try_into().unwrap());
+
+		let mut values = Vec::new();
+
+		for _ in 0..count {
+			let mut len_bytes = [0u8; 8];
+			r.read_exact(&mut len_bytes).map_err(|_| Error::InvalidSerializedData)?;
+			let len = u64::from_le_bytes(len_bytes) as usize;
+
+			let mut bytes = vec![0u8; len];
+			r.read_exact(&mut bytes).map_err(|_| Error::InvalidSerializedData)?;
+
+			values.push(T::from_bytes(context, &bytes)?);
+		}
+
+		Ok(Tensor(values))
+	}
+}
+
+impl<T> Tensor<T>
+where
+	Self: ToChunk,
+{
+	/// Serializes this tensor into chunks with [`ToChunk::to_chunk`], then seals them into a
+	/// single password-protected blob with [`EncryptedChunk::seal`].
+	pub fn to_chunk_encrypted(
+		&self,
+		password: &str,
+		algorithm: EncryptionAlgorithm,
+	) -> Result<Vec<u8>> {
+		EncryptedChunk::seal(&self.to_chunk()?, password, algorithm)
+	}
+}
+
+impl<T> Tensor<T>
+where
+	Self: FromChunk,
+{
+	/// Opens a blob produced by [`Tensor::to_chunk_encrypted`] and reconstructs the tensor
+	/// with [`FromChunk::from_chunk`].
+	pub fn from_chunk_encrypted(
+		context: &Context,
+		password: &str,
+		data: &[u8],
+	) -> Result<Self> {
+		let chunks = EncryptedChunk::open(data, password)?;
+		Self::from_chunk(context, &chunks)
+	}
+}
+
+impl<T> Tensor<T>
+where
+	T: Clone,
+{
+	/// Returns a cloned copy of the element given by the index.
+	pub fn get_cloned(&self, index: usize) -> Option<T> {
+		self.get(index).cloned()
+	}
+}
+
+impl<T, E> Tensor<std::result::Result<T, E>> {
+	/// Collects the results in this tensor, returning the successful values.
+	pub fn collect(self) -> std::result::Result<Tensor<T>, E> {
+		let values = self
+			.0
+			.into_iter()
+			.collect::<std::result::Result<Vec<_>, _>>()?;
+		Ok(Tensor(values))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BFVEncoder, Plaintext};
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	#[test]
+	fn round_trips_a_tensor_through_a_stream() {
+		let ctx = mk_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let tensor = Tensor(vec![
+			encoder.encode_i64(&[1, 2, 3]).unwrap(),
+			encoder.encode_i64(&[4, 5, 6]).unwrap(),
+		]);
+
+		let mut stream = Vec::new();
+		tensor.write_to(&mut stream).unwrap();
+
+		let loaded = Tensor::<Plaintext>::read_from(&ctx, stream.as_slice()).unwrap();
+
+		assert_eq!(tensor.0, loaded.0);
+	}
+
+	#[test]
+	fn rejects_a_stream_with_the_wrong_magic() {
+		let ctx = mk_ctx();
+
+		let result = Tensor::<Plaintext>::read_from(&ctx, b"not a tensor stream".as_slice());
+
+		assert!(matches!(result, Err(Error::InvalidSerializedData)));
+	}
+
+	#[test]
+	fn rejects_a_truncated_stream() {
+		let ctx = mk_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let tensor = Tensor(vec![encoder.encode_i64(&[1, 2, 3]).unwrap()]);
+
+		let mut stream = Vec::new();
+		tensor.write_to(&mut stream).unwrap();
+		stream.truncate(stream.len() - 4);
+
+		let result = Tensor::<Plaintext>::read_from(&ctx, stream.as_slice());
+
+		assert!(matches!(result, Err(Error::InvalidSerializedData)));
+	}
+
+	#[test]
+	fn shaped_tensor_rejects_a_shape_with_the_wrong_element_count() {
+		let tensor = Tensor(vec![1, 2, 3, 4]);
+
+		let result = ShapedTensor::new(tensor, vec![2, 3]);
+
+		assert!(matches!(result, Err(Error::InvalidArgument)));
+	}
+
+	#[test]
+	fn shaped_tensor_round_trips_through_chunks() {
+		let ctx = mk_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let tensor = Tensor(vec![
+			encoder.encode_i64(&[1, 2, 3]).unwrap(),
+			encoder.encode_i64(&[4, 5, 6]).unwrap(),
+			encoder.encode_i64(&[7, 8, 9]).unwrap(),
+			encoder.encode_i64(&[10, 11, 12]).unwrap(),
+		]);
+
+		let shaped = ShapedTensor::new(tensor, vec![2, 2]).unwrap();
+		let chunks = shaped.to_chunk().unwrap();
+		let loaded = ShapedTensor::<Plaintext>::from_chunk(&ctx, &chunks).unwrap();
+
+		assert_eq!(loaded.dims(), &[2, 2]);
+		assert_eq!(shaped.tensor().0, loaded.tensor().0);
+	}
+
+	#[test]
+	#[should_panic(expected = "shape mismatch")]
+	fn shaped_tensor_zip_panics_on_mismatched_shapes() {
+		let a = ShapedTensor::new(Tensor(vec![1, 2]), vec![2]).unwrap();
+		let b = ShapedTensor::new(Tensor(vec![1, 2, 3]), vec![3]).unwrap();
+
+		a.zip(&b, |x, y| x + y);
+	}
+}
@@ -0,0 +1,610 @@
+use std::ffi::c_void;
+use std::fmt::Debug;
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use serde::ser::Error as SerdeError;
+use serde::{Serialize, Serializer};
+
+use crate::{bindgen, serialization::CompressionType, Context, FromBytes, ToBytes};
+use crate::{error::*, try_seal};
+use crate::{Protected, SecretToBytes};
+
+macro_rules! sealed_key_type {
+	($name:ident, $create_fn:ident, $copy_fn:ident, $destroy_fn:ident, $save_size_fn:ident, $save_fn:ident, $load_fn:ident, $doc:expr) => {
+		#[doc = $doc]
+		pub struct $name {
+			handle: AtomicPtr<c_void>,
+		}
+
+		unsafe impl Sync for $name {}
+		unsafe impl Send for $name {}
+
+		impl $name {
+			/// Constructs an empty instance, ready to be populated by a `KeyGenerator` or
+			/// deserialized from bytes.
+			pub fn new() -> Result<Self> {
+				let mut handle: *mut c_void = null_mut();
+
+				try_seal!(unsafe { bindgen::$create_fn(&mut handle) })?;
+
+				Ok(Self {
+					handle: AtomicPtr::new(handle),
+				})
+			}
+
+			/// Returns the handle to the underlying SEAL object.
+			pub(crate) unsafe fn get_handle(&self) -> *mut c_void {
+				self.handle.load(Ordering::SeqCst)
+			}
+
+			pub(crate) unsafe fn from_handle(handle: *mut c_void) -> Self {
+				Self {
+					handle: AtomicPtr::new(handle),
+				}
+			}
+		}
+
+		impl Debug for $name {
+			fn fmt(
+				&self,
+				f: &mut std::fmt::Formatter<'_>,
+			) -> std::fmt::Result {
+				f.debug_struct(stringify!($name))
+					.field("handle", &self.handle)
+					.finish()
+			}
+		}
+
+		impl Clone for $name {
+			fn clone(&self) -> Self {
+				let mut copy = null_mut();
+
+				try_seal!(unsafe { bindgen::$copy_fn(self.get_handle(), &mut copy) })
+					.expect(concat!("Internal error: Failed to copy ", stringify!($name), "."));
+
+				Self {
+					handle: AtomicPtr::new(copy),
+				}
+			}
+		}
+
+		impl PartialEq for $name {
+			fn eq(
+				&self,
+				other: &Self,
+			) -> bool {
+				self.as_bytes().ok() == other.as_bytes().ok()
+			}
+		}
+
+		impl FromBytes for $name {
+			type State = Context;
+
+			fn from_bytes(
+				context: &Context,
+				data: &[u8],
+			) -> Result<Self> {
+				let mut bytes_read = 0;
+
+				let key = Self::new()?;
+
+				try_seal!(unsafe {
+					bindgen::$load_fn(
+						key.get_handle(),
+						context.get_handle(),
+						data.as_ptr() as *mut u8,
+						data.len() as u64,
+						&mut bytes_read,
+					)
+				})?;
+
+				Ok(key)
+			}
+		}
+
+		impl ToBytes for $name {
+			fn as_bytes(&self) -> Result<Vec<u8>> {
+				self.to_bytes_with_compression(CompressionType::ZStd)
+			}
+
+			fn to_bytes_with_compression(
+				&self,
+				compression: CompressionType,
+			) -> Result<Vec<u8>> {
+				crate::serialization::require_supported_compression(compression)?;
+
+				let mut num_bytes: i64 = 0;
+
+				try_seal!(unsafe {
+					bindgen::$save_size_fn(self.get_handle(), compression as u8, &mut num_bytes)
+				})?;
+
+				let mut data: Vec<u8> = Vec::with_capacity(num_bytes as usize);
+				let mut bytes_written: i64 = 0;
+
+				try_seal!(unsafe {
+					let data_ptr = data.as_mut_ptr();
+
+					bindgen::$save_fn(
+						self.get_handle(),
+						data_ptr,
+						num_bytes as u64,
+						compression as u8,
+						&mut bytes_written,
+					)
+				})?;
+
+				unsafe { data.set_len(bytes_written as usize) };
+
+				Ok(data)
+			}
+		}
+
+		impl Drop for $name {
+			fn drop(&mut self) {
+				try_seal!(unsafe { bindgen::$destroy_fn(self.get_handle()) })
+					.expect(concat!("Internal error in ", stringify!($name), "::drop()."));
+			}
+		}
+	};
+}
+
+/// Implements `serde::Serialize` for a key type the same way `sealed_key_type!` used to,
+/// unconditionally and via [`ToBytes::as_bytes`]. Kept separate from that macro so `SecretKey`
+/// can opt out and provide its own, feature-gated impl below instead.
+macro_rules! plain_key_serialize {
+	($name:ident) => {
+		impl Serialize for $name {
+			fn serialize<S>(
+				&self,
+				serializer: S,
+			) -> std::result::Result<S::Ok, S::Error>
+			where
+				S: Serializer,
+			{
+				let bytes = self
+					.as_bytes()
+					.map_err(|e| S::Error::custom(format!("Failed to serialize bytes: {}", e)))?;
+
+				serializer.serialize_bytes(&bytes)
+			}
+		}
+	};
+}
+
+sealed_key_type!(
+	PublicKey,
+	PublicKey_Create1,
+	PublicKey_Create2,
+	PublicKey_Destroy,
+	PublicKey_SaveSize,
+	PublicKey_Save,
+	PublicKey_Load,
+	"Class to store a public key."
+);
+
+plain_key_serialize!(PublicKey);
+
+sealed_key_type!(
+	SecretKey,
+	SecretKey_Create1,
+	SecretKey_Create2,
+	SecretKey_Destroy,
+	SecretKey_SaveSize,
+	SecretKey_Save,
+	SecretKey_Load,
+	"Class to store a secret key.\n\nDeliberately does not implement `PartialOrd` or `Hash`: an \
+	ordering or hash derived from secret bytes would itself be a side channel (e.g. comparing two \
+	keys by ordering leaks information a byte-exact `PartialEq` doesn't), so this type only ever \
+	exposes equality. For the same reason, its `serde::Serialize` impl is gated behind the opt-in \
+	`secret-serde` feature instead of being derived unconditionally like the other key types."
+);
+
+#[cfg(feature = "secret-serde")]
+impl Serialize for SecretKey {
+	/// Serializes this secret key's raw bytes, gated behind the opt-in `secret-serde` feature so
+	/// embedding a secret key in a serde-derived struct is something a caller has to ask for
+	/// explicitly, rather than something that can happen implicitly through a derive elsewhere in
+	/// a dependency graph.
+	///
+	/// Unlike the other key types' `Serialize` impls, this always saves with
+	/// [`CompressionType::None`] rather than the `ZStd` default: a compressor's output length (and
+	/// the time spent compressing) varies with its input, so a compressed secret key would leak a
+	/// coarse side channel on its own bytes. The fixed-size, uncompressed representation doesn't
+	/// have that problem.
+	fn serialize<S>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let bytes = self
+			.to_bytes_with_compression(CompressionType::None)
+			.map_err(|e| S::Error::custom(format!("Failed to serialize bytes: {}", e)))?;
+
+		serializer.serialize_bytes(&bytes)
+	}
+}
+
+impl SecretToBytes for SecretKey {
+	/// Serializes this secret key the same way as [`ToBytes::as_bytes`], but returns the
+	/// result as a [`Protected`] buffer so the decoded secret is `mlock`'d and zeroized on
+	/// drop instead of lingering, unwiped, in freed and potentially swapped-out heap.
+	///
+	/// The underlying SEAL save call still has to write the decoded bytes into a plain `Vec`
+	/// first; `Protected::new` locks and takes ownership of that buffer immediately afterwards,
+	/// which is the earliest point this binding can intervene.
+	///
+	/// Unlike [`crate::Plaintext::mark_secret`], `SecretKey` has no equivalent in-place
+	/// zeroize: the `sealed_key_type!` bindings only expose whole-object save/load, with no
+	/// per-coefficient accessor analogous to `Plaintext_SetCoeffAt`, so there's no way to wipe
+	/// a live `SecretKey`'s coefficients without destroying it. Serializing through this path
+	/// and holding the result in a `Protected` buffer remains the only scrubbed representation
+	/// of a secret key this crate can produce.
+	fn secret_as_bytes(&self) -> Result<Protected> {
+		Protected::new(self.as_bytes()?)
+	}
+}
+
+sealed_key_type!(
+	RelinearizationKey,
+	KSwitchKeys_Create1,
+	KSwitchKeys_Create2,
+	KSwitchKeys_Destroy,
+	KSwitchKeys_SaveSize,
+	KSwitchKeys_Save,
+	KSwitchKeys_Load,
+	"Class to store relinearization keys."
+);
+
+plain_key_serialize!(RelinearizationKey);
+
+sealed_key_type!(
+	GaloisKey,
+	KSwitchKeys_Create1,
+	KSwitchKeys_Create2,
+	KSwitchKeys_Destroy,
+	KSwitchKeys_SaveSize,
+	KSwitchKeys_Save,
+	KSwitchKeys_Load,
+	"Class to store Galois keys, used to rotate plaintext slots."
+);
+
+plain_key_serialize!(GaloisKey);
+
+macro_rules! seeded_compression_stub {
+	($name:ident) => {
+		impl $name {
+			/// Would serialize this key in SEAL's compact seeded form: the 32-byte PRNG seed used
+			/// to generate the key's uniformly-random component `a`, plus the secret-dependent
+			/// component, instead of both full-size polynomials. Upstream SEAL exposes this via
+			/// `Serializable<T>` and a `save_seed` overload on the creating `KeyGenerator` call;
+			/// this binding has neither, so this always returns
+			/// [`Error::UnsupportedOperation`](crate::Error::UnsupportedOperation).
+			///
+			/// # Limitations
+			///
+			/// This crate's `
+			#[doc = stringify!($name)]
+			/// ` is an opaque handle produced by whichever `KeyGenerator_Create*` bindgen call
+			/// built it, and the bound save/load surface
+			/// (`*_SaveSize`/`*_Save`/`*_Load`) only round-trips the whole object. There is no
+			/// bindgen symbol that reports the seed a `KeyGenerator` used, nor one that
+			/// reconstructs a key's `a` component from a caller-supplied seed, so a compact
+			/// seeded encoding can't be produced or re-expanded without adding new C++ shim
+			/// functions to the vendored SEAL wrapper — out of reach from this crate alone.
+			pub fn as_bytes_compressed(&self) -> Result<Vec<u8>> {
+				Err(Error::UnsupportedOperation)
+			}
+
+			/// Would deserialize a blob produced by
+			#[doc = concat!("[`", stringify!($name), "::as_bytes_compressed`]")]
+			/// , re-expanding `a` from its seed against `context` and validating the result
+			/// against the context's parameters. See that method's docs for why this always
+			/// returns [`Error::UnsupportedOperation`](crate::Error::UnsupportedOperation) instead.
+			pub fn from_bytes_compressed(
+				_context: &Context,
+				_data: &[u8],
+			) -> Result<Self> {
+				Err(Error::UnsupportedOperation)
+			}
+		}
+	};
+}
+
+seeded_compression_stub!(PublicKey);
+seeded_compression_stub!(RelinearizationKey);
+seeded_compression_stub!(GaloisKey);
+
+/// Generates matching secret key and public key. An existing KeyGenerator can
+/// also at any time be used to generate relinearization keys and Galois keys.
+/// Constructing a KeyGenerator requires only a `Context`.
+pub struct KeyGenerator {
+	handle: AtomicPtr<c_void>,
+	secret_key: SecretKey,
+}
+
+unsafe impl Sync for KeyGenerator {}
+unsafe impl Send for KeyGenerator {}
+
+impl KeyGenerator {
+	/// Creates a KeyGenerator initialized with the specified `Context` and generates a fresh
+	/// secret key.
+	pub fn new(ctx: &Context) -> Result<Self> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::KeyGenerator_Create1(ctx.get_handle(), &mut handle) })?;
+
+		let secret_key = Self::copy_secret_key(handle)?;
+
+		Ok(Self {
+			handle: AtomicPtr::new(handle),
+			secret_key,
+		})
+	}
+
+	/// Creates a KeyGenerator initialized with the specified `Context` and the given
+	/// previously-generated secret key.
+	pub fn new_from_secret_key(
+		ctx: &Context,
+		secret_key: &SecretKey,
+	) -> Result<Self> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe {
+			bindgen::KeyGenerator_Create2(ctx.get_handle(), secret_key.get_handle(), &mut handle)
+		})?;
+
+		Ok(Self {
+			handle: AtomicPtr::new(handle),
+			secret_key: secret_key.clone(),
+		})
+	}
+
+	/// Returns the handle to the underlying SEAL object.
+	pub(crate) unsafe fn get_handle(&self) -> *mut c_void {
+		self.handle.load(Ordering::SeqCst)
+	}
+
+	fn copy_secret_key(handle: *mut c_void) -> Result<SecretKey> {
+		let mut key_handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::KeyGenerator_SecretKey(handle, &mut key_handle) })?;
+
+		Ok(unsafe { SecretKey::from_handle(key_handle) })
+	}
+
+	/// Returns a copy of the secret key.
+	pub fn secret_key(&self) -> SecretKey {
+		self.secret_key.clone()
+	}
+
+	/// Generates and returns a new public key.
+	pub fn create_public_key(&self) -> PublicKey {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::KeyGenerator_CreatePublicKey(self.get_handle(), &mut handle) })
+			.expect("Internal error: Failed to create public key.");
+
+		unsafe { PublicKey::from_handle(handle) }
+	}
+
+	/// Generates and returns relinearization keys.
+	pub fn create_relinearization_keys(&self) -> Result<RelinearizationKey> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe {
+			bindgen::KeyGenerator_CreateRelinKeys(self.get_handle(), &mut handle)
+		})?;
+
+		Ok(unsafe { RelinearizationKey::from_handle(handle) })
+	}
+
+	/// Generates and returns Galois keys.
+	pub fn create_galois_keys(&self) -> Result<GaloisKey> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::KeyGenerator_CreateGaloisKeys(self.get_handle(), &mut handle) })?;
+
+		Ok(unsafe { GaloisKey::from_handle(handle) })
+	}
+
+	/// Generates and returns Galois keys restricted to the given rotation steps, rather than the
+	/// full Galois group [`KeyGenerator::create_galois_keys`] produces.
+	///
+	/// Most circuits only ever rotate by a handful of values (e.g. the powers of two a
+	/// rotate-and-add reduction needs), so a key covering just those steps can be an order of
+	/// magnitude smaller to serialize and faster to generate than the complete set.
+	///
+	/// * `steps` - the rotation step counts the returned key should support
+	pub fn create_galois_keys_from_steps(&self, steps: &[i32]) -> Result<GaloisKey> {
+		if steps.is_empty() {
+			return Err(Error::InvalidArgument);
+		}
+
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe {
+			bindgen::KeyGenerator_CreateGaloisKeysFromSteps(
+				self.get_handle(),
+				steps.as_ptr() as *mut i32,
+				steps.len() as u64,
+				&mut handle,
+			)
+		})?;
+
+		Ok(unsafe { GaloisKey::from_handle(handle) })
+	}
+
+	/// Generates and returns Galois keys restricted to the given raw Galois elements, rather than
+	/// the full Galois group [`KeyGenerator::create_galois_keys`] produces.
+	///
+	/// This is the same restricted-key-generation idea as
+	/// [`KeyGenerator::create_galois_keys_from_steps`], but for callers that already have the
+	/// Galois group elements on hand (e.g. forwarded from another party in a multi-party protocol)
+	/// rather than the rotation step counts they came from.
+	///
+	/// * `elts` - the raw Galois elements the returned key should support
+	pub fn create_galois_keys_from_elts(&self, elts: &[u32]) -> Result<GaloisKey> {
+		if elts.is_empty() {
+			return Err(Error::InvalidArgument);
+		}
+
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe {
+			bindgen::KeyGenerator_CreateGaloisKeysFromElts(
+				self.get_handle(),
+				elts.as_ptr() as *mut u32,
+				elts.len() as u64,
+				&mut handle,
+			)
+		})?;
+
+		Ok(unsafe { GaloisKey::from_handle(handle) })
+	}
+}
+
+impl Drop for KeyGenerator {
+	fn drop(&mut self) {
+		try_seal!(unsafe { bindgen::KeyGenerator_Destroy(self.get_handle()) })
+			.expect("Internal error in KeyGenerator::drop().");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	#[test]
+	fn seeded_compressed_key_serialization_reports_unsupported() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let public_key = gen.create_public_key();
+		let relin_keys = gen.create_relinearization_keys().unwrap();
+		let galois_keys = gen.create_galois_keys().unwrap();
+
+		assert!(matches!(
+			public_key.as_bytes_compressed(),
+			Err(Error::UnsupportedOperation)
+		));
+		assert!(matches!(
+			relin_keys.as_bytes_compressed(),
+			Err(Error::UnsupportedOperation)
+		));
+		assert!(matches!(
+			galois_keys.as_bytes_compressed(),
+			Err(Error::UnsupportedOperation)
+		));
+		assert!(matches!(
+			PublicKey::from_bytes_compressed(&ctx, &[]),
+			Err(Error::UnsupportedOperation)
+		));
+	}
+
+	#[test]
+	fn can_create_and_destroy_key_generator() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		std::mem::drop(gen);
+	}
+
+	#[test]
+	fn can_create_public_key() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		let _public_key = gen.create_public_key();
+	}
+
+	#[test]
+	fn can_create_relinearization_and_galois_keys() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		gen.create_relinearization_keys().unwrap();
+		gen.create_galois_keys().unwrap();
+	}
+
+	#[test]
+	fn can_create_galois_keys_from_steps() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		gen.create_galois_keys_from_steps(&[1, 2, 4]).unwrap();
+	}
+
+	#[test]
+	fn create_galois_keys_from_steps_rejects_an_empty_step_list() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		assert!(matches!(
+			gen.create_galois_keys_from_steps(&[]),
+			Err(Error::InvalidArgument)
+		));
+	}
+
+	#[test]
+	fn can_create_galois_keys_from_elts() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		// Galois element for a left rotation by one slot of a degree-8192 ring, i.e. 2 * 1 + 1.
+		gen.create_galois_keys_from_elts(&[3]).unwrap();
+	}
+
+	#[test]
+	fn create_galois_keys_from_elts_rejects_an_empty_element_list() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+
+		assert!(matches!(
+			gen.create_galois_keys_from_elts(&[]),
+			Err(Error::InvalidArgument)
+		));
+	}
+
+	#[test]
+	fn public_key_round_trips_through_serde_json() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let public_key = gen.create_public_key();
+
+		let json = serde_json::to_string(&public_key).unwrap();
+		let bytes: Vec<u8> = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(public_key, PublicKey::from_bytes(&ctx, &bytes).unwrap());
+	}
+
+	#[cfg(feature = "secret-serde")]
+	#[test]
+	fn secret_key_serde_serialization_is_uncompressed() {
+		let ctx = mk_ctx();
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let secret_key = gen.secret_key();
+
+		let json = serde_json::to_string(&secret_key).unwrap();
+		let bytes: Vec<u8> = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(bytes, secret_key.to_bytes_with_compression(CompressionType::None).unwrap());
+	}
+}
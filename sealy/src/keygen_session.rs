@@ -0,0 +1,270 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use crate::{error::*, Context, KeyGenerator, PublicKey, SecretKey, ToBytes};
+
+/// Computes a fingerprint of a public-key share, so other parties can detect a `Part` that was
+/// corrupted or tampered with in transit before they `Ack` it.
+fn fingerprint(public_key: &PublicKey) -> Result<u64> {
+	let bytes = public_key.as_bytes()?;
+	let mut hasher = DefaultHasher::new();
+	bytes.hash(&mut hasher);
+	Ok(hasher.finish())
+}
+
+/// A party's commitment, broadcast during a [`KeyGenSession`] round: its own freshly-generated
+/// public-key share, plus a fingerprint of that share the other parties can check before
+/// acknowledging it.
+///
+/// This mirrors the "Part" message of round-based distributed key generation protocols (e.g.
+/// the multiparty-FHE protocol of Mouchet et al., or FROST-style threshold signing): every
+/// party publishes one `Part`, the rest validate and `Ack` it, and once a quorum of `Ack`s
+/// exists for every `Part` the session can attempt to finalize.
+pub struct Part {
+	party_id: u32,
+	public_key: PublicKey,
+	fingerprint: u64,
+}
+
+impl Part {
+	fn new(
+		party_id: u32,
+		public_key: PublicKey,
+	) -> Result<Self> {
+		let fingerprint = fingerprint(&public_key)?;
+
+		Ok(Self {
+			party_id,
+			public_key,
+			fingerprint,
+		})
+	}
+
+	/// Returns the id of the party that produced this `Part`.
+	pub fn party_id(&self) -> u32 {
+		self.party_id
+	}
+
+	/// Returns this party's public-key share.
+	pub fn public_key(&self) -> &PublicKey {
+		&self.public_key
+	}
+
+	/// Returns `true` if this `Part`'s public-key share still matches the fingerprint it was
+	/// broadcast with, i.e. it wasn't corrupted or tampered with in transit.
+	pub fn verify(&self) -> bool {
+		fingerprint(&self.public_key)
+			.map(|f| f == self.fingerprint)
+			.unwrap_or(false)
+	}
+}
+
+/// An acknowledgement that `acker_party_id` has validated the [`Part`] broadcast by
+/// `acked_party_id`.
+///
+/// A [`KeyGenSession`] only finalizes once it has collected `Ack`s for a `Part` from a quorum
+/// of the participating parties.
+pub struct Ack {
+	acker_party_id: u32,
+	acked_party_id: u32,
+}
+
+impl Ack {
+	/// Creates an acknowledgement from `acker_party_id` that it has validated the `Part`
+	/// broadcast by `acked_party_id`.
+	pub fn new(
+		acker_party_id: u32,
+		acked_party_id: u32,
+	) -> Self {
+		Self {
+			acker_party_id,
+			acked_party_id,
+		}
+	}
+
+	/// Returns the id of the party that produced this acknowledgement.
+	pub fn acker_party_id(&self) -> u32 {
+		self.acker_party_id
+	}
+
+	/// Returns the id of the party whose `Part` is being acknowledged.
+	pub fn acked_party_id(&self) -> u32 {
+		self.acked_party_id
+	}
+}
+
+/// Coordinates one party's side of a round-based, multi-party public-key generation session.
+///
+/// Each party constructs its own `KeyGenSession`, broadcasts [`KeyGenSession::own_part`] to
+/// the others, feeds every `Part` and `Ack` it receives back in through [`handle_part`] and
+/// [`handle_ack`], and once a quorum of acknowledgements exists for every party's `Part`, calls
+/// [`finalize`] to derive the joint public key.
+///
+/// [`handle_part`]: KeyGenSession::handle_part
+/// [`handle_ack`]: KeyGenSession::handle_ack
+/// [`finalize`]: KeyGenSession::finalize
+///
+/// # Limitations
+///
+/// Deriving a cryptographically sound joint key this way requires every party's public-key
+/// share to be generated against the same shared random polynomial (a "common reference
+/// string"), after which the shares can be combined by summing them coefficient-wise — the
+/// same way `Evaluator::add` already combines two ciphertexts. Neither half of that is
+/// available through this crate's SEAL binding today: `KeyGenerator` always samples its own
+/// randomness with no way to pin the shared polynomial, and `PublicKey` exposes no arithmetic
+/// or raw-coefficient accessors to combine shares with afterwards. This type therefore
+/// faithfully tracks the protocol's round bookkeeping (`Part`/`Ack` collection and quorum
+/// counting), but [`finalize`] reports [`Error::UnsupportedOperation`] instead of handing back
+/// a key that would merely look correct. Closing this gap needs either a SEAL binding that
+/// accepts an externally supplied CRS during key generation, or a way to read/write a
+/// `PublicKey`'s raw polynomials.
+pub struct KeyGenSession {
+	party_id: u32,
+	quorum: usize,
+	secret_share: SecretKey,
+	own_part: Part,
+	parts: HashMap<u32, Part>,
+	acks: HashMap<u32, HashSet<u32>>,
+}
+
+impl KeyGenSession {
+	/// Starts this party's side of a session, requiring acknowledgements from `quorum` other
+	/// parties before a `Part` is considered accepted.
+	///
+	/// * `context` - The context all parties are generating keys under.
+	/// * `party_id` - This party's id, unique among the participants.
+	/// * `quorum` - The number of other parties that must `Ack` a `Part` before it's accepted.
+	pub fn new(
+		context: &Context,
+		party_id: u32,
+		quorum: usize,
+	) -> Result<Self> {
+		let gen = KeyGenerator::new(context)?;
+		let secret_share = gen.secret_key().clone();
+		let public_key = gen.create_public_key();
+		let own_part = Part::new(party_id, public_key)?;
+
+		Ok(Self {
+			party_id,
+			quorum,
+			secret_share,
+			own_part,
+			parts: HashMap::new(),
+			acks: HashMap::new(),
+		})
+	}
+
+	/// Returns this party's id.
+	pub fn party_id(&self) -> u32 {
+		self.party_id
+	}
+
+	/// Returns this party's own `Part`, to be broadcast to the other participants.
+	pub fn own_part(&self) -> &Part {
+		&self.own_part
+	}
+
+	/// Returns this party's share of the (would-be) joint secret key.
+	pub fn secret_share(&self) -> &SecretKey {
+		&self.secret_share
+	}
+
+	/// Records a `Part` broadcast by another party, rejecting it if its fingerprint doesn't
+	/// match its public-key share.
+	pub fn handle_part(
+		&mut self,
+		part: Part,
+	) -> Result<()> {
+		if !part.verify() {
+			return Err(Error::InvalidArgument);
+		}
+
+		self.parts.insert(part.party_id(), part);
+
+		Ok(())
+	}
+
+	/// Records that `ack.acker_party_id()` has validated the `Part` broadcast by
+	/// `ack.acked_party_id()`.
+	pub fn handle_ack(
+		&mut self,
+		ack: Ack,
+	) -> Result<()> {
+		self.acks.entry(ack.acked_party_id()).or_default().insert(ack.acker_party_id());
+
+		Ok(())
+	}
+
+	/// Returns `true` once a quorum of other parties has acknowledged `party_id`'s `Part`.
+	pub fn has_quorum(
+		&self,
+		party_id: u32,
+	) -> bool {
+		self.acks
+			.get(&party_id)
+			.map(|acks| acks.len() >= self.quorum)
+			.unwrap_or(false)
+	}
+
+	/// Attempts to derive the aggregated joint `PublicKey`, usable unchanged with
+	/// [`crate::Encryptor::with_public_key`], from this party's own `Part` plus every `Part`
+	/// recorded via [`handle_part`](KeyGenSession::handle_part).
+	///
+	/// Returns [`Error::QuorumNotReached`] if this party's own `Part` hasn't yet been
+	/// acknowledged by a quorum of the others. Otherwise, see the limitations documented on
+	/// [`KeyGenSession`]: this always returns [`Error::UnsupportedOperation`], since combining
+	/// the collected shares into a sound joint key isn't possible with the primitives this
+	/// crate's SEAL binding exposes.
+	pub fn finalize(&self) -> Result<PublicKey> {
+		if !self.has_quorum(self.party_id) {
+			return Err(Error::QuorumNotReached);
+		}
+
+		Err(Error::UnsupportedOperation)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	#[test]
+	fn accepts_a_genuine_part_and_tracks_acks() {
+		let ctx = mk_ctx();
+
+		let session_a = KeyGenSession::new(&ctx, 1, 1).unwrap();
+		let mut session_b = KeyGenSession::new(&ctx, 2, 1).unwrap();
+
+		assert!(session_a.own_part().verify());
+
+		let part = Part::new(session_a.party_id(), session_a.own_part().public_key().clone())
+			.unwrap();
+		session_b.handle_part(part).unwrap();
+
+		assert!(!session_a.has_quorum(session_a.party_id()));
+		session_b.handle_ack(Ack::new(session_b.party_id(), session_a.party_id())).unwrap();
+	}
+
+	#[test]
+	fn finalize_reports_quorum_not_reached_before_acks() {
+		let ctx = mk_ctx();
+		let session = KeyGenSession::new(&ctx, 1, 2).unwrap();
+
+		assert!(matches!(session.finalize(), Err(Error::QuorumNotReached)));
+	}
+
+	#[test]
+	fn finalize_reports_unsupported_once_quorum_is_reached() {
+		let ctx = mk_ctx();
+		let mut session = KeyGenSession::new(&ctx, 1, 1).unwrap();
+
+		session.handle_ack(Ack::new(2, 1)).unwrap();
+
+		assert!(matches!(session.finalize(), Err(Error::UnsupportedOperation)));
+	}
+}
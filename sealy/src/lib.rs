@@ -70,46 +70,90 @@ mod bindgen {
 	pub const COR_E_INVALIDOPERATION: c_long = 0x80131509u32 as c_long;
 }
 
+mod aggregator;
 mod ciphertext;
+mod compact_ciphertext;
 mod components;
 mod context;
+mod context_data;
+mod crt_ciphertext;
 mod decryptor;
 mod encoder;
+mod encrypted_chunk;
 mod encryptor;
 mod error;
+mod eval_env;
 mod evaluator;
 mod ext;
 mod key_generator;
+mod keygen_session;
+mod maybe_encrypted;
 mod memory;
 mod modulus;
+mod net_aggregation;
 mod parameters;
 mod plaintext;
 mod poly_array;
+mod proto;
+mod secure;
+pub mod serde;
 mod serialization;
+mod signed_ciphertext;
+#[cfg(test)]
+mod test_support;
+mod threshold;
+mod typed_context;
 
+pub use aggregator::{FheAggregator, SecureAggregator, TensorAggregator};
 pub use ciphertext::Ciphertext;
+pub use compact_ciphertext::CompactCiphertext;
 pub use components::{
 	marker as component_marker, Asym, AsymmetricComponents, Sym, SymAsym, SymmetricComponents,
 };
 pub use context::Context;
-pub use decryptor::Decryptor;
-pub use encoder::bfv::BFVEncoder;
+pub use context_data::ContextData;
+pub use crt_ciphertext::CrtCiphertext;
+pub use decryptor::{Decrypt, Decryptor};
+pub use encoder::bfv::{BFVEncoder, BFVFractionalEncoder, IntegerEncoder};
 pub use encoder::ckks::CKKSEncoder;
-pub use encryptor::{AsymmetricEncryptor, Encryptor, SymmetricEncryptor};
+pub use encoder::crt::CrtEncoder;
+pub use encoder::{Encoder, SlotCount};
+pub use encrypted_chunk::{EncryptedChunk, EncryptionAlgorithm};
+pub use encryptor::{AsymmetricEncryptor, Encrypt, Encryptor, SymmetricEncryptor};
 pub use error::{Error, Result};
+pub use eval_env::{BFVEvalEnv, CKKSEvalEnv};
 pub use evaluator::bfv::BFVEvaluator;
 pub use evaluator::ckks::CKKSEvaluator;
 pub use evaluator::Evaluator;
 pub use ext::tensor::{
-	decryptor::TensorDecryptor, encoder::TensorEncoder, encryptor::TensorEncryptor,
-	evaluator::TensorEvaluator, FromChunk, Tensor, ToChunk,
+	decryptor::TensorDecryptor,
+	encoder::{Column, Conversion, EncodedColumn, MixedTensorEncoder, TensorEncoder},
+	encryptor::TensorEncryptor,
+	evaluator::TensorEvaluator,
+	FromChunk, ShapedTensor, Tensor, ToChunk,
 };
 pub use key_generator::{GaloisKey, KeyGenerator, PublicKey, RelinearizationKey, SecretKey};
+pub use keygen_session::{Ack, KeyGenSession, Part};
+pub use maybe_encrypted::MaybeEncrypted;
 pub use memory::MemoryPool;
 pub use modulus::{
 	CoefficientModulusFactory, DegreeType, Modulus, PlainModulusFactory, SecurityLevel,
 };
+pub use net_aggregation::{chunk_for_transport, RunParameters};
 pub use parameters::*;
 pub use plaintext::Plaintext;
 pub use poly_array::PolynomialArray;
-pub use serialization::{FromBytes, ToBytes};
+pub use proto::{BatchEnvelope, BatchHeader, ProtoChunks};
+pub use secure::{Encrypted, Protected, SecretToBytes};
+pub use signed_ciphertext::{
+	Ed25519KeyPair, Ed25519PublicKey, SignedCiphertext, SignedCiphertextBatch,
+};
+pub use serialization::{
+	CompressionType, FromBytes, FromReader, ToBytes, ToWriter, Versioned, VersionedChunks,
+};
+pub use threshold::{
+	combine_decryption_shares, combine_partial_decryptions, combine_partials,
+	CommonReferenceString, DecryptionShare, PartialDecryptionShare, PartialDecryptor,
+	ThresholdKeyGenerator,
+};
+pub use typed_context::{chain, scheme, TypedContext};
@@ -0,0 +1,157 @@
+use crate::{
+	component_marker, Ciphertext, Context, Error, Plaintext, Result, Tensor, TensorDecryptor,
+	TensorEncryptor, VersionedChunks,
+};
+
+/// A tensor that is either still cleartext or already encrypted, letting a pipeline pass a
+/// value through stages that only conditionally encrypt it (e.g. keeping low-sensitivity
+/// columns as plaintext while encrypting others) without the caller tracking the state by
+/// hand.
+pub enum MaybeEncrypted {
+	/// The tensor has not been encrypted yet.
+	Plain(Tensor<Plaintext>),
+	/// The tensor has been encrypted.
+	Cipher(Tensor<Ciphertext>),
+}
+
+impl MaybeEncrypted {
+	/// Returns whether this value currently holds ciphertexts rather than cleartext.
+	pub fn is_encrypted(&self) -> bool {
+		matches!(self, Self::Cipher(_))
+	}
+
+	/// Encrypts `self` in place with `encryptor`. A no-op if `self` is already encrypted.
+	pub fn encrypt_in_place<T: component_marker::Asym>(
+		&mut self,
+		encryptor: &TensorEncryptor<T>,
+	) -> Result<()> {
+		if let Self::Plain(plaintext) = self {
+			*self = Self::Cipher(encryptor.encrypt(plaintext)?);
+		}
+
+		Ok(())
+	}
+
+	/// Decrypts `self` in place with `decryptor`. A no-op if `self` is already cleartext.
+	pub fn decrypt_in_place(
+		&mut self,
+		decryptor: &TensorDecryptor,
+	) -> Result<()> {
+		if let Self::Cipher(ciphertext) = self {
+			*self = Self::Plain(decryptor.decrypt(ciphertext)?);
+		}
+
+		Ok(())
+	}
+
+	/// Returns the inner ciphertext tensor, so callers can feed it to a
+	/// [`crate::ext::tensor::evaluator::TensorEvaluator`]. Fails with
+	/// [`Error::InvalidArgument`] if `self` has not been encrypted yet.
+	pub fn as_ciphertext(&self) -> Result<&Tensor<Ciphertext>> {
+		match self {
+			Self::Cipher(ciphertext) => Ok(ciphertext),
+			Self::Plain(_) => Err(Error::InvalidArgument),
+		}
+	}
+
+	/// Returns the inner plaintext tensor. Fails with [`Error::InvalidArgument`] if `self` has
+	/// already been encrypted.
+	pub fn as_plaintext(&self) -> Result<&Tensor<Plaintext>> {
+		match self {
+			Self::Plain(plaintext) => Ok(plaintext),
+			Self::Cipher(_) => Err(Error::InvalidArgument),
+		}
+	}
+
+	/// Serializes `self`, tagging which variant is present so [`MaybeEncrypted::from_bytes`]
+	/// can restore the same state without the caller tracking it separately.
+	pub fn as_bytes(
+		&self,
+		context: &Context,
+	) -> Result<Vec<u8>> {
+		let (tag, payload) = match self {
+			Self::Plain(plaintext) => (0u8, plaintext.to_bytes_versioned(context)?),
+			Self::Cipher(ciphertext) => (1u8, ciphertext.to_bytes_versioned(context)?),
+		};
+
+		let mut bytes = Vec::with_capacity(1 + payload.len());
+		bytes.push(tag);
+		bytes.extend_from_slice(&payload);
+
+		Ok(bytes)
+	}
+
+	/// Deserializes a value previously produced by [`MaybeEncrypted::as_bytes`].
+	pub fn from_bytes(
+		context: &Context,
+		bytes: &[u8],
+	) -> Result<Self> {
+		let (&tag, payload) = bytes.split_first().ok_or(Error::InvalidSerializedData)?;
+
+		match tag {
+			0 => Ok(Self::Plain(Tensor::from_bytes_versioned(context, payload)?)),
+			1 => Ok(Self::Cipher(Tensor::from_bytes_versioned(context, payload)?)),
+			_ => Err(Error::InvalidSerializedData),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	#[test]
+	fn encrypt_in_place_transitions_plain_to_cipher_and_is_idempotent() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let public_key = gen.create_public_key();
+		let secret_key = gen.secret_key();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let encryptor = TensorEncryptor::with_public_key(&ctx, &public_key).unwrap();
+		let decryptor = TensorDecryptor::new(&ctx, &secret_key).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let mut value = MaybeEncrypted::Plain(Tensor(vec![plaintext]));
+
+		assert!(!value.is_encrypted());
+
+		value.encrypt_in_place(&encryptor).unwrap();
+		assert!(value.is_encrypted());
+
+		// Encrypting an already-encrypted value is a no-op: the ciphertext is left untouched.
+		let before = value.as_ciphertext().unwrap().first().unwrap().clone();
+		value.encrypt_in_place(&encryptor).unwrap();
+		let after = value.as_ciphertext().unwrap().first().unwrap().clone();
+		assert_eq!(before.as_bytes().unwrap(), after.as_bytes().unwrap());
+
+		value.decrypt_in_place(&decryptor).unwrap();
+		assert!(!value.is_encrypted());
+
+		let decoded = encoder.decode(value.as_plaintext().unwrap().first().unwrap()).unwrap();
+		assert_eq!(&decoded[..3], &[1, 2, 3]);
+	}
+
+	#[test]
+	fn round_trips_both_variants_through_bytes() {
+		let ctx = mk_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let plaintext = MaybeEncrypted::Plain(Tensor(vec![encoder.encode_i64(&[7]).unwrap()]));
+		let bytes = plaintext.as_bytes(&ctx).unwrap();
+		let loaded = MaybeEncrypted::from_bytes(&ctx, &bytes).unwrap();
+		assert!(!loaded.is_encrypted());
+
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = TensorEncryptor::with_public_key(&ctx, &gen.create_public_key()).unwrap();
+		let mut cipher = MaybeEncrypted::Plain(Tensor(vec![encoder.encode_i64(&[8]).unwrap()]));
+		cipher.encrypt_in_place(&encryptor).unwrap();
+
+		let bytes = cipher.as_bytes(&ctx).unwrap();
+		let loaded = MaybeEncrypted::from_bytes(&ctx, &bytes).unwrap();
+		assert!(loaded.is_encrypted());
+	}
+}
@@ -3,9 +3,22 @@ use std::{ffi::c_void, ptr::null_mut};
 
 use crate::bindgen;
 
-/// Memory pool handle for SEAL.
+/// A handle to one of SEAL's memory pools, which back the scratch allocations that encoders and
+/// evaluators need while they run.
 ///
-/// Heavily incomplete and work in progress.
+/// `MemoryPool` is `Send`/`Sync` because the underlying SEAL `MemoryPoolHandle` is a thin,
+/// reference-counted handle to a pool that is itself internally synchronized: every method SEAL
+/// exposes on it is safe to call concurrently from multiple threads, so passing or sharing a
+/// `MemoryPool` across threads never races. What you *don't* get for free is isolation: the
+/// pools returned by [`MemoryPool::global`] and [`MemoryPool::thread_local`] are shared (the
+/// latter per-thread, not per-handle), so concurrent callers using the same handle will
+/// contend on the same underlying allocations. [`MemoryPool::new`] hands out a pool with no
+/// such sharing, at the cost of allocating one.
+///
+/// A single handle is cheap to reuse: clone it (an `Arc`-style refcount bump, not a fresh
+/// allocation) and thread the clone through a batch of encode/evaluate calls via the
+/// `_with_pool` entry points (e.g. [`crate::CKKSEncoder::encode_f64_with_pool`]) instead of
+/// letting each call allocate its own pool.
 #[derive(Debug)]
 pub struct MemoryPool {
 	pub(crate) handle: *mut c_void,
@@ -15,7 +28,7 @@ unsafe impl Sync for MemoryPool {}
 unsafe impl Send for MemoryPool {}
 
 impl MemoryPool {
-	/// Creates an instance of MemoryPool.
+	/// Creates a fresh, exclusively-owned instance of MemoryPool.
 	pub fn new() -> Result<Self> {
 		let mut handle: *mut c_void = null_mut();
 
@@ -26,12 +39,57 @@ impl MemoryPool {
 		})
 	}
 
+	/// Returns a handle to SEAL's global memory pool, shared across the whole process.
+	///
+	/// Cheaper to obtain than [`MemoryPool::new`] since no allocation happens up front, but
+	/// every caller that uses this handle shares the same pool, so it's best suited to
+	/// low-contention or one-off work rather than a busy multi-threaded hot loop.
+	pub fn global() -> Result<Self> {
+		let mut handle: *mut c_void = null_mut();
+
+		convert_seal_error(unsafe { bindgen::MemoryPoolHandle_Global(&mut handle) })?;
+
+		Ok(MemoryPool {
+			handle,
+		})
+	}
+
+	/// Returns a handle to the calling thread's thread-local memory pool.
+	///
+	/// Reusing this handle across repeated encode/evaluate calls made from the same worker
+	/// thread avoids the allocation overhead of a fresh pool per call while still keeping
+	/// allocations isolated from other threads' thread-local pools.
+	pub fn thread_local() -> Result<Self> {
+		let mut handle: *mut c_void = null_mut();
+
+		convert_seal_error(unsafe { bindgen::MemoryPoolHandle_ThreadLocal(&mut handle) })?;
+
+		Ok(MemoryPool {
+			handle,
+		})
+	}
+
 	/// Returns handle to the underlying SEAL object.
 	pub fn get_handle(&self) -> *mut c_void {
 		self.handle
 	}
 }
 
+impl Clone for MemoryPool {
+	/// Cheaply duplicates this handle: SEAL's `MemoryPoolHandle` is reference-counted, so this
+	/// bumps a refcount rather than allocating a new pool.
+	fn clone(&self) -> Self {
+		let mut handle: *mut c_void = null_mut();
+
+		convert_seal_error(unsafe { bindgen::MemoryPoolHandle_New2(self.handle, &mut handle) })
+			.expect("Internal error: Failed to copy memory pool handle.");
+
+		MemoryPool {
+			handle,
+		}
+	}
+}
+
 impl Drop for MemoryPool {
 	fn drop(&mut self) {
 		convert_seal_error(unsafe { bindgen::MemoryPoolHandle_Destroy(self.handle) })
@@ -49,4 +107,22 @@ mod tests {
 
 		std::mem::drop(memory_pool);
 	}
+
+	#[test]
+	fn can_get_global_and_thread_local_pools() {
+		let global = MemoryPool::global().unwrap();
+		let thread_local = MemoryPool::thread_local().unwrap();
+
+		std::mem::drop(global);
+		std::mem::drop(thread_local);
+	}
+
+	#[test]
+	fn can_clone_a_pool_handle() {
+		let pool = MemoryPool::new().unwrap();
+		let cloned = pool.clone();
+
+		std::mem::drop(pool);
+		std::mem::drop(cloned);
+	}
 }
@@ -0,0 +1,332 @@
+use std::ffi::c_void;
+use std::ptr::null_mut;
+
+use crate::bindgen;
+use crate::error::*;
+use crate::try_seal;
+pub use crate::parameters::DegreeType;
+
+/// The security level used to validate encryption parameters against the
+/// HomomorphicEncryption.org security standard.
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SecurityLevel {
+	/// No security guarantee is enforced. Only use this for testing.
+	None = 0,
+
+	/// 128 bits of security.
+	TC128 = 128,
+
+	/// 192 bits of security.
+	TC192 = 192,
+
+	/// 256 bits of security.
+	TC256 = 256,
+}
+
+impl Default for SecurityLevel {
+	fn default() -> Self {
+		Self::TC128
+	}
+}
+
+impl From<SecurityLevel> for i32 {
+	fn from(value: SecurityLevel) -> Self {
+		value as i32
+	}
+}
+
+impl TryFrom<i32> for SecurityLevel {
+	type Error = Error;
+
+	fn try_from(value: i32) -> Result<Self> {
+		match value {
+			0 => Ok(Self::None),
+			128 => Ok(Self::TC128),
+			192 => Ok(Self::TC192),
+			256 => Ok(Self::TC256),
+			_ => Err(Error::InvalidParams),
+		}
+	}
+}
+
+/// Represents a single prime modulus used by SEAL, e.g. as one of the primes in a coefficient
+/// modulus chain, or as a plaintext modulus.
+pub struct Modulus {
+	handle: *mut c_void,
+}
+
+unsafe impl Sync for Modulus {}
+unsafe impl Send for Modulus {}
+
+impl Modulus {
+	/// Creates a new modulus from a constant value. The value must be a prime congruent to 1
+	/// modulo 2 * poly_modulus_degree for batching to work.
+	pub fn new(value: u64) -> Result<Self> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::Modulus_Create1(value, &mut handle) })?;
+
+		Ok(Self {
+			handle,
+		})
+	}
+
+	/// Returns the handle to the underlying SEAL object.
+	pub fn get_handle(&self) -> *mut c_void {
+		self.handle
+	}
+
+	/// Wraps an externally-owned handle without taking ownership of it. Callers must ensure the
+	/// handle outlives the returned [`Modulus`], or `forget` it before it would otherwise drop.
+	pub(crate) unsafe fn unchecked_from_handle(handle: *mut c_void) -> Self {
+		Self {
+			handle,
+		}
+	}
+
+	/// Returns the value of the modulus.
+	pub fn value(&self) -> u64 {
+		let mut value: u64 = 0;
+
+		try_seal!(unsafe { bindgen::Modulus_Value(self.handle, &mut value) })
+			.expect("Internal error in Modulus::value().");
+
+		value
+	}
+
+	/// Returns the number of significant bits of the modulus.
+	pub fn bit_count(&self) -> i32 {
+		let mut bit_count: i32 = 0;
+
+		try_seal!(unsafe { bindgen::Modulus_BitCount(self.handle, &mut bit_count) })
+			.expect("Internal error in Modulus::bit_count().");
+
+		bit_count
+	}
+}
+
+/// Creates a [`Modulus`] that refers to (but does not own) an existing SEAL modulus handle.
+///
+/// # Safety
+/// The caller must ensure `handle` remains valid and that the returned value's `Drop` impl is
+/// not allowed to run while the original owner still needs the handle (e.g. via `forget`).
+pub(crate) unsafe fn unchecked_from_handle(handle: *mut c_void) -> Modulus {
+	Modulus::unchecked_from_handle(handle)
+}
+
+impl Clone for Modulus {
+	fn clone(&self) -> Self {
+		let mut handle = null_mut();
+
+		try_seal!(unsafe { bindgen::Modulus_Create2(self.handle, &mut handle) })
+			.expect("Internal error in Modulus::clone().");
+
+		Self {
+			handle,
+		}
+	}
+}
+
+impl PartialEq for Modulus {
+	fn eq(
+		&self,
+		other: &Self,
+	) -> bool {
+		self.value() == other.value()
+	}
+}
+
+impl Eq for Modulus {}
+
+impl std::fmt::Debug for Modulus {
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result {
+		f.debug_struct("Modulus")
+			.field("value", &self.value())
+			.finish()
+	}
+}
+
+impl Drop for Modulus {
+	fn drop(&mut self) {
+		try_seal!(unsafe { bindgen::Modulus_Destroy(self.handle) })
+			.expect("Internal error in Modulus::drop().");
+	}
+}
+
+/// Factory for constructing coefficient modulus chains suitable for a given polynomial
+/// modulus degree.
+pub struct CoefficientModulusFactory;
+
+/// Alias kept for call sites that spell this type without the `Factory` suffix.
+pub type CoefficientModulus = CoefficientModulusFactory;
+
+impl CoefficientModulusFactory {
+	/// Creates a coefficient modulus chain with primes of the given bit sizes, each congruent
+	/// to 1 modulo `2 * poly_modulus_degree` so that the resulting parameters support batching.
+	///
+	/// * `degree` - The polynomial modulus degree the chain will be used with.
+	/// * `bit_sizes` - The desired bit length of each prime in the chain.
+	pub fn create(
+		degree: DegreeType,
+		bit_sizes: &[i32],
+	) -> Result<Vec<Modulus>> {
+		Self::build(degree, bit_sizes)
+	}
+
+	/// Creates a coefficient modulus chain with primes of the given bit sizes. See
+	/// [`CoefficientModulusFactory::create`].
+	pub fn build(
+		degree: DegreeType,
+		bit_sizes: &[i32],
+	) -> Result<Vec<Modulus>> {
+		let mut handles: Vec<*mut c_void> = vec![null_mut(); bit_sizes.len()];
+
+		try_seal!(unsafe {
+			bindgen::CoeffModulus_Create(
+				u64::from(degree),
+				bit_sizes.len() as u64,
+				bit_sizes.as_ptr() as *mut i32,
+				handles.as_mut_ptr(),
+			)
+		})?;
+
+		Ok(handles
+			.into_iter()
+			.map(|h| unsafe { Modulus::unchecked_from_handle(h) })
+			.collect())
+	}
+
+	/// Returns the default coefficient modulus chain recommended for the given polynomial
+	/// modulus degree and security level.
+	pub fn bfv(
+		degree: DegreeType,
+		security_level: SecurityLevel,
+	) -> Result<Vec<Modulus>> {
+		let mut len: u64 = 0;
+
+		try_seal!(unsafe {
+			bindgen::CoeffModulus_BFVDefault(
+				u64::from(degree),
+				security_level as i32,
+				&mut len,
+				null_mut(),
+			)
+		})?;
+
+		let mut handles: Vec<*mut c_void> = vec![null_mut(); len as usize];
+
+		try_seal!(unsafe {
+			bindgen::CoeffModulus_BFVDefault(
+				u64::from(degree),
+				security_level as i32,
+				&mut len,
+				handles.as_mut_ptr(),
+			)
+		})?;
+
+		Ok(handles
+			.into_iter()
+			.map(|h| unsafe { Modulus::unchecked_from_handle(h) })
+			.collect())
+	}
+
+	/// Returns the largest bit count a coefficient modulus for the given polynomial modulus
+	/// degree may have while still satisfying the given security level.
+	pub fn max_bit_count(
+		degree: u64,
+		security_level: SecurityLevel,
+	) -> u32 {
+		let bit_count =
+			unsafe { bindgen::CoeffModulus_MaxBitCount(degree, security_level as i32) };
+
+		bit_count.max(0) as u32
+	}
+
+	/// Recommends the smallest polynomial modulus degree (and a matching coefficient modulus
+	/// chain) able to support `multiplicative_depth` levels of multiplication at roughly
+	/// `precision_bits` of precision per level, under `security_level`.
+	///
+	/// The chain follows SEAL's usual CKKS shape: a 60-bit leading prime (sized for the initial
+	/// scale), `multiplicative_depth` middle primes of `precision_bits` each (one consumed by
+	/// every rescale), and a 60-bit trailing prime matching the leading one. Every
+	/// [`DegreeType`] from [`DegreeType::D256`] to [`DegreeType::D32768`] is tried in increasing
+	/// order, returning the first whose [`CoefficientModulusFactory::max_bit_count`] can fit the
+	/// chain's total bit count.
+	///
+	/// * `multiplicative_depth` - the number of multiply-then-rescale levels the parameters must
+	///   support.
+	/// * `precision_bits` - the bit width of each middle prime, which bounds the fractional
+	///   precision retained after each rescale.
+	/// * `security_level` - the security level the coefficient modulus must satisfy.
+	///
+	/// Returns [`Error::InvalidArgument`] if no degree up to `D32768` fits the requested chain.
+	pub fn recommend(
+		multiplicative_depth: usize,
+		precision_bits: u32,
+		security_level: SecurityLevel,
+	) -> Result<(DegreeType, Vec<Modulus>)> {
+		const LEADING_TRAILING_BITS: i32 = 60;
+
+		let mut bit_sizes = Vec::with_capacity(multiplicative_depth + 2);
+		bit_sizes.push(LEADING_TRAILING_BITS);
+		bit_sizes.extend(std::iter::repeat(precision_bits as i32).take(multiplicative_depth));
+		bit_sizes.push(LEADING_TRAILING_BITS);
+
+		let total_bits: u32 = bit_sizes.iter().sum::<i32>() as u32;
+
+		const DEGREES: [DegreeType; 8] = [
+			DegreeType::D256,
+			DegreeType::D512,
+			DegreeType::D1024,
+			DegreeType::D2048,
+			DegreeType::D4096,
+			DegreeType::D8192,
+			DegreeType::D16384,
+			DegreeType::D32768,
+		];
+
+		for degree in DEGREES {
+			if total_bits > Self::max_bit_count(u64::from(degree), security_level) {
+				continue;
+			}
+
+			if let Ok(modulus) = Self::create(degree, &bit_sizes) {
+				return Ok((degree, modulus));
+			}
+		}
+
+		Err(Error::InvalidArgument)
+	}
+}
+
+/// Factory for constructing a plaintext modulus.
+pub struct PlainModulusFactory;
+
+/// Alias kept for call sites that spell this type without the `Factory` suffix.
+pub type PlainModulus = PlainModulusFactory;
+
+impl PlainModulusFactory {
+	/// Creates a plaintext modulus suitable for CRT batching with the given polynomial modulus
+	/// degree, using a prime with the given bit count.
+	pub fn batching(
+		degree: DegreeType,
+		bit_size: u32,
+	) -> Result<Modulus> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe {
+			bindgen::PlainModulus_Batching(u64::from(degree), bit_size as i32, &mut handle)
+		})?;
+
+		Ok(unsafe { Modulus::unchecked_from_handle(handle) })
+	}
+
+	/// Creates a plaintext modulus from a constant value. Disables batching.
+	pub fn raw(value: u64) -> Result<Modulus> {
+		Modulus::new(value)
+	}
+}
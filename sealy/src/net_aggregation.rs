@@ -0,0 +1,143 @@
+//! Wiring [`FheAggregator`]/[`TensorAggregator`] up to a networked secure-aggregation service.
+//!
+//! # Limitations
+//!
+//! The full ask here is to serve aggregation over `thorn_core::protocol::driver`'s generated
+//! `DriverClient`/`DriverServer` and `PushTaskInsRequest`/`PullTaskResResponse`/
+//! `CreateRunRequest`/`CreateRunResponse` types, so a coordinator can accept client updates and
+//! return the aggregate over gRPC instead of in-process. That part isn't implementable against
+//! this tree: `thorn-core` has no crate root (`thorn-core/src/lib.rs` doesn't exist), its
+//! `protocol::autogen` module has no source file on disk (it's meant to be generated by
+//! `tonic_build` from `.proto` sources at build time), and the `.proto` files its `build.rs`
+//! points at (`proto/thorn/driver.proto` and friends) aren't present in this tree either. There
+//! is consequently no real `DriverServer`/`Driver` trait to implement a server against here.
+//!
+//! What *is* implementable without any of that — and genuinely useful on its own once the
+//! `thorn-core` scaffolding above is filled in — is the run-lifecycle bookkeeping and the
+//! message-size chunking a `PushTaskInsRequest` handler would need: negotiating and recording
+//! which [`Context`] a run uses, and splitting a large [`Tensor`]'s serialized chunks into
+//! groups that fit under a gRPC message size limit. Both live here.
+
+use crate::ext::tensor::ToChunk;
+use crate::{error::*, Context, ToBytes};
+
+/// The encryption parameters negotiated for one aggregation run, recorded once at run creation
+/// (analogous to what a `CreateRunRequest`/`CreateRunResponse` exchange would pin down) so every
+/// subsequent `PushTaskInsRequest` in the run can be validated against it instead of trusting
+/// each client's claimed parameters individually.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunParameters {
+	/// An opaque identifier for this run, assigned by the coordinator.
+	pub run_id: String,
+	/// The serialized encryption parameters every participant in this run must encrypt under,
+	/// as produced by `EncryptionParameters::as_bytes`.
+	pub parameters: Vec<u8>,
+}
+
+impl RunParameters {
+	/// Negotiates a new run's parameters from the coordinator's own [`Context`], to be handed
+	/// back to clients in a `CreateRunResponse`.
+	pub fn negotiate(
+		run_id: impl Into<String>,
+		context: &Context,
+	) -> Result<Self> {
+		Ok(Self {
+			run_id: run_id.into(),
+			parameters: context.get_encryption_parameters()?.as_bytes()?,
+		})
+	}
+}
+
+/// Splits the per-element byte chunks of a [`ToChunk`] value (e.g. a [`Tensor<Ciphertext>`])
+/// into groups whose total size never exceeds `max_group_bytes`, so a single
+/// `PushTaskInsRequest`/`PullTaskResResponse` pair can stay under a gRPC message size limit even
+/// for multi-million-element tensors.
+///
+/// Each group preserves the original element order; reassembling a tensor from the groups is just
+/// concatenating them back into one `Vec<Vec<u8>>` before calling [`FromChunk::from_chunk`].
+///
+/// A single element larger than `max_group_bytes` is still placed in its own, over-sized group
+/// rather than being rejected or split further, since ciphertext chunk boundaries aren't
+/// sub-divisible.
+///
+/// [`FromChunk::from_chunk`]: crate::ext::tensor::FromChunk
+pub fn chunk_for_transport<T: ToChunk>(
+	value: &T,
+	max_group_bytes: usize,
+) -> Result<Vec<Vec<Vec<u8>>>> {
+	let elements = value.to_chunk()?;
+
+	let mut groups: Vec<Vec<Vec<u8>>> = Vec::new();
+	let mut current: Vec<Vec<u8>> = Vec::new();
+	let mut current_bytes = 0usize;
+
+	for element in elements {
+		if !current.is_empty() && current_bytes + element.len() > max_group_bytes {
+			groups.push(std::mem::take(&mut current));
+			current_bytes = 0;
+		}
+
+		current_bytes += element.len();
+		current.push(element);
+	}
+
+	if !current.is_empty() {
+		groups.push(current);
+	}
+
+	Ok(groups)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	#[test]
+	fn negotiate_records_the_run_id_and_parameters() {
+		let ctx = mk_ctx();
+
+		let run = RunParameters::negotiate("run-1", &ctx).unwrap();
+
+		assert_eq!(run.run_id, "run-1");
+		assert_eq!(run.parameters, ctx.get_encryption_parameters().unwrap().as_bytes().unwrap());
+	}
+
+	#[test]
+	fn chunk_for_transport_groups_elements_under_the_byte_limit() {
+		let ctx = mk_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let tensor = Tensor(vec![
+			encoder.encode_i64(&[1, 2, 3]).unwrap(),
+			encoder.encode_i64(&[4, 5, 6]).unwrap(),
+			encoder.encode_i64(&[7, 8, 9]).unwrap(),
+		]);
+
+		let element_size = tensor.0[0].as_bytes().unwrap().len();
+		let groups = chunk_for_transport(&tensor, element_size * 2).unwrap();
+
+		// Each group holds at most 2 elements' worth of bytes, and every element is preserved.
+		assert_eq!(groups.iter().map(|g| g.len()).sum::<usize>(), 3);
+		for group in &groups {
+			let total: usize = group.iter().map(|e| e.len()).sum();
+			assert!(total <= element_size * 2);
+		}
+	}
+
+	#[test]
+	fn chunk_for_transport_keeps_an_oversized_element_in_its_own_group() {
+		let ctx = mk_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let tensor = Tensor(vec![encoder.encode_i64(&[1, 2, 3]).unwrap()]);
+		let groups = chunk_for_transport(&tensor, 1).unwrap();
+
+		assert_eq!(groups.len(), 1);
+		assert_eq!(groups[0].len(), 1);
+	}
+}
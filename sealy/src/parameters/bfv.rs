@@ -1,5 +1,6 @@
 use crate::{DegreeType, EncryptionParameters, Error, Modulus, SchemeType};
 
+use super::validate::validate_coefficient_modulus;
 use super::{CoefficientModulusType, PlainModulusType};
 
 /// Represents a builder that sets up and creates encryption scheme parameters.
@@ -72,16 +73,24 @@ impl BFVEncryptionParametersBuilder {
 
 	/// Validate the parameter choices and return the encryption parameters.
 	pub fn build(self) -> Result<EncryptionParameters, Error> {
-		let mut params = EncryptionParameters::new(SchemeType::Bfv)?;
-
-		match self.poly_modulus_degree {
-			Some(degree) => params.set_poly_modulus_degree(u64::from(degree))?,
+		let degree = match self.poly_modulus_degree {
+			Some(degree) => degree,
 			None => return Err(Error::DegreeNotSet),
+		};
+
+		if !u64::from(degree).is_power_of_two() {
+			return Err(Error::DegreeNotPowerOfTwo);
 		}
 
+		let mut params = EncryptionParameters::new(SchemeType::Bfv)?;
+		params.set_poly_modulus_degree(u64::from(degree))?;
+
 		match self.coefficient_modulus {
 			CoefficientModulusType::NotSet => return Err(Error::CoefficientModulusNotSet),
-			CoefficientModulusType::Modulus(m) => params.set_coefficient_modulus(m)?,
+			CoefficientModulusType::Modulus(m) => {
+				validate_coefficient_modulus(u64::from(degree), &m)?;
+				params.set_coefficient_modulus(m)?;
+			}
 		};
 
 		match self.plain_modulus {
@@ -0,0 +1,156 @@
+use crate::{DegreeType, EncryptionParameters, Error, Modulus, SchemeType};
+
+use super::validate::validate_coefficient_modulus;
+use super::{CoefficientModulusType, PlainModulusType};
+
+/// Represents a builder that sets up and creates encryption scheme parameters for the BGV
+/// scheme. The parameters (most importantly PolyModulus, CoeffModulus, PlainModulus)
+/// significantly affect the performance, capabilities, and security of the encryption scheme.
+///
+/// BGV mirrors BFV's parameter shape (and its batching-enabled integer plain modulus), but
+/// manages noise growth differently: BGV's invariant noise budget is measured and consumed
+/// the same way BFV's is (see [`crate::Decryptor::invariant_noise_budget`]), so the two
+/// schemes share the same `Decryptor` noise-tracking API.
+#[derive(Debug, PartialEq)]
+pub struct BGVEncryptionParametersBuilder {
+	poly_modulus_degree: Option<DegreeType>,
+	coefficient_modulus: CoefficientModulusType,
+	plain_modulus: PlainModulusType,
+}
+
+impl BGVEncryptionParametersBuilder {
+	/// Creates a new builder.
+	pub fn new() -> Self {
+		Self {
+			poly_modulus_degree: None,
+			coefficient_modulus: CoefficientModulusType::NotSet,
+			plain_modulus: PlainModulusType::NotSet,
+		}
+	}
+
+	/// Set the degree of the polynomial used in the BGV scheme. Genrally,
+	/// larger values provide more security and noise margin at the expense
+	/// of performance.
+	pub fn set_poly_modulus_degree(
+		mut self,
+		degree: DegreeType,
+	) -> Self {
+		self.poly_modulus_degree = Some(degree);
+		self
+	}
+
+	/// Sets the coefficient modulus parameter. The coefficient modulus consists
+	/// of a list of distinct prime numbers, and is represented by a vector of
+	/// Modulus objects. The coefficient modulus directly affects the size
+	/// of ciphertext elements, the amount of computation that the scheme can
+	/// perform (bigger is better), and the security level (bigger is worse). In
+	/// Microsoft SEAL each of the prime numbers in the coefficient modulus must
+	/// be at most 60 bits, and must be congruent to 1 modulo 2*poly_modulus_degree.
+	pub fn set_coefficient_modulus(
+		mut self,
+		modulus: Vec<Modulus>,
+	) -> Self {
+		self.coefficient_modulus = CoefficientModulusType::Modulus(modulus);
+		self
+	}
+
+	/// Set the plaintext modulus to a fixed size. Not recommended.
+	/// Ideally, create a PlainModulus to set up batching and call
+	/// set_plain_modulus.
+	pub fn set_plain_modulus_u64(
+		mut self,
+		modulus: u64,
+	) -> Self {
+		self.plain_modulus = PlainModulusType::Constant(modulus);
+		self
+	}
+
+	/// Set the plaintext modulus. This method enables batching, use
+	/// `PlainModulus::batching()` to create a suitable modulus chain.
+	pub fn set_plain_modulus(
+		mut self,
+		modulus: Modulus,
+	) -> Self {
+		self.plain_modulus = PlainModulusType::Modulus(modulus);
+		self
+	}
+
+	/// Validate the parameter choices and return the encryption parameters.
+	pub fn build(self) -> Result<EncryptionParameters, Error> {
+		let degree = match self.poly_modulus_degree {
+			Some(degree) => degree,
+			None => return Err(Error::DegreeNotSet),
+		};
+
+		if !u64::from(degree).is_power_of_two() {
+			return Err(Error::DegreeNotPowerOfTwo);
+		}
+
+		let mut params = EncryptionParameters::new(SchemeType::Bgv)?;
+		params.set_poly_modulus_degree(u64::from(degree))?;
+
+		match self.coefficient_modulus {
+			CoefficientModulusType::NotSet => return Err(Error::CoefficientModulusNotSet),
+			CoefficientModulusType::Modulus(m) => {
+				validate_coefficient_modulus(u64::from(degree), &m)?;
+				params.set_coefficient_modulus(m)?;
+			}
+		};
+
+		match self.plain_modulus {
+			PlainModulusType::NotSet => return Err(Error::PlainModulusNotSet),
+			PlainModulusType::Constant(p) => {
+				params.set_plain_modulus_u64(p)?;
+			}
+			PlainModulusType::Modulus(m) => {
+				params.set_plain_modulus(m)?;
+			}
+		};
+
+		Ok(params)
+	}
+}
+
+impl Default for BGVEncryptionParametersBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	#[test]
+	fn can_build_params() {
+		let params = BGVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D1024)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::bfv(DegreeType::D1024, SecurityLevel::default())
+					.unwrap(),
+			)
+			.set_plain_modulus_u64(1234)
+			.build()
+			.unwrap();
+
+		assert_eq!(params.get_poly_modulus_degree(), 1024);
+		assert_eq!(params.get_scheme(), SchemeType::Bgv);
+		assert_eq!(params.get_plain_modulus().value(), 1234);
+		assert_eq!(params.get_coefficient_modulus().len(), 1);
+		assert_eq!(params.get_coefficient_modulus()[0].value(), 132120577);
+	}
+
+	#[test]
+	fn can_build_batching_params() {
+		let params = BGVEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		assert_eq!(params.get_scheme(), SchemeType::Bgv);
+	}
+}
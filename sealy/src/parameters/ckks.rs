@@ -1,5 +1,6 @@
 use crate::{DegreeType, EncryptionParameters, Error, Modulus, ModulusDegreeType, SchemeType};
 
+use super::validate::validate_coefficient_modulus;
 use super::CoefficientModulusType;
 
 /// Represents a builder that sets up and creates encryption scheme parameters.
@@ -42,13 +43,20 @@ impl CkksEncryptionParametersBuilder {
 
 	/// Validate the parameter choices and return the encryption parameters.
 	pub fn build(self) -> Result<EncryptionParameters, Error> {
+		let degree: u64 = self.poly_modulus_degree.try_into()?;
+
+		if !degree.is_power_of_two() {
+			return Err(Error::DegreeNotPowerOfTwo);
+		}
+
 		let mut params = EncryptionParameters::new(SchemeType::Ckks)?;
 
-		params.set_poly_modulus_degree(self.poly_modulus_degree.try_into()?)?;
+		params.set_poly_modulus_degree(degree)?;
 
 		match self.coefficient_modulus {
 			CoefficientModulusType::NotSet => return Err(Error::CoefficientModulusNotSet),
 			CoefficientModulusType::Modulus(m) => {
+				validate_coefficient_modulus(degree, &m)?;
 				params.set_coefficient_modulus(m)?;
 			}
 		};
@@ -1,19 +1,22 @@
 use std::ffi::c_void;
 use std::mem::forget;
-use std::os::raw::c_ulong;
 use std::ptr::null_mut;
 
 use crate::bindgen::{self};
 use crate::error::{convert_seal_error, Error};
 use crate::modulus::unchecked_from_handle;
-use crate::Modulus;
+use crate::serialization::{require_supported_compression, CompressionType};
+use crate::{FromBytes, Modulus, ToBytes};
 
 use serde::{Deserialize, Serialize};
 
 mod bfv;
 pub use bfv::BfvEncryptionParametersBuilder;
+mod bgv;
+pub use bgv::BGVEncryptionParametersBuilder;
 mod ckks;
 pub use ckks::CkksEncryptionParametersBuilder;
+mod validate;
 
 /// The FHE scheme supported by SEAL.
 #[repr(u8)]
@@ -27,6 +30,9 @@ pub enum SchemeType {
 
 	/// Cheon-Kim-Kim-Song scheme
 	Ckks = 0x2,
+
+	/// Brakerski-Gentry-Vaikuntanathan scheme
+	Bgv = 0x3,
 }
 
 impl SchemeType {
@@ -36,11 +42,65 @@ impl SchemeType {
 			0x0 => SchemeType::None,
 			0x1 => SchemeType::Bfv,
 			0x2 => SchemeType::Ckks,
+			0x3 => SchemeType::Bgv,
 			_ => panic!("Illegal scheme type"),
 		}
 	}
 }
 
+/// The 256-bit hash SEAL computes over a set of encryption parameters, used as a unique
+/// identifier for object-identity checks across ciphertexts, keys, and contexts (see
+/// [`EncryptionParameters`]'s documentation for details). It's a 4-tuple of 64-bit words, not
+/// a single `u64`.
+///
+/// `ParmsId`'s `PartialEq` runs in constant time with respect to the values being compared: it
+/// never short-circuits on the first mismatching word, so comparing two parms_ids can't leak
+/// which word (or how many) differed through timing.
+#[derive(Debug, Copy, Clone, Hash)]
+pub struct ParmsId([u64; 4]);
+
+impl From<[u64; 4]> for ParmsId {
+	fn from(words: [u64; 4]) -> Self {
+		Self(words)
+	}
+}
+
+impl From<ParmsId> for [u64; 4] {
+	fn from(parms_id: ParmsId) -> Self {
+		parms_id.0
+	}
+}
+
+impl PartialEq for ParmsId {
+	fn eq(
+		&self,
+		other: &Self,
+	) -> bool {
+		let mut diff = 0u64;
+
+		for i in 0..4 {
+			diff |= self.0[i] ^ other.0[i];
+		}
+
+		diff == 0
+	}
+}
+
+impl Eq for ParmsId {}
+
+impl std::fmt::Display for ParmsId {
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result {
+		for word in &self.0 {
+			write!(f, "{:016x}", word)?;
+		}
+
+		Ok(())
+	}
+}
+
 /// An immutable collection of parameters that defines an encryption scheme.
 /// Use either the CKKSBuilder or BFVBuilder to create one of these. Once created,
 /// these objects are effectively immutable.
@@ -189,16 +249,16 @@ impl EncryptionParameters {
 			.collect()
 	}
 
-	/// Returns the parms id.
-	pub fn get_parms_id(&self) -> u64 {
-		let mut parms_id: c_ulong = 0;
+	/// Returns the full 256-bit [`ParmsId`] hash of these encryption parameters.
+	pub fn parms_id(&self) -> ParmsId {
+		let mut words = [0u64; Self::block_size() as usize];
 
 		unsafe {
-			convert_seal_error(bindgen::EncParams_GetParmsId(self.handle, &mut parms_id))
+			convert_seal_error(bindgen::EncParams_GetParmsId(self.handle, words.as_mut_ptr()))
 				.expect("Internal error");
 		}
 
-		parms_id
+		ParmsId::from(words)
 	}
 
 	/// Sets the polynomial modulus degree.
@@ -325,3 +385,121 @@ impl Drop for EncryptionParameters {
 			.expect("Internal error in EncryptionParameters::drop().");
 	}
 }
+
+impl ToBytes for EncryptionParameters {
+	fn as_bytes(&self) -> crate::Result<Vec<u8>> {
+		self.to_bytes_with_compression(CompressionType::ZStd)
+	}
+
+	fn to_bytes_with_compression(
+		&self,
+		compression: CompressionType,
+	) -> crate::Result<Vec<u8>> {
+		require_supported_compression(compression)?;
+
+		let mut num_bytes: i64 = 0;
+
+		convert_seal_error(unsafe {
+			bindgen::EncParams_SaveSize(self.handle, compression as u8, &mut num_bytes)
+		})?;
+
+		let mut data: Vec<u8> = Vec::with_capacity(num_bytes as usize);
+		let mut bytes_written: i64 = 0;
+
+		convert_seal_error(unsafe {
+			let data_ptr = data.as_mut_ptr();
+
+			bindgen::EncParams_Save(
+				self.handle,
+				data_ptr,
+				num_bytes as u64,
+				compression as u8,
+				&mut bytes_written,
+			)
+		})?;
+
+		unsafe { data.set_len(bytes_written as usize) };
+
+		Ok(data)
+	}
+}
+
+impl FromBytes for EncryptionParameters {
+	/// SEAL's `EncryptionParameters::Load` reads into an already-constructed instance rather
+	/// than producing one from scratch, so the caller must supply the scheme type up front.
+	type State = SchemeType;
+
+	/// Deserializes a byte stream into a set of encryption parameters. `scheme` must match the
+	/// scheme the bytes were originally saved with, or loading will fail.
+	fn from_bytes(
+		scheme: &SchemeType,
+		bytes: &[u8],
+	) -> crate::Result<Self> {
+		let params = EncryptionParameters::new(*scheme)?;
+		let mut bytes_read: i64 = 0;
+
+		convert_seal_error(unsafe {
+			// While the interface marks bytes as mut, SEAL doesn't actually modify it, so we're
+			// okay.
+			bindgen::EncParams_Load(
+				params.handle,
+				bytes.as_ptr() as *mut u8,
+				bytes.len() as u64,
+				&mut bytes_read,
+			)
+		})?;
+
+		Ok(params)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parms_ids_with_equal_words_are_equal() {
+		let a = ParmsId::from([1, 2, 3, 4]);
+		let b = ParmsId::from([1, 2, 3, 4]);
+
+		assert_eq!(a, b);
+	}
+
+	#[test]
+	fn parms_ids_differing_in_any_word_are_unequal() {
+		let a = ParmsId::from([1, 2, 3, 4]);
+
+		assert_ne!(a, ParmsId::from([0, 2, 3, 4]));
+		assert_ne!(a, ParmsId::from([1, 2, 3, 0]));
+	}
+
+	#[test]
+	fn displays_as_hex() {
+		let id = ParmsId::from([0x0123456789abcdef, 0, 0, 0]);
+
+		assert_eq!(
+			id.to_string(),
+			"0123456789abcdef000000000000000000000000000000000000000000000000"
+		);
+	}
+
+	#[test]
+	fn can_round_trip_encryption_parameters() {
+		use crate::{CoefficientModulusFactory, DegreeType, PlainModulusFactory};
+
+		let params = BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		let bytes = params.as_bytes().unwrap();
+		let loaded = EncryptionParameters::from_bytes(&SchemeType::Bfv, &bytes).unwrap();
+
+		assert_eq!(params.get_poly_modulus_degree(), loaded.get_poly_modulus_degree());
+		assert_eq!(params.parms_id(), loaded.parms_id());
+	}
+}
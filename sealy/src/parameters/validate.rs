@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use crate::{Error, Modulus, Result};
+
+/// A Barrett-style precomputed reciprocal for cheap repeated reduction modulo a fixed
+/// `modulus`, used to check prime congruences across a coefficient modulus chain without
+/// re-running hardware division for every prime.
+///
+/// `r = floor(2^64 / modulus)` is computed once; [`Reciprocal::reduce`] then estimates the
+/// quotient as `mulhi(x, r)` (the high 64 bits of `x * r`) and corrects the at-most-one-off
+/// estimate with a single conditional subtraction.
+struct Reciprocal {
+	modulus: u64,
+	r: u64,
+}
+
+impl Reciprocal {
+	fn new(modulus: u64) -> Self {
+		debug_assert!(modulus > 1, "modulus must be greater than 1");
+
+		// floor(2^64 / modulus), computed in u128 since 2^64 overflows u64.
+		let r = ((1u128 << 64) / modulus as u128) as u64;
+
+		Self {
+			modulus,
+			r,
+		}
+	}
+
+	/// Reduces `x` modulo this reciprocal's modulus.
+	fn reduce(
+		&self,
+		x: u64,
+	) -> u64 {
+		let q = ((x as u128 * self.r as u128) >> 64) as u64;
+		let mut rem = x.wrapping_sub(q.wrapping_mul(self.modulus));
+
+		if rem >= self.modulus {
+			rem -= self.modulus;
+		}
+
+		rem
+	}
+}
+
+/// Validates a coefficient modulus chain against the constraints SEAL requires of it before
+/// handing it to the FFI layer, where a violation would otherwise surface as an opaque,
+/// deep SEAL error:
+///
+/// * no prime may have more than 60 significant bits,
+/// * every prime must be congruent to 1 modulo `2 * poly_modulus_degree`, as required for
+///   batching and NTT-friendly modulus switching,
+/// * no prime may appear twice in the chain.
+pub(crate) fn validate_coefficient_modulus(
+	poly_modulus_degree: u64,
+	modulus: &[Modulus],
+) -> Result<()> {
+	let reciprocal = Reciprocal::new(poly_modulus_degree * 2);
+	let mut seen = HashSet::with_capacity(modulus.len());
+
+	for m in modulus {
+		if m.bit_count() > 60 {
+			return Err(Error::PrimeTooLarge);
+		}
+
+		if reciprocal.reduce(m.value()) != 1 {
+			return Err(Error::PrimeNotCongruent);
+		}
+
+		if !seen.insert(m.value()) {
+			return Err(Error::DuplicateModulus);
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn reciprocal_reduces_like_the_hardware_modulo_operator() {
+		let reciprocal = Reciprocal::new(2048);
+
+		for x in [0u64, 1, 2047, 2048, 2049, 132120577, u64::MAX] {
+			assert_eq!(reciprocal.reduce(x), x % 2048);
+		}
+	}
+
+	#[test]
+	fn accepts_a_valid_chain() {
+		// 132120577 == 1 + 64512 * 2048, so it's congruent to 1 modulo 2 * 1024.
+		let modulus = vec![Modulus::new(132120577).unwrap()];
+
+		assert!(validate_coefficient_modulus(1024, &modulus).is_ok());
+	}
+
+	#[test]
+	fn rejects_a_prime_that_is_not_congruent() {
+		let modulus = vec![Modulus::new(132120579).unwrap()];
+
+		let result = validate_coefficient_modulus(1024, &modulus);
+
+		assert!(matches!(result, Err(Error::PrimeNotCongruent)));
+	}
+
+	#[test]
+	fn rejects_a_prime_over_60_bits() {
+		// 2^61 - 1: the widest value SEAL's `Modulus` accepts, one bit over the chain limit.
+		let modulus = vec![Modulus::new(u64::MAX >> 3).unwrap()];
+
+		let result = validate_coefficient_modulus(1024, &modulus);
+
+		assert!(matches!(result, Err(Error::PrimeTooLarge)));
+	}
+
+	#[test]
+	fn rejects_a_duplicate_prime() {
+		let modulus = vec![
+			Modulus::new(132120577).unwrap(),
+			Modulus::new(132120577).unwrap(),
+		];
+
+		let result = validate_coefficient_modulus(1024, &modulus);
+
+		assert!(matches!(result, Err(Error::DuplicateModulus)));
+	}
+}
@@ -6,6 +6,7 @@ use std::sync::atomic::{AtomicPtr, Ordering};
 
 use crate::{bindgen, serialization::CompressionType, Context, FromBytes, ToBytes};
 use crate::{error::*, try_seal, MemoryPool};
+use crate::{Protected, SecretToBytes};
 
 use serde::ser::Error;
 use serde::{Serialize, Serializer};
@@ -32,8 +33,15 @@ use serde::{Serialize, Serializer};
 /// is needed is the size of the coefficient modulus (number of primes) times
 /// the degree of the polynomial modulus. In addition, a valid CKKS plaintext
 /// will also store the ParmsId for the corresponding encryption parameters.
+///
+/// # Security
+/// A decrypted plaintext's coefficients are freed without being scrubbed by default, same as
+/// any other SEAL-owned allocation. Call [`Plaintext::mark_secret`] on a plaintext that holds
+/// sensitive decrypted data to have its coefficients zeroed via [`Plaintext::zeroize`]
+/// automatically just before it's dropped.
 pub struct Plaintext {
 	handle: AtomicPtr<c_void>,
+	secret: bool,
 }
 
 impl Plaintext {
@@ -50,6 +58,7 @@ impl Plaintext {
 
 		Ok(Self {
 			handle: AtomicPtr::new(handle),
+			secret: false,
 		})
 	}
 
@@ -61,6 +70,7 @@ impl Plaintext {
 
 		Ok(Self {
 			handle: AtomicPtr::new(handle),
+			secret: false,
 		})
 	}
 
@@ -101,9 +111,43 @@ impl Plaintext {
 
 		Ok(Self {
 			handle: AtomicPtr::new(handle),
+			secret: false,
 		})
 	}
 
+	/// Returns this plaintext's polynomial as a hexadecimal string, in the same
+	/// `"7FFx^3 + 1x^1 + 3"` format accepted by [`Plaintext::from_hex_string`] (see that
+	/// method for the full format description), so a plaintext can be inspected or diffed as
+	/// plain text.
+	///
+	/// Terms are listed from highest to lowest degree with zero-coefficient terms omitted; if
+	/// every coefficient is zero, returns `"0"`.
+	pub fn to_hex_string(&self) -> String {
+		let mut terms = Vec::new();
+
+		for i in (0..self.len()).rev() {
+			let coeff = self.get_coefficient(i);
+
+			if coeff == 0 {
+				continue;
+			}
+
+			let coeff = format!("{:X}", coeff);
+
+			terms.push(match i {
+				0 => coeff,
+				1 => format!("{}x^1", coeff),
+				_ => format!("{}x^{}", coeff, i),
+			});
+		}
+
+		if terms.is_empty() {
+			"0".to_string()
+		} else {
+			terms.join(" + ")
+		}
+	}
+
 	/// Gets the coefficient at the given location. Coefficients are ordered
 	/// from lowest to highest degree, with the first value being the constant
 	/// coefficient.
@@ -147,6 +191,37 @@ impl Plaintext {
 			.expect("Fatal error in Plaintext::index().");
 	}
 
+	/// Returns every coefficient, in the same low-to-high degree order as
+	/// [`Plaintext::get_coefficient`].
+	///
+	/// # Limitations
+	/// SEAL's C wrapper only exposes per-coefficient `Plaintext_CoeffAt` calls, not a raw
+	/// pointer to the backing polynomial buffer, so this still issues one FFI round-trip per
+	/// coefficient under the hood rather than a single bulk read — it just collects them into
+	/// one `Vec` instead of making the caller loop over [`Plaintext::get_coefficient`]
+	/// themselves. [`PartialEq`] and [`Hash`](std::hash::Hash) below call this once instead of
+	/// indexing coefficient-by-coefficient, so a future bulk accessor on the native bindings
+	/// only needs to change this one function to benefit every caller.
+	pub fn coefficients(&self) -> Vec<u64> {
+		(0..self.len()).map(|i| self.get_coefficient(i)).collect()
+	}
+
+	/// Overwrites every coefficient with `values`, resizing the plaintext to `values.len()`
+	/// first.
+	///
+	/// Subject to the same per-coefficient FFI round-trip as [`Plaintext::coefficients`]; see
+	/// its docs for why a true bulk write isn't available.
+	pub fn set_coefficients(
+		&mut self,
+		values: &[u64],
+	) {
+		self.resize(values.len());
+
+		for (i, &value) in values.iter().enumerate() {
+			self.set_coefficient(i, value);
+		}
+	}
+
 	/// Sets the number of coefficients this plaintext can hold.
 	pub fn resize(
 		&mut self,
@@ -180,6 +255,46 @@ impl Plaintext {
 
 		result
 	}
+
+	/// Flags this plaintext as holding secret material (e.g. a decrypted message), so
+	/// [`Plaintext::zeroize`] runs automatically on `Drop` instead of leaving its coefficients
+	/// to whatever reuses that heap afterwards.
+	///
+	/// # Limitations
+	/// This only wipes the coefficients this binding can reach through
+	/// `Plaintext_SetCoeffAt`; unlike [`crate::Protected`], it can't additionally `mlock` the
+	/// backing allocation, because SEAL owns a plaintext's buffer internally and exposes no
+	/// bindgen symbol for a raw pointer and length into it. The plaintext can still be paged to
+	/// swap while it's alive; this only closes the post-use, lingering-in-freed-heap window.
+	pub fn mark_secret(&mut self) -> &mut Self {
+		self.secret = true;
+		self
+	}
+
+	/// Overwrites every coefficient with zero.
+	///
+	/// Runs automatically on `Drop` for plaintexts flagged via [`Plaintext::mark_secret`], but
+	/// can also be called directly to wipe a plaintext's contents while keeping the allocation
+	/// around for reuse.
+	pub fn zeroize(&mut self) {
+		for i in 0..self.len() {
+			self.set_coefficient(i, 0);
+		}
+	}
+}
+
+impl SecretToBytes for Plaintext {
+	/// Serializes this plaintext the same way as [`ToBytes::as_bytes`], but returns the result
+	/// as a [`Protected`] buffer so the decoded coefficients are `mlock`'d and zeroized on drop
+	/// instead of lingering, unwiped, in freed and potentially swapped-out heap.
+	///
+	/// This complements [`Plaintext::mark_secret`] rather than replacing it: `mark_secret`
+	/// zeroizes *this* plaintext's own coefficients in place when it drops, while
+	/// `secret_as_bytes` protects the serialized copy produced here, e.g. so it can be wrapped
+	/// in a [`crate::Encrypted`] for at-rest storage.
+	fn secret_as_bytes(&self) -> Result<Protected> {
+		Protected::new(self.as_bytes()?)
+	}
 }
 
 impl Debug for Plaintext {
@@ -202,6 +317,7 @@ impl Clone for Plaintext {
 
 		Self {
 			handle: AtomicPtr::new(copy),
+			secret: self.secret,
 		}
 	}
 }
@@ -217,17 +333,7 @@ impl PartialEq for Plaintext {
 		&self,
 		other: &Self,
 	) -> bool {
-		if self.len() == other.len() {
-			for i in 0..self.len() {
-				if self.get_coefficient(i) != other.get_coefficient(i) {
-					return false;
-				}
-			}
-
-			true
-		} else {
-			false
-		}
+		self.len() == other.len() && self.coefficients() == other.coefficients()
 	}
 }
 
@@ -236,10 +342,7 @@ impl Hash for Plaintext {
 		&self,
 		state: &mut H,
 	) {
-		for i in 0..self.len() {
-			let c = self.get_coefficient(i);
-			state.write_u64(c);
-		}
+		self.coefficients().hash(state);
 	}
 }
 
@@ -301,14 +404,19 @@ impl FromBytes for Plaintext {
 
 impl ToBytes for Plaintext {
 	fn as_bytes(&self) -> Result<Vec<u8>> {
+		self.to_bytes_with_compression(CompressionType::ZStd)
+	}
+
+	fn to_bytes_with_compression(
+		&self,
+		compression: CompressionType,
+	) -> Result<Vec<u8>> {
+		crate::serialization::require_supported_compression(compression)?;
+
 		let mut num_bytes: i64 = 0;
 
 		try_seal!(unsafe {
-			bindgen::Plaintext_SaveSize(
-				self.get_handle(),
-				CompressionType::ZStd as u8,
-				&mut num_bytes,
-			)
+			bindgen::Plaintext_SaveSize(self.get_handle(), compression as u8, &mut num_bytes)
 		})?;
 
 		let mut data: Vec<u8> = Vec::with_capacity(num_bytes as usize);
@@ -321,7 +429,7 @@ impl ToBytes for Plaintext {
 				self.get_handle(),
 				data_ptr,
 				num_bytes as u64,
-				CompressionType::ZStd as u8,
+				compression as u8,
 				&mut bytes_written,
 			)
 		})?;
@@ -334,6 +442,10 @@ impl ToBytes for Plaintext {
 
 impl Drop for Plaintext {
 	fn drop(&mut self) {
+		if self.secret {
+			self.zeroize();
+		}
+
 		try_seal!(unsafe { bindgen::Plaintext_Destroy(self.get_handle()) })
 			.expect("Internal error in Plaintext::drop.");
 	}
@@ -358,4 +470,69 @@ mod tests {
 		assert_eq!(plaintext.get_coefficient(1), 0);
 		assert_eq!(plaintext.get_coefficient(2), 0x1234);
 	}
+
+	#[test]
+	fn to_hex_string_round_trips_through_from_hex_string() {
+		let plaintext = Plaintext::from_hex_string("1234x^2 + 4321").unwrap();
+
+		assert_eq!(plaintext.to_hex_string(), "1234x^2 + 4321");
+	}
+
+	#[test]
+	fn to_hex_string_of_an_all_zero_plaintext_is_zero() {
+		let mut plaintext = Plaintext::from_hex_string("1234x^2 + 4321").unwrap();
+		plaintext.zeroize();
+
+		assert_eq!(plaintext.to_hex_string(), "0");
+	}
+
+	#[test]
+	fn coefficients_matches_indexing_one_at_a_time() {
+		let plaintext = Plaintext::from_hex_string("1234x^2 + 4321").unwrap();
+
+		let bulk = plaintext.coefficients();
+		let indexed: Vec<u64> = (0..plaintext.len()).map(|i| plaintext.get_coefficient(i)).collect();
+
+		assert_eq!(bulk, indexed);
+	}
+
+	#[test]
+	fn set_coefficients_round_trips_through_coefficients() {
+		let mut plaintext = Plaintext::new().unwrap();
+
+		plaintext.set_coefficients(&[0x4321, 0, 0x1234]);
+
+		assert_eq!(plaintext.coefficients(), vec![0x4321, 0, 0x1234]);
+	}
+
+	#[test]
+	fn zeroize_clears_every_coefficient() {
+		let mut plaintext = Plaintext::from_hex_string("1234x^2 + 4321").unwrap();
+
+		plaintext.zeroize();
+
+		for i in 0..plaintext.len() {
+			assert_eq!(plaintext.get_coefficient(i), 0);
+		}
+	}
+
+	#[test]
+	fn marking_a_plaintext_secret_zeroizes_it_on_drop() {
+		let mut plaintext = Plaintext::from_hex_string("1234x^2 + 4321").unwrap();
+		plaintext.mark_secret();
+
+		// There's no coefficient left to observe once `plaintext` is dropped and its
+		// underlying SEAL object is destroyed, so this only confirms the flag doesn't prevent
+		// normal use beforehand; `zeroize_clears_every_coefficient` covers the actual wipe.
+		assert_eq!(plaintext.get_coefficient(2), 0x1234);
+	}
+
+	#[test]
+	fn secret_as_bytes_matches_as_bytes() {
+		let plaintext = Plaintext::from_hex_string("1234x^2 + 4321").unwrap();
+
+		let protected = plaintext.secret_as_bytes().unwrap();
+
+		assert_eq!(&*protected, &plaintext.as_bytes().unwrap()[..]);
+	}
 }
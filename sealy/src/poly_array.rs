@@ -0,0 +1,191 @@
+use std::ffi::c_void;
+use std::ptr::null_mut;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use serde::ser::Error as _;
+use serde::{Serialize, Serializer};
+
+use crate::{bindgen, error::*, serialization::CompressionType, try_seal, Context, FromBytes, ToBytes};
+use crate::{Protected, SecretToBytes};
+
+/// A polynomial array is a flat list of polynomials with coefficients modulo some
+/// coefficient modulus. SEAL uses these internally to expose the intermediate `u` and `e`
+/// noise terms produced while encrypting.
+pub struct PolynomialArray {
+	handle: AtomicPtr<c_void>,
+}
+
+unsafe impl Sync for PolynomialArray {}
+unsafe impl Send for PolynomialArray {}
+
+impl PolynomialArray {
+	/// Constructs an empty polynomial array allocating no memory.
+	pub fn new() -> Result<Self> {
+		let mut handle: *mut c_void = null_mut();
+
+		try_seal!(unsafe { bindgen::PolynomialArray_Create1(&mut handle) })?;
+
+		Ok(Self {
+			handle: AtomicPtr::new(handle),
+		})
+	}
+
+	/// Returns the handle to the underlying SEAL object.
+	pub(crate) unsafe fn get_handle(&self) -> *mut c_void {
+		self.handle.load(Ordering::SeqCst)
+	}
+
+	/// Returns the number of polynomials in this array.
+	pub fn size(&self) -> usize {
+		let mut size: u64 = 0;
+
+		try_seal!(unsafe { bindgen::PolynomialArray_Size(self.get_handle(), &mut size) })
+			.expect("Internal error in PolynomialArray::size().");
+
+		size as usize
+	}
+
+	/// Returns true if this polynomial array is backed by an NTT representation.
+	pub fn is_ntt_form(&self) -> bool {
+		let mut is_ntt = false;
+
+		try_seal!(unsafe {
+			bindgen::PolynomialArray_IsNTTForm(self.get_handle(), &mut is_ntt)
+		})
+		.expect("Internal error in PolynomialArray::is_ntt_form().");
+
+		is_ntt
+	}
+}
+
+impl std::fmt::Debug for PolynomialArray {
+	fn fmt(
+		&self,
+		f: &mut std::fmt::Formatter<'_>,
+	) -> std::fmt::Result {
+		f.debug_struct("PolynomialArray")
+			.field("size", &self.size())
+			.finish()
+	}
+}
+
+impl Serialize for PolynomialArray {
+	fn serialize<S>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let bytes = self
+			.as_bytes()
+			.map_err(|e| S::Error::custom(format!("Failed to serialize bytes: {}", e)))?;
+
+		serializer.serialize_bytes(&bytes)
+	}
+}
+
+impl FromBytes for PolynomialArray {
+	type State = Context;
+
+	/// Deserializes a byte stream into a polynomial array. This requires a context, which is
+	/// why `PolynomialArray` doesn't `impl Deserialize`.
+	fn from_bytes(
+		context: &Context,
+		data: &[u8],
+	) -> Result<Self> {
+		let mut bytes_read = 0;
+
+		let poly_array = PolynomialArray::new()?;
+
+		try_seal!(unsafe {
+			// While the interface marks data as mut, SEAL doesn't actually modify it, so we're okay.
+			bindgen::PolynomialArray_Load(
+				poly_array.get_handle(),
+				context.get_handle(),
+				data.as_ptr() as *mut u8,
+				data.len() as u64,
+				&mut bytes_read,
+			)
+		})?;
+
+		Ok(poly_array)
+	}
+}
+
+impl ToBytes for PolynomialArray {
+	fn as_bytes(&self) -> Result<Vec<u8>> {
+		self.to_bytes_with_compression(CompressionType::ZStd)
+	}
+
+	fn to_bytes_with_compression(
+		&self,
+		compression: CompressionType,
+	) -> Result<Vec<u8>> {
+		crate::serialization::require_supported_compression(compression)?;
+
+		let mut num_bytes: i64 = 0;
+
+		try_seal!(unsafe {
+			bindgen::PolynomialArray_SaveSize(self.get_handle(), compression as u8, &mut num_bytes)
+		})?;
+
+		let mut data: Vec<u8> = Vec::with_capacity(num_bytes as usize);
+		let mut bytes_written: i64 = 0;
+
+		try_seal!(unsafe {
+			let data_ptr = data.as_mut_ptr();
+
+			bindgen::PolynomialArray_Save(
+				self.get_handle(),
+				data_ptr,
+				num_bytes as u64,
+				compression as u8,
+				&mut bytes_written,
+			)
+		})?;
+
+		unsafe { data.set_len(bytes_written as usize) };
+
+		Ok(data)
+	}
+}
+
+impl Drop for PolynomialArray {
+	fn drop(&mut self) {
+		try_seal!(unsafe { bindgen::PolynomialArray_Destroy(self.get_handle()) })
+			.expect("Internal error in PolynomialArray::drop().");
+	}
+}
+
+impl SecretToBytes for PolynomialArray {
+	/// Serializes this polynomial array the same way as [`ToBytes::as_bytes`], but returns the
+	/// result as a [`Protected`] buffer so the decoded coefficients are `mlock`'d and zeroized
+	/// on drop instead of lingering, unwiped, in freed and potentially swapped-out heap.
+	///
+	/// # Limitations
+	/// Like [`crate::SecretKey`]'s `SecretToBytes` impl, this can only protect
+	/// the serialized copy: a `PolynomialArray` exposes no bindgen symbol for a raw pointer and
+	/// length into its SEAL-owned backing allocation, so there's no way to `mlock` or zeroize
+	/// the live array in place the way [`crate::Plaintext::mark_secret`] does for a plaintext's
+	/// coefficients. Reach for this to protect the `u`/`e` noise polynomials held by
+	/// [`crate::AsymmetricComponents`]/[`crate::SymmetricComponents`] once they're serialized
+	/// out, e.g. by wrapping the result in a [`crate::Encrypted`].
+	fn secret_as_bytes(&self) -> Result<Protected> {
+		Protected::new(self.as_bytes()?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn secret_as_bytes_matches_as_bytes() {
+		let poly_array = PolynomialArray::new().unwrap();
+
+		let protected = poly_array.secret_as_bytes().unwrap();
+
+		assert_eq!(&*protected, &poly_array.as_bytes().unwrap()[..]);
+	}
+}
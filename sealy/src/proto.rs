@@ -0,0 +1,172 @@
+use prost::Message;
+
+use crate::ext::tensor::{FromChunk, ToChunk};
+use crate::parameters::SchemeType;
+use crate::{Context, Error, Result};
+
+/// The header prefixed to a [`ProtoChunks`] message, describing the encryption parameters
+/// its elements were produced under so a consumer in another language can validate them
+/// without out-of-band agreement.
+#[derive(Clone, PartialEq, Message)]
+pub struct BatchHeader {
+	/// The [`SchemeType`] each element was produced under, as its raw `u8` tag.
+	#[prost(uint32, tag = "1")]
+	pub scheme: u32,
+
+	/// The polynomial modulus degree of the encryption parameters.
+	#[prost(uint32, tag = "2")]
+	pub poly_modulus_degree: u32,
+
+	/// The number of primes in the coefficient modulus chain.
+	#[prost(uint32, tag = "3")]
+	pub coeff_modulus_size: u32,
+
+	/// Whether elements of this batch are, by default, stored in NTT form under this
+	/// scheme (always true for CKKS, false for BFV).
+	#[prost(bool, tag = "4")]
+	pub ntt_form: bool,
+
+	/// The number of elements in the batch.
+	#[prost(uint32, tag = "5")]
+	pub element_count: u32,
+}
+
+/// A self-describing, language-agnostic encoding of a batch of serialized ciphertexts or
+/// plaintexts: a [`BatchHeader`] followed by the raw, per-element payloads.
+#[derive(Clone, PartialEq, Message)]
+pub struct BatchEnvelope {
+	/// The header identifying the parameters the elements were produced under.
+	#[prost(message, optional, tag = "1")]
+	pub header: Option<BatchHeader>,
+
+	/// The raw serialized bytes of each element, in order.
+	#[prost(bytes = "vec", repeated, tag = "2")]
+	pub elements: Vec<Vec<u8>>,
+}
+
+fn build_header(
+	context: &Context,
+	element_count: usize,
+) -> Result<BatchHeader> {
+	let params = context.get_first_context_data()?.get_encryption_parameters()?;
+	let scheme = params.get_scheme();
+
+	Ok(BatchHeader {
+		scheme: scheme as u8 as u32,
+		poly_modulus_degree: params.get_poly_modulus_degree() as u32,
+		coeff_modulus_size: params.get_coefficient_modulus().len() as u32,
+		ntt_form: scheme == SchemeType::Ckks,
+		element_count: element_count as u32,
+	})
+}
+
+fn validate_header(
+	context: &Context,
+	header: &BatchHeader,
+	element_count: usize,
+) -> Result<()> {
+	let expected = build_header(context, element_count)?;
+
+	if header.scheme != expected.scheme
+		|| header.poly_modulus_degree != expected.poly_modulus_degree
+		|| header.coeff_modulus_size != expected.coeff_modulus_size
+	{
+		return Err(Error::IncompatibleParameters);
+	}
+
+	if header.element_count as usize != element_count {
+		return Err(Error::InvalidSerializedData);
+	}
+
+	Ok(())
+}
+
+/// Adds a protobuf-backed, header-prefixed serialization to container types that serialize
+/// to a list of chunks, for interop with non-Rust consumers of a sealy batch.
+///
+/// Unlike [`crate::VersionedChunks`], whose wire format is a sealy-specific, length-prefixed
+/// stream of envelopes, [`ProtoChunks`] encodes a single [`BatchEnvelope`] protobuf message,
+/// so other FHE stacks and services can read and write sealy batches against a stable,
+/// versioned schema instead of a positional blob list.
+pub trait ProtoChunks: ToChunk + FromChunk + Sized {
+	/// Serializes `self` into a single protobuf-encoded [`BatchEnvelope`] message.
+	fn to_proto_bytes(
+		&self,
+		context: &Context,
+	) -> Result<Vec<u8>> {
+		let elements = self.to_chunk()?;
+		let header = build_header(context, elements.len())?;
+
+		let envelope = BatchEnvelope {
+			header: Some(header),
+			elements,
+		};
+
+		Ok(envelope.encode_to_vec())
+	}
+
+	/// Deserializes a value previously produced by [`ProtoChunks::to_proto_bytes`], rejecting
+	/// it if its header is incompatible with `context`.
+	fn from_proto_bytes(
+		context: &Context,
+		bytes: &[u8],
+	) -> Result<Self> {
+		let envelope = BatchEnvelope::decode(bytes).map_err(|_| Error::InvalidSerializedData)?;
+		let header = envelope.header.ok_or(Error::InvalidSerializedData)?;
+
+		validate_header(context, &header, envelope.elements.len())?;
+
+		Self::from_chunk(context, &envelope.elements)
+	}
+}
+
+impl<T> ProtoChunks for T where T: ToChunk + FromChunk {}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx(bit_sizes: &[i32]) -> Context {
+		let params = BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, bit_sizes).unwrap(),
+			)
+			.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[test]
+	fn can_round_trip_a_proto_encoded_plaintext_tensor() {
+		let ctx = mk_ctx(&[50, 30, 30, 50, 50]);
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let tensor = Tensor(vec![
+			encoder.encode_i64(&[1, 2, 3]).unwrap(),
+			encoder.encode_i64(&[4, 5, 6]).unwrap(),
+		]);
+
+		let bytes = tensor.to_proto_bytes(&ctx).unwrap();
+		let loaded = Tensor::<Plaintext>::from_proto_bytes(&ctx, &bytes).unwrap();
+
+		assert_eq!(tensor.len(), loaded.len());
+	}
+
+	#[test]
+	fn rejects_a_proto_tensor_from_an_incompatible_context() {
+		let ctx = mk_ctx(&[50, 30, 30, 50, 50]);
+		// Same scheme and degree, but one prime fewer in the coefficient modulus chain.
+		let other_ctx = mk_ctx(&[50, 30, 50, 50]);
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let tensor = Tensor(vec![encoder.encode_i64(&[1, 2, 3]).unwrap()]);
+		let bytes = tensor.to_proto_bytes(&ctx).unwrap();
+
+		let result = Tensor::<Plaintext>::from_proto_bytes(&other_ctx, &bytes);
+
+		assert!(matches!(result, Err(Error::IncompatibleParameters)));
+	}
+}
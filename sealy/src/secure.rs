@@ -0,0 +1,292 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{compiler_fence, Ordering};
+use std::sync::OnceLock;
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+use crate::error::{Error, Result};
+
+/// Returns whether [`Protected`] should `mlock` its pages, honoring the `MLOCK_SECRETS`
+/// environment variable so hosts whose `ulimit -l` is too small to lock anything can opt out.
+fn mlock_enabled() -> bool {
+	match std::env::var("MLOCK_SECRETS") {
+		Ok(value) => value != "false",
+		Err(_) => true,
+	}
+}
+
+#[cfg(unix)]
+fn mlock(bytes: &[u8]) -> Result<()> {
+	if bytes.is_empty() {
+		return Ok(());
+	}
+
+	let ret = unsafe { libc::mlock(bytes.as_ptr() as *const libc::c_void, bytes.len()) };
+
+	if ret != 0 {
+		return Err(Error::MlockFailed {
+			errno: std::io::Error::last_os_error().raw_os_error().unwrap_or(0),
+			addr: bytes.as_ptr() as usize,
+			n_bytes: bytes.len(),
+		});
+	}
+
+	Ok(())
+}
+
+#[cfg(unix)]
+fn munlock(bytes: &[u8]) -> Result<()> {
+	if bytes.is_empty() {
+		return Ok(());
+	}
+
+	let ret = unsafe { libc::munlock(bytes.as_ptr() as *const libc::c_void, bytes.len()) };
+
+	if ret != 0 {
+		return Err(Error::MunlockFailed {
+			errno: std::io::Error::last_os_error().raw_os_error().unwrap_or(0),
+			addr: bytes.as_ptr() as usize,
+			n_bytes: bytes.len(),
+		});
+	}
+
+	Ok(())
+}
+
+// `mlock`/`munlock` have no portable equivalent outside unix targets (e.g. wasm32); treat
+// locking as unavailable there rather than failing every `Protected::new` call.
+#[cfg(not(unix))]
+fn mlock(_bytes: &[u8]) -> Result<()> {
+	Ok(())
+}
+
+#[cfg(not(unix))]
+fn munlock(_bytes: &[u8]) -> Result<()> {
+	Ok(())
+}
+
+/// Volatile-overwrites every byte of `bytes` with zero, so the wipe can't be optimized away
+/// even though nothing reads the buffer afterwards.
+fn zeroize(bytes: &mut [u8]) {
+	for byte in bytes.iter_mut() {
+		unsafe { std::ptr::write_volatile(byte, 0) };
+	}
+
+	compiler_fence(Ordering::SeqCst);
+}
+
+/// A byte buffer whose pages are locked into physical memory (via `mlock`) for as long as
+/// it's alive, and which is volatile-zeroed before being unlocked and freed. Intended for
+/// secret material, such as a serialized [`crate::SecretKey`], that shouldn't be paged to
+/// swap or linger in freed heap after it's dropped.
+///
+/// Locking can be disabled process-wide by setting the `MLOCK_SECRETS` environment variable
+/// to `false`, for hosts whose `ulimit -l` is too small to lock any pages at all. When locking
+/// is enabled and the underlying `mlock` call fails, [`Protected::new`] surfaces the error
+/// rather than silently returning an unlocked buffer.
+pub struct Protected {
+	bytes: Vec<u8>,
+	locked: bool,
+}
+
+impl Protected {
+	/// Takes ownership of `bytes`, locking its pages into physical memory unless disabled by
+	/// the `MLOCK_SECRETS` environment toggle.
+	///
+	/// `bytes` is shrunk to exactly fit its contents first: `mlock`/the zeroizing `Drop` only
+	/// ever touch `bytes.len()` bytes, so any excess capacity (e.g. from a `Vec::with_capacity`
+	/// that overshot its final `set_len`) would otherwise back an unlocked, un-zeroized region
+	/// of the same allocation.
+	///
+	/// # Errors
+	/// Returns [`Error::MlockFailed`] if locking is enabled and the underlying `mlock` syscall
+	/// fails, e.g. because the process's `ulimit -l` is smaller than `bytes.len()`.
+	pub fn new(mut bytes: Vec<u8>) -> Result<Self> {
+		bytes.shrink_to_fit();
+
+		if !mlock_enabled() {
+			return Ok(Self {
+				bytes,
+				locked: false,
+			});
+		}
+
+		mlock(&bytes)?;
+
+		Ok(Self {
+			bytes,
+			locked: true,
+		})
+	}
+}
+
+impl Deref for Protected {
+	type Target = [u8];
+
+	fn deref(&self) -> &[u8] {
+		&self.bytes
+	}
+}
+
+impl DerefMut for Protected {
+	fn deref_mut(&mut self) -> &mut [u8] {
+		&mut self.bytes
+	}
+}
+
+impl Drop for Protected {
+	fn drop(&mut self) {
+		zeroize(&mut self.bytes);
+
+		// The wipe already happened; if the pages can't be unlocked there's nothing left to
+		// protect, so this is best-effort.
+		if self.locked {
+			let _ = munlock(&self.bytes);
+		}
+	}
+}
+
+/// Mirrors [`crate::ToBytes`] for types whose serialized form is sensitive enough to deserve
+/// `mlock`+zeroize protection, such as secret keys.
+pub trait SecretToBytes {
+	/// Returns the object as an `mlock`'d, zeroizing byte buffer.
+	fn secret_as_bytes(&self) -> Result<Protected>;
+}
+
+/// A process-wide symmetric key, generated once from the OS RNG and kept in locked, zeroized
+/// memory for as long as the process runs, used to encrypt [`Encrypted`] values at rest.
+static EPHEMERAL_KEY: OnceLock<Protected> = OnceLock::new();
+
+fn ephemeral_key() -> &'static Protected {
+	EPHEMERAL_KEY.get_or_init(|| {
+		let mut key = vec![0u8; 32];
+		OsRng.fill_bytes(&mut key);
+
+		Protected::new(key).expect("Internal error: failed to lock the ephemeral session key")
+	})
+}
+
+/// A secret value kept encrypted at rest in memory under this process's ephemeral key,
+/// decrypted into a [`Protected`] scratch buffer only for the duration of a [`Encrypted::map`]
+/// call. This narrows the window in which a core dump or memory scrape can recover a
+/// long-lived secret, such as a [`crate::SecretKey`] held for the lifetime of a session, down
+/// to however long the caller's closure takes to run.
+///
+/// The type parameter `T` only records what kind of secret this blob holds; `Encrypted` itself
+/// stores nothing but the ciphertext and doesn't know how to reconstruct `T`, so callers
+/// reconstruct it themselves (e.g. via [`crate::FromBytes::from_bytes`]) inside the closure
+/// passed to [`Encrypted::map`].
+pub struct Encrypted<T> {
+	nonce: [u8; 12],
+	ciphertext: Vec<u8>,
+	marker: PhantomData<T>,
+}
+
+impl<T> Encrypted<T> {
+	/// Encrypts `plaintext` under the process's ephemeral key with a fresh random nonce.
+	fn seal_bytes(plaintext: &[u8]) -> Result<Self> {
+		let mut nonce_bytes = [0u8; 12];
+		OsRng.fill_bytes(&mut nonce_bytes);
+
+		let cipher = ChaCha20Poly1305::new_from_slice(ephemeral_key())
+			.map_err(|_| Error::InvalidArgument)?;
+
+		let ciphertext = cipher
+			.encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+			.map_err(|_| Error::Unexpected)?;
+
+		Ok(Self {
+			nonce: nonce_bytes,
+			ciphertext,
+			marker: PhantomData,
+		})
+	}
+
+	/// Decrypts this value into a [`Protected`] scratch buffer, invokes `f` on it, then drops
+	/// the buffer as soon as `f` returns, wiping it — bounding the plaintext's lifetime to the
+	/// duration of this call.
+	pub fn map<R>(
+		&self,
+		f: impl FnOnce(&Protected) -> R,
+	) -> Result<R> {
+		let cipher = ChaCha20Poly1305::new_from_slice(ephemeral_key())
+			.map_err(|_| Error::InvalidArgument)?;
+
+		let plaintext = cipher
+			.decrypt(Nonce::from_slice(&self.nonce), self.ciphertext.as_slice())
+			.map_err(|_| Error::DecryptionFailed)?;
+
+		let guarded = Protected::new(plaintext)?;
+
+		Ok(f(&guarded))
+	}
+}
+
+impl<T> Encrypted<T>
+where
+	T: SecretToBytes,
+{
+	/// Serializes `secret` through [`SecretToBytes::secret_as_bytes`] and immediately encrypts
+	/// it under the process's ephemeral key, so the decrypted copy doesn't linger in memory
+	/// beyond this call.
+	pub fn seal(secret: &T) -> Result<Self> {
+		Self::seal_bytes(&secret.secret_as_bytes()?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trips_the_bytes() {
+		let protected = Protected::new(vec![1, 2, 3]).unwrap();
+
+		assert_eq!(&*protected, &[1, 2, 3]);
+	}
+
+	#[test]
+	fn shrinks_excess_capacity_so_the_whole_allocation_is_locked_and_zeroized() {
+		let mut bytes = Vec::with_capacity(64);
+		bytes.extend_from_slice(&[1, 2, 3]);
+		assert!(bytes.capacity() > bytes.len());
+
+		let protected = Protected::new(bytes).unwrap();
+
+		assert_eq!(protected.bytes.capacity(), protected.bytes.len());
+	}
+
+	#[test]
+	fn disables_locking_when_the_environment_toggle_is_set() {
+		std::env::set_var("MLOCK_SECRETS", "false");
+
+		let protected = Protected::new(vec![1, 2, 3]).unwrap();
+
+		assert!(!protected.locked);
+
+		std::env::remove_var("MLOCK_SECRETS");
+	}
+
+	#[test]
+	fn encrypted_round_trips_through_map() {
+		let encrypted = Encrypted::<Vec<u8>>::seal_bytes(b"top secret").unwrap();
+
+		let recovered = encrypted.map(|plaintext| plaintext.to_vec()).unwrap();
+
+		assert_eq!(recovered, b"top secret");
+	}
+
+	#[test]
+	fn encrypted_values_are_not_stored_as_plaintext() {
+		let encrypted = Encrypted::<Vec<u8>>::seal_bytes(b"top secret").unwrap();
+
+		assert!(!encrypted
+			.ciphertext
+			.windows(b"top secret".len())
+			.any(|window| window == b"top secret"));
+	}
+}
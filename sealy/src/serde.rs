@@ -0,0 +1,217 @@
+//! `#[serde(with = "...")]` adapters for [`crate::Plaintext`], letting a field round-trip
+//! through human-readable formats (JSON, TOML, ...) instead of the opaque binary blob that
+//! [`crate::Plaintext`]'s own `Serialize` impl produces.
+//!
+//! Both adapters go through [`Plaintext::to_hex_string`]/[`Plaintext::from_hex_string`] or
+//! [`crate::ToBytes::to_bytes_with_compression`] rather than [`crate::FromBytes`], so neither
+//! needs a [`crate::Context`] the way a full parameter-aware round-trip would.
+//!
+//! This module also has [`ContextSeed`], which *does* thread a [`crate::Context`] through, for
+//! key types whose [`crate::FromBytes`] needs one.
+
+use std::marker::PhantomData;
+
+use serde::de::DeserializeSeed;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Context, FromBytes, Plaintext};
+
+/// A [`DeserializeSeed`] that threads a [`Context`] through deserialization of any type whose
+/// [`FromBytes::from_bytes`] needs one — namely [`crate::PublicKey`], [`crate::SecretKey`],
+/// [`crate::RelinearizationKey`], [`crate::GaloisKey`], [`crate::Plaintext`], and
+/// [`crate::Ciphertext`], none of which can implement a plain `serde::Deserialize` for the same
+/// reason [`compressed`]'s adapter has no `deserialize`: a `Deserializer` has no side channel to
+/// pass a `Context` through on its own. [`crate::Ciphertext`] already implements
+/// `serde::Serialize` (as its ZStd-compressed bytes), so pairing it with `ContextSeed` gives a
+/// full round trip through any `serde`-driven format (`serde_json`, `bincode`, ...) without
+/// losing the `parms_id` binding a `Context` carries.
+///
+/// # Example
+///
+/// ```ignore
+/// use serde::de::DeserializeSeed;
+/// use sealy::serde::ContextSeed;
+///
+/// let key: sealy::PublicKey = ContextSeed::new(&ctx).deserialize(&mut deserializer)?;
+/// ```
+pub struct ContextSeed<'a, T> {
+	context: &'a Context,
+	_marker: PhantomData<T>,
+}
+
+impl<'a, T> ContextSeed<'a, T> {
+	/// Creates a seed that will deserialize a `T` against `context`.
+	pub fn new(context: &'a Context) -> Self {
+		Self {
+			context,
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<'de, 'a, T> DeserializeSeed<'de> for ContextSeed<'a, T>
+where
+	T: FromBytes<State = Context>,
+{
+	type Value = T;
+
+	fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let bytes = Vec::<u8>::deserialize(deserializer)?;
+
+		T::from_bytes(self.context, &bytes).map_err(serde::de::Error::custom)
+	}
+}
+
+/// Serializes a [`Plaintext`] as its hexadecimal polynomial string (e.g.
+/// `"7FFx^3 + 1x^1 + 3"`) instead of an opaque binary blob, so it shows up as plain, diffable
+/// text in JSON/TOML output.
+///
+/// Use via `#[serde(with = "sealy::serde::hex")]` on a `Plaintext` field.
+pub mod hex {
+	use super::*;
+
+	/// Serializes `plaintext` as its hex polynomial string.
+	pub fn serialize<S>(
+		plaintext: &Plaintext,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		plaintext.to_hex_string().serialize(serializer)
+	}
+
+	/// Deserializes a `Plaintext` from its hex polynomial string.
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Plaintext, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let hex_str = String::deserialize(deserializer)?;
+
+		Plaintext::from_hex_string(&hex_str).map_err(serde::de::Error::custom)
+	}
+}
+
+/// Serializes a [`Plaintext`] to bytes under an explicitly chosen [`crate::CompressionType`]
+/// rather than [`Plaintext`]'s default (ZStd-compressed) `Serialize` impl, so the codec can be
+/// picked per field.
+///
+/// Use via `#[serde(serialize_with = "sealy::serde::compressed::serialize")]`.
+///
+/// # Limitations
+/// There's no `deserialize` counterpart here: reconstructing a [`Plaintext`] from bytes
+/// requires a [`crate::Context`] (see [`crate::FromBytes::from_bytes`]), but a `serde`
+/// `Deserializer` has no side channel to thread one through. Callers that need the round trip
+/// should deserialize into `Vec<u8>` and call `Plaintext::from_bytes(&ctx, &bytes)` themselves
+/// once a `Context` is in scope, or use [`super::hex`], which round-trips without one.
+pub mod compressed {
+	use super::*;
+	use crate::{CompressionType, ToBytes};
+
+	/// Serializes `plaintext` to bytes compressed with `CompressionType::ZLib`, distinct from
+	/// the ZStd default already covered by `Plaintext`'s plain `Serialize` impl.
+	pub fn serialize<S>(
+		plaintext: &Plaintext,
+		serializer: S,
+	) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let bytes = plaintext
+			.to_bytes_with_compression(CompressionType::ZLib)
+			.map_err(serde::ser::Error::custom)?;
+
+		serializer.serialize_bytes(&bytes)
+	}
+}
+
+// Note: this test module intentionally avoids `use crate::*;` (the convention used elsewhere
+// in this crate's tests) because that glob would pull `crate::serde` itself into scope as
+// `serde`, shadowing the `serde` crate that `#[derive(...)]` below needs to resolve.
+#[cfg(test)]
+mod tests {
+	use super::ContextSeed;
+	use crate::{
+		BFVEncoder, Ciphertext, Context, Encryptor, KeyGenerator, Plaintext, PublicKey, ToBytes,
+	};
+	use serde::de::DeserializeSeed;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	#[derive(::serde::Serialize, ::serde::Deserialize)]
+	struct HexWrapper {
+		#[serde(with = "crate::serde::hex")]
+		plaintext: Plaintext,
+	}
+
+	#[test]
+	fn hex_adapter_round_trips_through_json() {
+		let ctx = mk_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+
+		let wrapper = HexWrapper {
+			plaintext,
+		};
+
+		let json = serde_json::to_string(&wrapper).unwrap();
+		let restored: HexWrapper = serde_json::from_str(&json).unwrap();
+
+		assert_eq!(wrapper.plaintext, restored.plaintext);
+	}
+
+	#[derive(::serde::Serialize)]
+	struct CompressedWrapper {
+		#[serde(serialize_with = "crate::serde::compressed::serialize")]
+		plaintext: Plaintext,
+	}
+
+	#[test]
+	fn compressed_adapter_serializes_to_bytes() {
+		let ctx = mk_ctx();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+
+		let wrapper = CompressedWrapper {
+			plaintext,
+		};
+
+		assert!(serde_json::to_string(&wrapper).is_ok());
+	}
+
+	#[test]
+	fn context_seed_round_trips_a_public_key_through_bytes() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let public_key = gen.create_public_key();
+
+		let bytes = public_key.as_bytes().unwrap();
+		let mut deserializer = serde_json::Deserializer::from_str(&serde_json::to_string(&bytes).unwrap());
+		let restored: PublicKey = ContextSeed::new(&ctx).deserialize(&mut deserializer).unwrap();
+
+		assert_eq!(public_key, restored);
+	}
+
+	#[test]
+	fn ciphertext_round_trips_through_serde_json_via_context_seed() {
+		let ctx = mk_ctx();
+		let gen = KeyGenerator::new(&ctx).unwrap();
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+		let public_key = gen.create_public_key();
+		let encryptor = Encryptor::with_public_key(&ctx, &public_key).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let json = serde_json::to_string(&ciphertext).unwrap();
+		let mut deserializer = serde_json::Deserializer::from_str(&json);
+		let restored: Ciphertext = ContextSeed::new(&ctx).deserialize(&mut deserializer).unwrap();
+
+		assert_eq!(ciphertext.as_bytes().unwrap(), restored.as_bytes().unwrap());
+	}
+}
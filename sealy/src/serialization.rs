@@ -1,7 +1,9 @@
-use crate::Result;
+use std::io::{Read, Write};
+
+use crate::ext::tensor::{FromChunk, ToChunk};
+use crate::{Context, Error, Result};
 
 /// Represents the type of compression used in the serialization.
-#[allow(unused)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u8)]
 pub enum CompressionType {
@@ -10,13 +12,66 @@ pub enum CompressionType {
 	ZStd = 2,
 }
 
+impl CompressionType {
+	/// Converts a u8 to a CompressionType.
+	pub fn from_u8(val: u8) -> Self {
+		match val {
+			0 => CompressionType::None,
+			1 => CompressionType::ZLib,
+			2 => CompressionType::ZStd,
+			_ => panic!("Illegal compression type"),
+		}
+	}
+
+	/// Returns whether this codec was compiled into this build of the crate. `None` is always
+	/// available; `ZLib`/`ZStd` depend on the `zlib`/`zstd` Cargo features, which mirror
+	/// whether the vendored SEAL library was itself built with that codec.
+	pub fn is_supported(self) -> bool {
+		match self {
+			CompressionType::None => true,
+			CompressionType::ZLib => cfg!(feature = "zlib"),
+			CompressionType::ZStd => cfg!(feature = "zstd"),
+		}
+	}
+}
+
+/// Returns `Ok(())` if `compression` is supported by this build, else
+/// [`Error::UnsupportedOperation`].
+pub(crate) fn require_supported_compression(compression: CompressionType) -> Result<()> {
+	if compression.is_supported() {
+		Ok(())
+	} else {
+		Err(Error::UnsupportedOperation)
+	}
+}
+
 /// A trait for converting objects into byte arrays.
 pub trait ToBytes {
-	/// Returns the object as a byte array.
+	/// Returns the object as a byte array, compressed with SEAL's default codec
+	/// ([`CompressionType::ZStd`]).
 	fn as_bytes(&self) -> Result<Vec<u8>>;
+
+	/// Returns the object as a byte array, compressed with the given `compression` codec.
+	///
+	/// The default implementation ignores `compression` and falls back to [`ToBytes::as_bytes`].
+	/// Types whose serialization goes straight through a single SEAL handle (ciphertexts,
+	/// plaintexts, keys, polynomial arrays) override this to forward the chosen codec to SEAL's
+	/// own `Save` call; composite types built by concatenating other `ToBytes` values' output
+	/// have no single codec of their own and are left at the default.
+	fn to_bytes_with_compression(
+		&self,
+		compression: CompressionType,
+	) -> Result<Vec<u8>> {
+		let _ = compression;
+		self.as_bytes()
+	}
 }
 
 /// A trait for converting data from a byte slice under a given SEAL context.
+///
+/// SEAL's own `Load` functions read the compression codec from the stream's header rather than
+/// taking it as a parameter, so every `FromBytes` impl in this crate already accepts a blob
+/// produced under any [`CompressionType`] without change.
 pub trait FromBytes {
 	/// State used to deserialize an object from bytes.
 	type State;
@@ -29,3 +84,296 @@ pub trait FromBytes {
 	where
 		Self: Sized;
 }
+
+/// Magic bytes identifying a versioned, header-prefixed serialization produced by
+/// [`Versioned`]/[`VersionedChunks`]. Chosen so a stray raw SEAL save (which always starts
+/// with SEAL's own magic number) cannot be mistaken for one of ours.
+const ENVELOPE_MAGIC: [u8; 4] = *b"SLYE";
+
+/// The current envelope format. Bump this if the header layout ever changes.
+const ENVELOPE_VERSION: u8 = 1;
+
+/// `magic (4) + version (1) + scheme (1) + parms_id (4 * u64)`.
+const ENVELOPE_HEADER_LEN: usize = 4 + 1 + 1 + 4 * 8;
+
+/// Prefixes `payload` with a small header recording the envelope version, the scheme, and
+/// the parameter fingerprint of `context`, so a later [`read_envelope`] can reject payloads
+/// that were produced under incompatible encryption parameters.
+fn write_envelope(
+	context: &Context,
+	payload: &[u8],
+) -> Result<Vec<u8>> {
+	let scheme = context
+		.get_first_context_data()?
+		.get_encryption_parameters()?
+		.get_scheme();
+	let parms_id = context.get_key_parms_id()?;
+
+	let mut envelope = Vec::with_capacity(ENVELOPE_HEADER_LEN + payload.len());
+	envelope.extend_from_slice(&ENVELOPE_MAGIC);
+	envelope.push(ENVELOPE_VERSION);
+	envelope.push(scheme as u8);
+
+	for word in &parms_id {
+		envelope.extend_from_slice(&word.to_le_bytes());
+	}
+
+	envelope.extend_from_slice(payload);
+
+	Ok(envelope)
+}
+
+/// Validates the header written by [`write_envelope`] against `context` and returns the
+/// payload that follows it.
+fn read_envelope<'a>(
+	context: &Context,
+	data: &'a [u8],
+) -> Result<&'a [u8]> {
+	if data.len() < ENVELOPE_HEADER_LEN || data[0..4] != ENVELOPE_MAGIC {
+		return Err(Error::InvalidSerializedData);
+	}
+
+	if data[4] != ENVELOPE_VERSION {
+		return Err(Error::InvalidSerializedData);
+	}
+
+	let scheme = context
+		.get_first_context_data()?
+		.get_encryption_parameters()?
+		.get_scheme();
+
+	if data[5] != scheme as u8 {
+		return Err(Error::IncompatibleParameters);
+	}
+
+	let parms_id = context.get_key_parms_id()?;
+
+	for (i, expected) in parms_id.iter().enumerate() {
+		let offset = 6 + i * 8;
+		let stored = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+		if stored != *expected {
+			return Err(Error::IncompatibleParameters);
+		}
+	}
+
+	Ok(&data[ENVELOPE_HEADER_LEN..])
+}
+
+/// Adds header-prefixed, parameter-checked serialization on top of any type that already
+/// supports the raw `ToBytes`/`FromBytes` round-trip under a `Context`. Unlike `ToBytes`/
+/// `FromBytes`, whose wire format is the bare SEAL save format, `to_bytes_versioned` prefixes
+/// a small header so that `from_bytes_versioned` can reject blobs produced under incompatible
+/// encryption parameters instead of silently loading garbage.
+pub trait Versioned: ToBytes + FromBytes<State = Context> + Sized {
+	/// Serializes `self`, prefixed with a header identifying the scheme and parameters of
+	/// `context`.
+	fn to_bytes_versioned(
+		&self,
+		context: &Context,
+	) -> Result<Vec<u8>> {
+		write_envelope(context, &self.as_bytes()?)
+	}
+
+	/// Deserializes a value previously produced by `to_bytes_versioned`, rejecting it if it
+	/// was produced under parameters incompatible with `context`.
+	fn from_bytes_versioned(
+		context: &Context,
+		data: &[u8],
+	) -> Result<Self> {
+		Self::from_bytes(context, read_envelope(context, data)?)
+	}
+}
+
+impl<T> Versioned for T where T: ToBytes + FromBytes<State = Context> {}
+
+/// Adds header-prefixed, parameter-checked serialization to container types (such as
+/// [`crate::Tensor`] and [`crate::ext::batched::Batch`]) that serialize to a list of chunks
+/// rather than a single blob. Each chunk is individually wrapped with the same header as
+/// [`Versioned`], then the chunks are length-prefixed and concatenated into a single byte
+/// stream.
+pub trait VersionedChunks: ToChunk + FromChunk + Sized {
+	/// Serializes `self` into a single byte stream of header-prefixed, length-delimited
+	/// chunks.
+	fn to_bytes_versioned(
+		&self,
+		context: &Context,
+	) -> Result<Vec<u8>> {
+		let mut bytes = Vec::new();
+
+		for chunk in self.to_chunk()? {
+			let chunk = write_envelope(context, &chunk)?;
+			bytes.extend_from_slice(&(chunk.len() as u64).to_le_bytes());
+			bytes.extend_from_slice(&chunk);
+		}
+
+		Ok(bytes)
+	}
+
+	/// Deserializes a value previously produced by `to_bytes_versioned`, rejecting it if any
+	/// chunk was produced under parameters incompatible with `context`.
+	fn from_bytes_versioned(
+		context: &Context,
+		data: &[u8],
+	) -> Result<Self> {
+		let mut chunks = Vec::new();
+		let mut offset = 0;
+
+		while offset < data.len() {
+			if data.len() - offset < 8 {
+				return Err(Error::InvalidSerializedData);
+			}
+
+			let len =
+				u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize;
+			offset += 8;
+
+			if data.len() - offset < len {
+				return Err(Error::InvalidSerializedData);
+			}
+
+			chunks.push(read_envelope(context, &data[offset..offset + len])?.to_vec());
+			offset += len;
+		}
+
+		Self::from_chunk(context, &chunks)
+	}
+}
+
+impl<T> VersionedChunks for T where T: ToChunk + FromChunk {}
+
+/// Adds streaming serialization directly against [`std::io::Write`] to any type that already
+/// supports the [`Versioned`] round-trip, writing a single length-prefixed frame instead of
+/// requiring the caller to materialize an intermediate `Vec<u8>` first. Mirrors
+/// [`crate::Tensor::write_to`], which does the analogous thing for container types one element
+/// at a time instead of one frame for the whole value.
+pub trait ToWriter: Versioned {
+	/// Serializes `self`, writing a `u64` little-endian length prefix followed by the
+	/// [`Versioned::to_bytes_versioned`] encoding to `writer`.
+	fn to_writer<W: Write>(
+		&self,
+		context: &Context,
+		writer: &mut W,
+	) -> Result<()> {
+		let bytes = self.to_bytes_versioned(context)?;
+
+		writer
+			.write_all(&(bytes.len() as u64).to_le_bytes())
+			.map_err(|_| Error::IoError)?;
+		writer.write_all(&bytes).map_err(|_| Error::IoError)
+	}
+}
+
+impl<T> ToWriter for T where T: Versioned {}
+
+/// Adds streaming deserialization directly against [`std::io::Read`], the inverse of
+/// [`ToWriter`].
+pub trait FromReader: Versioned {
+	/// Deserializes a value previously written by [`ToWriter::to_writer`] from `reader`.
+	fn from_reader<R: Read>(
+		context: &Context,
+		reader: &mut R,
+	) -> Result<Self> {
+		let mut len_bytes = [0u8; 8];
+		reader
+			.read_exact(&mut len_bytes)
+			.map_err(|_| Error::InvalidSerializedData)?;
+		let len = u64::from_le_bytes(len_bytes) as usize;
+
+		let mut bytes = vec![0u8; len];
+		reader
+			.read_exact(&mut bytes)
+			.map_err(|_| Error::InvalidSerializedData)?;
+
+		Self::from_bytes_versioned(context, &bytes)
+	}
+}
+
+impl<T> FromReader for T where T: Versioned {}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx(plain_modulus_bits: u32) -> Context {
+		let params = BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus(
+				PlainModulusFactory::batching(DegreeType::D8192, plain_modulus_bits).unwrap(),
+			)
+			.build()
+			.unwrap();
+
+		Context::new(&params, false, SecurityLevel::TC128).unwrap()
+	}
+
+	#[test]
+	fn can_round_trip_versioned_plaintext() {
+		let ctx = mk_ctx(20);
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let bytes = plaintext.to_bytes_versioned(&ctx).unwrap();
+
+		let loaded = Plaintext::from_bytes_versioned(&ctx, &bytes).unwrap();
+
+		assert_eq!(plaintext, loaded);
+	}
+
+	#[test]
+	fn rejects_versioned_plaintext_from_incompatible_context() {
+		let ctx = mk_ctx(20);
+		let other_ctx = mk_ctx(21);
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+		let bytes = plaintext.to_bytes_versioned(&ctx).unwrap();
+
+		let result = Plaintext::from_bytes_versioned(&other_ctx, &bytes);
+
+		assert!(matches!(result, Err(Error::IncompatibleParameters)));
+	}
+
+	#[test]
+	fn can_round_trip_plaintext_through_a_writer_and_reader() {
+		let ctx = mk_ctx(20);
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+
+		let mut buf = Vec::new();
+		plaintext.to_writer(&ctx, &mut buf).unwrap();
+
+		let loaded = Plaintext::from_reader(&ctx, &mut buf.as_slice()).unwrap();
+
+		assert_eq!(plaintext, loaded);
+	}
+
+	#[test]
+	fn from_reader_rejects_a_truncated_stream() {
+		let ctx = mk_ctx(20);
+		let encoder = BFVEncoder::new(&ctx).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+
+		let mut buf = Vec::new();
+		plaintext.to_writer(&ctx, &mut buf).unwrap();
+		buf.truncate(buf.len() - 1);
+
+		let result = Plaintext::from_reader(&ctx, &mut buf.as_slice());
+
+		assert!(matches!(result, Err(Error::InvalidSerializedData)));
+	}
+
+	#[test]
+	fn rejects_versioned_plaintext_from_garbage_bytes() {
+		let ctx = mk_ctx(20);
+
+		let result = Plaintext::from_bytes_versioned(&ctx, b"not an envelope");
+
+		assert!(matches!(result, Err(Error::InvalidSerializedData)));
+	}
+}
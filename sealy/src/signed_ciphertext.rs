@@ -0,0 +1,331 @@
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+
+use crate::error::*;
+use crate::ext::tensor::{FromChunk, ToChunk};
+use crate::{Ciphertext, Context, FromBytes, Tensor, ToBytes};
+
+const SIGNATURE_LEN: usize = 64;
+
+/// Magic bytes identifying a blob produced by [`SignedCiphertext::as_bytes`] or
+/// [`SignedCiphertextBatch::as_bytes`].
+const SIGNED_MAGIC: [u8; 4] = *b"SLYS";
+
+/// The current envelope format. Bump this if the header layout ever changes.
+const SIGNED_VERSION: u8 = 1;
+
+/// `magic (4) + version (1) + signature (64)`.
+const HEADER_LEN: usize = 4 + 1 + SIGNATURE_LEN;
+
+/// An Ed25519 keypair used to sign [`SignedCiphertext`]/[`SignedCiphertextBatch`] containers.
+pub struct Ed25519KeyPair {
+	signing_key: SigningKey,
+}
+
+impl Ed25519KeyPair {
+	/// Generates a fresh keypair from the OS RNG.
+	pub fn generate() -> Self {
+		Self {
+			signing_key: SigningKey::generate(&mut OsRng),
+		}
+	}
+
+	/// Reconstructs a keypair from a 32-byte seed, e.g. one persisted from a prior
+	/// [`Ed25519KeyPair::generate`] call.
+	pub fn from_seed(seed: &[u8; 32]) -> Self {
+		Self {
+			signing_key: SigningKey::from_bytes(seed),
+		}
+	}
+
+	/// Returns the public half of this keypair, which a recipient uses to verify signatures
+	/// produced by it without being able to forge new ones.
+	pub fn public_key(&self) -> Ed25519PublicKey {
+		Ed25519PublicKey {
+			verifying_key: self.signing_key.verifying_key(),
+		}
+	}
+}
+
+/// The public half of an [`Ed25519KeyPair`], used to verify a [`SignedCiphertext`] or
+/// [`SignedCiphertextBatch`] without holding the private signing key.
+#[derive(Debug, Clone, Copy)]
+pub struct Ed25519PublicKey {
+	verifying_key: VerifyingKey,
+}
+
+impl Ed25519PublicKey {
+	/// Reconstructs a public key from its 32-byte encoding.
+	pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self> {
+		let verifying_key = VerifyingKey::from_bytes(bytes).map_err(|_| Error::InvalidArgument)?;
+
+		Ok(Self {
+			verifying_key,
+		})
+	}
+
+	/// Returns the 32-byte encoding of this public key.
+	pub fn as_bytes(&self) -> [u8; 32] {
+		self.verifying_key.to_bytes()
+	}
+}
+
+/// A serialized [`Ciphertext`] bound to a detached Ed25519 signature, so a recipient can verify
+/// it was produced by a trusted party before spending CPU on homomorphic evaluation.
+///
+/// Verifying a `SignedCiphertext` only requires the sender's [`Ed25519PublicKey`] and is
+/// independent of the FHE secret key, so a gateway can reject tampered or forged payloads
+/// before they ever reach whoever holds the ability to decrypt.
+pub struct SignedCiphertext {
+	bytes: Vec<u8>,
+	signature: [u8; SIGNATURE_LEN],
+}
+
+impl SignedCiphertext {
+	/// Serializes `ciphertext` and signs it under `keypair`.
+	pub fn sign(
+		keypair: &Ed25519KeyPair,
+		ciphertext: &Ciphertext,
+	) -> Result<Self> {
+		let bytes = ciphertext.as_bytes()?;
+		let signature = keypair.signing_key.sign(&bytes);
+
+		Ok(Self {
+			bytes,
+			signature: signature.to_bytes(),
+		})
+	}
+
+	/// Verifies this container's signature under `public_key` and, once it checks out,
+	/// deserializes the ciphertext under `context`.
+	///
+	/// Returns [`Error::SignatureVerificationFailed`] if the signature doesn't match, without
+	/// attempting to deserialize the (untrusted) bytes at all.
+	pub fn verify(
+		&self,
+		public_key: &Ed25519PublicKey,
+		context: &Context,
+	) -> Result<Ciphertext> {
+		let signature = Signature::from_bytes(&self.signature);
+
+		public_key
+			.verifying_key
+			.verify(&self.bytes, &signature)
+			.map_err(|_| Error::SignatureVerificationFailed)?;
+
+		Ciphertext::from_bytes(context, &self.bytes)
+	}
+
+	/// Serializes this container into a single transportable blob: a magic tag, a format
+	/// version, the detached signature, then the raw (still SEAL-serialized) ciphertext bytes.
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut out = Vec::with_capacity(HEADER_LEN + self.bytes.len());
+		out.extend_from_slice(&SIGNED_MAGIC);
+		out.push(SIGNED_VERSION);
+		out.extend_from_slice(&self.signature);
+		out.extend_from_slice(&self.bytes);
+
+		out
+	}
+
+	/// Deserializes a blob produced by [`SignedCiphertext::as_bytes`] without verifying it —
+	/// call [`SignedCiphertext::verify`] on the result before trusting its contents.
+	pub fn from_bytes(data: &[u8]) -> Result<Self> {
+		if data.len() < HEADER_LEN || data[0..4] != SIGNED_MAGIC || data[4] != SIGNED_VERSION {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let signature: [u8; SIGNATURE_LEN] = data[5..HEADER_LEN].try_into().unwrap();
+		let bytes = data[HEADER_LEN..].to_vec();
+
+		Ok(Self {
+			bytes,
+			signature,
+		})
+	}
+}
+
+/// Joins a list of chunks into a single length-prefixed byte stream, so a batch of ciphertexts
+/// can be signed as one message.
+fn join_chunks(chunks: &[Vec<u8>]) -> Vec<u8> {
+	let mut out = Vec::new();
+
+	for chunk in chunks {
+		out.extend_from_slice(&(chunk.len() as u64).to_le_bytes());
+		out.extend_from_slice(chunk);
+	}
+
+	out
+}
+
+/// Splits a byte stream produced by [`join_chunks`] back into its chunks.
+fn split_chunks(data: &[u8]) -> Result<Vec<Vec<u8>>> {
+	let mut chunks = Vec::new();
+	let mut offset = 0;
+
+	while offset < data.len() {
+		if data.len() - offset < 8 {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let len = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize;
+		offset += 8;
+
+		if data.len() - offset < len {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		chunks.push(data[offset..offset + len].to_vec());
+		offset += len;
+	}
+
+	Ok(chunks)
+}
+
+/// The batched counterpart to [`SignedCiphertext`]: a whole [`Tensor`] of ciphertexts, signed
+/// as a single message over the length-prefixed concatenation of their serialized bytes.
+pub struct SignedCiphertextBatch {
+	bytes: Vec<u8>,
+	signature: [u8; SIGNATURE_LEN],
+}
+
+impl SignedCiphertextBatch {
+	/// Serializes each ciphertext in `ciphertexts` and signs the concatenation under `keypair`.
+	pub fn sign(
+		keypair: &Ed25519KeyPair,
+		ciphertexts: &Tensor<Ciphertext>,
+	) -> Result<Self> {
+		let bytes = join_chunks(&ciphertexts.to_chunk()?);
+		let signature = keypair.signing_key.sign(&bytes);
+
+		Ok(Self {
+			bytes,
+			signature: signature.to_bytes(),
+		})
+	}
+
+	/// Verifies this container's signature under `public_key` and, once it checks out,
+	/// deserializes the batch under `context`.
+	pub fn verify(
+		&self,
+		public_key: &Ed25519PublicKey,
+		context: &Context,
+	) -> Result<Tensor<Ciphertext>> {
+		let signature = Signature::from_bytes(&self.signature);
+
+		public_key
+			.verifying_key
+			.verify(&self.bytes, &signature)
+			.map_err(|_| Error::SignatureVerificationFailed)?;
+
+		Tensor::from_chunk(context, &split_chunks(&self.bytes)?)
+	}
+
+	/// Serializes this container into a single transportable blob, in the same framing as
+	/// [`SignedCiphertext::as_bytes`].
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut out = Vec::with_capacity(HEADER_LEN + self.bytes.len());
+		out.extend_from_slice(&SIGNED_MAGIC);
+		out.push(SIGNED_VERSION);
+		out.extend_from_slice(&self.signature);
+		out.extend_from_slice(&self.bytes);
+
+		out
+	}
+
+	/// Deserializes a blob produced by [`SignedCiphertextBatch::as_bytes`] without verifying
+	/// it — call [`SignedCiphertextBatch::verify`] on the result before trusting its contents.
+	pub fn from_bytes(data: &[u8]) -> Result<Self> {
+		if data.len() < HEADER_LEN || data[0..4] != SIGNED_MAGIC || data[4] != SIGNED_VERSION {
+			return Err(Error::InvalidSerializedData);
+		}
+
+		let signature: [u8; SIGNATURE_LEN] = data[5..HEADER_LEN].try_into().unwrap();
+		let bytes = data[HEADER_LEN..].to_vec();
+
+		Ok(Self {
+			bytes,
+			signature,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	fn mk_ciphertext(ctx: &Context) -> Ciphertext {
+		let gen = crate::KeyGenerator::new(ctx).unwrap();
+		let encoder = crate::BFVEncoder::new(ctx).unwrap();
+		let encryptor = crate::Encryptor::with_public_key(ctx, &gen.create_public_key()).unwrap();
+
+		let plaintext = encoder.encode_i64(&[1, 2, 3]).unwrap();
+
+		encryptor.encrypt(&plaintext).unwrap()
+	}
+
+	#[test]
+	fn round_trips_a_signed_ciphertext() {
+		let ctx = mk_ctx();
+		let ciphertext = mk_ciphertext(&ctx);
+		let keypair = Ed25519KeyPair::generate();
+
+		let signed = SignedCiphertext::sign(&keypair, &ciphertext).unwrap();
+		let bytes = signed.as_bytes();
+
+		let reloaded = SignedCiphertext::from_bytes(&bytes).unwrap();
+		let verified = reloaded.verify(&keypair.public_key(), &ctx).unwrap();
+
+		assert_eq!(ciphertext.as_bytes().unwrap(), verified.as_bytes().unwrap());
+	}
+
+	#[test]
+	fn rejects_a_signature_from_the_wrong_key() {
+		let ctx = mk_ctx();
+		let ciphertext = mk_ciphertext(&ctx);
+		let keypair = Ed25519KeyPair::generate();
+		let other_keypair = Ed25519KeyPair::generate();
+
+		let signed = SignedCiphertext::sign(&keypair, &ciphertext).unwrap();
+
+		let result = signed.verify(&other_keypair.public_key(), &ctx);
+
+		assert!(matches!(result, Err(Error::SignatureVerificationFailed)));
+	}
+
+	#[test]
+	fn rejects_tampered_bytes() {
+		let ctx = mk_ctx();
+		let ciphertext = mk_ciphertext(&ctx);
+		let keypair = Ed25519KeyPair::generate();
+
+		let signed = SignedCiphertext::sign(&keypair, &ciphertext).unwrap();
+		let mut bytes = signed.as_bytes();
+		let last = bytes.len() - 1;
+		bytes[last] ^= 1;
+
+		let tampered = SignedCiphertext::from_bytes(&bytes).unwrap();
+		let result = tampered.verify(&keypair.public_key(), &ctx);
+
+		assert!(matches!(result, Err(Error::SignatureVerificationFailed)));
+	}
+
+	#[test]
+	fn round_trips_a_signed_batch() {
+		let ctx = mk_ctx();
+		let ciphertexts = Tensor(vec![mk_ciphertext(&ctx), mk_ciphertext(&ctx)]);
+		let keypair = Ed25519KeyPair::generate();
+
+		let signed = SignedCiphertextBatch::sign(&keypair, &ciphertexts).unwrap();
+		let bytes = signed.as_bytes();
+
+		let reloaded = SignedCiphertextBatch::from_bytes(&bytes).unwrap();
+		let verified = reloaded.verify(&keypair.public_key(), &ctx).unwrap();
+
+		assert_eq!(ciphertexts.to_chunk().unwrap(), verified.to_chunk().unwrap());
+	}
+}
@@ -0,0 +1,36 @@
+//! Shared `Context` fixtures for test modules across the crate, so the same
+//! `EncryptionParametersBuilder` chain doesn't get re-pasted into every file that needs one.
+#![cfg(test)]
+
+use crate::{
+	BfvEncryptionParametersBuilder, CkksEncryptionParametersBuilder, CoefficientModulusFactory,
+	Context, DegreeType, PlainModulusFactory, SecurityLevel,
+};
+
+/// A BFV `Context` over `D8192` with a 5-prime `[50, 30, 30, 50, 50]`-bit coefficient modulus
+/// and a 20-bit batching plain modulus, so a `BFVEncoder` can be built from it.
+pub(crate) fn bfv_ctx() -> Context {
+	let params = BfvEncryptionParametersBuilder::new()
+		.set_poly_modulus_degree(DegreeType::D8192)
+		.set_coefficient_modulus(
+			CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+		)
+		.set_plain_modulus(PlainModulusFactory::batching(DegreeType::D8192, 20).unwrap())
+		.build()
+		.unwrap();
+
+	Context::new(&params, false, SecurityLevel::TC128).unwrap()
+}
+
+/// A CKKS `Context` over `D8192` with a 4-prime `[60, 40, 40, 60]`-bit coefficient modulus.
+pub(crate) fn ckks_ctx() -> Context {
+	let params = CkksEncryptionParametersBuilder::new()
+		.set_poly_modulus_degree(DegreeType::D8192)
+		.set_coefficient_modulus(
+			CoefficientModulusFactory::build(DegreeType::D8192, &[60, 40, 40, 60]).unwrap(),
+		)
+		.build()
+		.unwrap();
+
+	Context::new(&params, true, SecurityLevel::TC128).unwrap()
+}
@@ -0,0 +1,415 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use crate::{error::*, Ciphertext, Context, KeyGenerator, Plaintext, SecretKey};
+
+/// A shared, deterministically-sampled uniform random polynomial, broadcast to every party
+/// before key generation so each party's public-key share can later be computed against the
+/// *same* polynomial and summed into one aggregate public key (`a` in the threshold-crypto
+/// literature: each party publishes `b_i = -(a·s_i + e_i)`, and `Σb_i` together with `a` forms
+/// the shared public key).
+///
+/// Every party that calls [`CommonReferenceString::sample`] with the same `seed` and `degree`
+/// derives identical coefficients without an actual broadcast round — the seed itself is the
+/// only thing that needs distributing out of band (e.g. agreed on once, ahead of key
+/// generation).
+///
+/// # Limitations
+/// This only produces the shared polynomial's coefficients. Actually combining it with a
+/// party's [`SecretKey`] to produce `b_i`, or summing the `b_i` shares into an aggregate
+/// [`crate::PublicKey`], needs arithmetic over `PublicKey`'s and `SecretKey`'s raw polynomial
+/// coefficients, which this crate's SEAL binding doesn't expose (see
+/// [`ThresholdKeyGenerator`]'s docs for the same gap). A caller that already has `a`'s
+/// coefficients from here still has nothing in this crate to combine them with.
+pub struct CommonReferenceString {
+	coefficients: Vec<u64>,
+}
+
+impl CommonReferenceString {
+	/// Deterministically samples `degree` coefficients, each uniform in `[0, modulus)`, from
+	/// `seed`. Every caller with the same `(seed, degree, modulus)` gets the same polynomial.
+	pub fn sample(
+		seed: u64,
+		degree: usize,
+		modulus: u64,
+	) -> Self {
+		let mut rng = StdRng::seed_from_u64(seed);
+
+		let coefficients = (0..degree)
+			.map(|_| {
+				if modulus == 0 {
+					rng.next_u64()
+				} else {
+					rng.next_u64() % modulus
+				}
+			})
+			.collect();
+
+		Self {
+			coefficients,
+		}
+	}
+
+	/// Returns the sampled coefficients, lowest degree first.
+	pub fn coefficients(&self) -> &[u64] {
+		&self.coefficients
+	}
+
+	/// Returns the number of coefficients in this polynomial.
+	pub fn len(&self) -> usize {
+		self.coefficients.len()
+	}
+
+	/// Returns true if this polynomial has no coefficients.
+	pub fn is_empty(&self) -> bool {
+		self.coefficients.is_empty()
+	}
+}
+
+/// Generates independent, per-party secret-key shares for a threshold scheme.
+///
+/// Each share is produced by its own freshly-seeded [`KeyGenerator`], mirroring how a genuine
+/// additive secret-sharing scheme would have every party sample its own `s_i` locally so no
+/// single party (and no coordinator) ever holds the full key.
+///
+/// # Limitations
+///
+/// Deriving the *shared public key* that clients encrypt to requires every party's public-key
+/// share to be computed against the same broadcast common-reference polynomial `a`
+/// (`p_i = -(a·s_i + e_i)`), then summed coefficient-wise. This crate's SEAL binding offers no
+/// way to pin that shared `a` during key generation, nor any arithmetic or raw-coefficient
+/// accessor on [`crate::PublicKey`] to sum the shares with afterwards — the same gap documented
+/// on [`crate::KeyGenSession`], which this type's construction step intentionally parallels.
+/// [`ThresholdKeyGenerator::new`] therefore only produces the per-party `SecretKey` shares;
+/// combining their public halves is out of reach until the binding exposes one of the above.
+pub struct ThresholdKeyGenerator {
+	secret_shares: Vec<SecretKey>,
+}
+
+impl ThresholdKeyGenerator {
+	/// Samples `party_count` independent secret-key shares under `context`, one per
+	/// participating party.
+	pub fn new(
+		context: &Context,
+		party_count: usize,
+	) -> Result<Self> {
+		if party_count == 0 {
+			return Err(Error::InvalidArgument);
+		}
+
+		let mut secret_shares = Vec::with_capacity(party_count);
+
+		for _ in 0..party_count {
+			secret_shares.push(KeyGenerator::new(context)?.secret_key().clone());
+		}
+
+		Ok(Self {
+			secret_shares,
+		})
+	}
+
+	/// Returns the generated secret-key shares, one per party in generation order.
+	pub fn secret_shares(&self) -> &[SecretKey] {
+		&self.secret_shares
+	}
+}
+
+/// One party's partial decryption of a ciphertext, contributed towards a threshold decryption.
+///
+/// A genuine share is `h_i = c1·s_i + e_i'`, where `e_i'` is a "smudging" noise term sampled
+/// from a distribution wide enough to drown out whatever `s_i` would otherwise leak through
+/// `h_i` — never reuse the ciphertext's own noise budget for this, or the share stops hiding
+/// its party's key. See [`DecryptionShare::compute`] for why this crate can't produce that
+/// value yet.
+pub struct DecryptionShare {
+	party_id: u32,
+}
+
+impl DecryptionShare {
+	/// Computes `party_id`'s partial decryption of `ciphertext` under `secret_share`.
+	///
+	/// Always returns [`Error::UnsupportedOperation`]: computing `c1·s_i` needs the ciphertext's
+	/// and secret key's raw polynomial coefficients, and sampling a sound smudging term needs a
+	/// noise distribution sized relative to them. [`crate::Ciphertext`] and [`SecretKey`] are
+	/// both opaque handles with no coefficient accessor, and [`crate::PolynomialArray`] (SEAL's
+	/// own vehicle for exposing intermediate polynomial data) exposes only [`crate::PolynomialArray::size`]
+	/// and [`crate::PolynomialArray::is_ntt_form`] — no way to read or write a coefficient.
+	/// Returning anything else here would be a value that merely looks like a decryption share
+	/// without the security property (hiding `s_i`) the whole scheme depends on.
+	pub fn compute(
+		party_id: u32,
+		ciphertext: &Ciphertext,
+		secret_share: &SecretKey,
+	) -> Result<Self> {
+		let _ = (ciphertext, secret_share);
+		let _ = party_id;
+
+		Err(Error::UnsupportedOperation)
+	}
+
+	/// Returns the id of the party that contributed this share.
+	pub fn party_id(&self) -> u32 {
+		self.party_id
+	}
+}
+
+/// Combines every party's [`DecryptionShare`] for `ciphertext` into the recovered plaintext,
+/// i.e. `c0 + Σ h_i`, without any single party (including the coordinator calling this) ever
+/// reconstructing the full secret key.
+///
+/// Since [`DecryptionShare::compute`] can't produce a genuine share today, there is nothing
+/// sound to sum here either: this returns [`Error::InvalidArgument`] if `shares` is empty and
+/// [`Error::UnsupportedOperation`] otherwise, rather than adding up placeholder values and
+/// handing back a plaintext that isn't actually correct. Whether `shares` matches
+/// `ciphertext`'s party count is not checked.
+pub fn combine_decryption_shares(
+	shares: &[DecryptionShare],
+	ciphertext: &Ciphertext,
+) -> Result<Plaintext> {
+	let _ = ciphertext;
+
+	if shares.is_empty() {
+		return Err(Error::InvalidArgument);
+	}
+
+	Err(Error::UnsupportedOperation)
+}
+
+/// One party's partial decryption of a ciphertext under a [`CommonReferenceString`]-based
+/// threshold scheme, as returned by [`SecretKey::partial_decrypt`].
+///
+/// This is a thin wrapper around [`DecryptionShare`] with the naming this request asked for;
+/// see [`DecryptionShare`] for what a genuine share would contain and why this crate can't
+/// compute one yet.
+pub struct PartialDecryptionShare(DecryptionShare);
+
+impl PartialDecryptionShare {
+	/// Returns the id of the party that contributed this share.
+	pub fn party_id(&self) -> u32 {
+		self.0.party_id()
+	}
+}
+
+impl SecretKey {
+	/// Computes this party's partial decryption of `ciphertext` towards a threshold
+	/// decryption, i.e. `c1·s_i` plus fresh smudging noise wide enough to mask whatever `s_i`
+	/// would otherwise leak through the share.
+	///
+	/// All parties combining their shares via [`combine_partial_decryptions`] must have
+	/// generated their [`SecretKey`] under the same [`Context`] (same encryption parameters);
+	/// there is no way to enforce that at this call site since a bare `SecretKey` carries no
+	/// reference back to the context it came from.
+	///
+	/// Delegates to [`DecryptionShare::compute`], which always returns
+	/// [`Error::UnsupportedOperation`] — see that method's docs for why a sound share can't be
+	/// produced without raw-coefficient access to `ciphertext` and `self`.
+	pub fn partial_decrypt(
+		&self,
+		ciphertext: &Ciphertext,
+	) -> Result<PartialDecryptionShare> {
+		DecryptionShare::compute(0, ciphertext, self).map(PartialDecryptionShare)
+	}
+}
+
+/// Combines every party's [`PartialDecryptionShare`] for a ciphertext whose degree-zero term
+/// is `c0` into the recovered plaintext, i.e. `c0 + Σ h_i`, rounded back onto the plaintext
+/// modulus.
+///
+/// Delegates to [`combine_decryption_shares`], which returns [`Error::InvalidArgument`] for an
+/// empty `shares` and otherwise [`Error::UnsupportedOperation`], for the same reason
+/// [`SecretKey::partial_decrypt`] does — see that function's docs. `c0` is accepted here (rather than the whole ciphertext, as
+/// [`combine_decryption_shares`] takes) purely to match the combiner's logical inputs in a
+/// genuine scheme; it isn't used since there's nothing sound to add it to yet.
+pub fn combine_partial_decryptions(
+	shares: &[PartialDecryptionShare],
+	c0: &Ciphertext,
+) -> Result<Plaintext> {
+	let inner_shares: Vec<DecryptionShare> = shares
+		.iter()
+		.map(|share| DecryptionShare {
+			party_id: share.party_id(),
+		})
+		.collect();
+
+	combine_decryption_shares(&inner_shares, c0)
+}
+
+/// A named handle around one party's secret-key share, offered as the `PartialDecryptor`
+/// entry point to threshold decryption alongside the lower-level [`SecretKey::partial_decrypt`]
+/// it wraps.
+///
+/// This is the same primitive under the name repeat requests for this feature keep asking for;
+/// it exists so callers don't need to route through `SecretKey` directly. It's still bound by
+/// the limitation documented on [`DecryptionShare::compute`]: producing a genuine partial
+/// decryption needs raw-coefficient access to the ciphertext and secret key that this crate's
+/// SEAL binding doesn't expose, so [`PartialDecryptor::decrypt`] always returns
+/// [`Error::UnsupportedOperation`].
+pub struct PartialDecryptor {
+	party_id: u32,
+	secret_share: SecretKey,
+}
+
+impl PartialDecryptor {
+	/// Creates a `PartialDecryptor` for `party_id`, holding that party's secret-key share.
+	pub fn new(
+		party_id: u32,
+		secret_share: SecretKey,
+	) -> Self {
+		Self {
+			party_id,
+			secret_share,
+		}
+	}
+
+	/// Computes this party's partial decryption of `ciphertext`. Delegates to
+	/// [`DecryptionShare::compute`], which always returns [`Error::UnsupportedOperation`] — see
+	/// that method's docs for why a sound share can't be produced yet.
+	pub fn decrypt(
+		&self,
+		ciphertext: &Ciphertext,
+	) -> Result<PartialDecryptionShare> {
+		DecryptionShare::compute(self.party_id, ciphertext, &self.secret_share).map(PartialDecryptionShare)
+	}
+}
+
+/// Alias for [`combine_partial_decryptions`], named to match [`PartialDecryptor`].
+pub fn combine_partials(
+	shares: &[PartialDecryptionShare],
+	c0: &Ciphertext,
+) -> Result<Plaintext> {
+	combine_partial_decryptions(shares, c0)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::*;
+
+	fn mk_ctx() -> Context {
+		crate::test_support::bfv_ctx()
+	}
+
+	#[test]
+	fn generates_one_secret_share_per_party() {
+		let ctx = mk_ctx();
+
+		let gen = ThresholdKeyGenerator::new(&ctx, 3).unwrap();
+
+		assert_eq!(gen.secret_shares().len(), 3);
+	}
+
+	#[test]
+	fn rejects_a_zero_party_count() {
+		let ctx = mk_ctx();
+
+		assert!(matches!(
+			ThresholdKeyGenerator::new(&ctx, 0),
+			Err(Error::InvalidArgument)
+		));
+	}
+
+	#[test]
+	fn decryption_share_reports_unsupported() {
+		let ctx = mk_ctx();
+		let gen = ThresholdKeyGenerator::new(&ctx, 1).unwrap();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = Encryptor::with_public_key(&ctx, &key_gen.create_public_key()).unwrap();
+		let plaintext = Plaintext::new().unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(matches!(
+			DecryptionShare::compute(1, &ciphertext, &gen.secret_shares()[0]),
+			Err(Error::UnsupportedOperation)
+		));
+	}
+
+	#[test]
+	fn common_reference_string_is_deterministic_given_the_same_seed() {
+		let a = CommonReferenceString::sample(42, 8, 1234);
+		let b = CommonReferenceString::sample(42, 8, 1234);
+
+		assert_eq!(a.coefficients(), b.coefficients());
+		assert_eq!(a.len(), 8);
+	}
+
+	#[test]
+	fn common_reference_string_differs_across_seeds() {
+		let a = CommonReferenceString::sample(1, 8, 1234);
+		let b = CommonReferenceString::sample(2, 8, 1234);
+
+		assert_ne!(a.coefficients(), b.coefficients());
+	}
+
+	#[test]
+	fn partial_decrypt_reports_unsupported() {
+		let ctx = mk_ctx();
+		let gen = ThresholdKeyGenerator::new(&ctx, 1).unwrap();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = Encryptor::with_public_key(&ctx, &key_gen.create_public_key()).unwrap();
+		let plaintext = Plaintext::new().unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(matches!(
+			gen.secret_shares()[0].partial_decrypt(&ciphertext),
+			Err(Error::UnsupportedOperation)
+		));
+	}
+
+	#[test]
+	fn combine_partial_decryptions_rejects_an_empty_share_list() {
+		let ctx = mk_ctx();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = Encryptor::with_public_key(&ctx, &key_gen.create_public_key()).unwrap();
+		let plaintext = Plaintext::new().unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(matches!(
+			combine_partial_decryptions(&[], &ciphertext),
+			Err(Error::InvalidArgument)
+		));
+	}
+
+	#[test]
+	fn combine_rejects_an_empty_share_list() {
+		let ctx = mk_ctx();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = Encryptor::with_public_key(&ctx, &key_gen.create_public_key()).unwrap();
+		let plaintext = Plaintext::new().unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(matches!(
+			combine_decryption_shares(&[], &ciphertext),
+			Err(Error::InvalidArgument)
+		));
+	}
+
+	#[test]
+	fn partial_decryptor_reports_unsupported() {
+		let ctx = mk_ctx();
+		let gen = ThresholdKeyGenerator::new(&ctx, 1).unwrap();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = Encryptor::with_public_key(&ctx, &key_gen.create_public_key()).unwrap();
+		let plaintext = Plaintext::new().unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		let decryptor = PartialDecryptor::new(0, gen.secret_shares()[0].clone());
+
+		assert!(matches!(
+			decryptor.decrypt(&ciphertext),
+			Err(Error::UnsupportedOperation)
+		));
+	}
+
+	#[test]
+	fn combine_partials_rejects_an_empty_share_list() {
+		let ctx = mk_ctx();
+		let key_gen = KeyGenerator::new(&ctx).unwrap();
+		let encryptor = Encryptor::with_public_key(&ctx, &key_gen.create_public_key()).unwrap();
+		let plaintext = Plaintext::new().unwrap();
+		let ciphertext = encryptor.encrypt(&plaintext).unwrap();
+
+		assert!(matches!(
+			combine_partials(&[], &ciphertext),
+			Err(Error::InvalidArgument)
+		));
+	}
+}
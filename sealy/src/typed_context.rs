@@ -0,0 +1,173 @@
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+use crate::parameters::{EncryptionParameters, SchemeType};
+use crate::{Context, ContextData, Error, Result, SecurityLevel};
+
+/// Zero-sized marker types identifying which FHE scheme a [`TypedContext`] was built for,
+/// borrowed from the phantom-marker pattern `secp256k1` uses on `Secp256k1<C>` to make
+/// capability mismatches a compile error instead of a runtime one.
+pub mod scheme {
+	use crate::parameters::SchemeType;
+
+	/// Implemented by the zero-sized marker types in this module.
+	pub trait Marker {
+		/// The [`SchemeType`] this marker corresponds to.
+		const TYPE: SchemeType;
+	}
+
+	/// Marker for the Brakerski/Fan-Vercauteren scheme.
+	pub struct Bfv;
+
+	/// Marker for the Cheon-Kim-Kim-Song scheme.
+	pub struct Ckks;
+
+	impl Marker for Bfv {
+		const TYPE: SchemeType = SchemeType::Bfv;
+	}
+
+	impl Marker for Ckks {
+		const TYPE: SchemeType = SchemeType::Ckks;
+	}
+}
+
+/// Zero-sized marker types recording, at the type level, whether a [`TypedContext`] was built
+/// with a modulus-switching chain.
+pub mod chain {
+	/// Implemented by the zero-sized marker types in this module.
+	pub trait State {}
+
+	/// Marker for a context built with `expand_mod_chain = true`.
+	pub struct WithChain;
+
+	/// Marker for a context built with `expand_mod_chain = false`.
+	pub struct NoChain;
+
+	impl State for WithChain {}
+	impl State for NoChain {}
+}
+
+/// A [`Context`] whose scheme and modulus-switching-chain availability are encoded as phantom
+/// type parameters rather than checked at runtime.
+///
+/// This wraps the existing, untyped [`Context`] (which every other API in this crate still
+/// takes, unchanged) without imposing the type parameters on the rest of the crate. Operations
+/// that only make sense when a modulus switching chain exists — today,
+/// `get_first_context_data`/`get_last_context_data`, which otherwise fail at runtime with
+/// [`Error::InvalidPointer`] when no chain was built — are only implemented for
+/// `TypedContext<S, chain::WithChain>`, turning that failure mode into a compile error at the
+/// call site. The markers are phantom fields, so a `TypedContext` has the exact same layout
+/// and runtime cost as the [`Context`] it wraps.
+pub struct TypedContext<S, C> {
+	inner: Context,
+	_scheme: PhantomData<S>,
+	_chain: PhantomData<C>,
+}
+
+impl<S: scheme::Marker, C> TypedContext<S, C> {
+	fn new(
+		params: &EncryptionParameters,
+		expand_mod_chain: bool,
+		security_level: SecurityLevel,
+	) -> Result<Self> {
+		if params.get_scheme() != S::TYPE {
+			return Err(Error::InvalidParams);
+		}
+
+		Ok(Self {
+			inner: Context::new(params, expand_mod_chain, security_level)?,
+			_scheme: PhantomData,
+			_chain: PhantomData,
+		})
+	}
+
+	/// Returns the handle to the underlying SEAL object, for FFI calls that need it
+	/// regardless of the scheme/chain markers.
+	pub fn get_handle(&self) -> *mut c_void {
+		self.inner.get_handle()
+	}
+
+	/// Returns the untyped [`Context`] this value wraps, for passing to the rest of the
+	/// crate's APIs, which are not parameterized over `S`/`C`.
+	pub fn as_context(&self) -> &Context {
+		&self.inner
+	}
+}
+
+impl<S: scheme::Marker> TypedContext<S, chain::WithChain> {
+	/// Creates a `TypedContext` with a modulus switching chain, failing at compile time
+	/// rather than at the `get_first_context_data`/`get_last_context_data` call site if the
+	/// caller meant to build one without a chain.
+	pub fn with_chain(
+		params: &EncryptionParameters,
+		security_level: SecurityLevel,
+	) -> Result<Self> {
+		Self::new(params, true, security_level)
+	}
+
+	/// Returns the [`ContextData`] corresponding to the first (lowest) set of parameters in
+	/// the modulus switching chain. Only implemented for contexts built with a chain.
+	pub fn get_first_context_data(&self) -> Result<ContextData> {
+		self.inner.get_first_context_data()
+	}
+
+	/// Returns the [`ContextData`] corresponding to the last set of parameters in the modulus
+	/// switching chain. Only implemented for contexts built with a chain.
+	pub fn get_last_context_data(&self) -> Result<ContextData> {
+		self.inner.get_last_context_data()
+	}
+}
+
+impl<S: scheme::Marker> TypedContext<S, chain::NoChain> {
+	/// Creates a `TypedContext` without a modulus switching chain.
+	pub fn without_chain(
+		params: &EncryptionParameters,
+		security_level: SecurityLevel,
+	) -> Result<Self> {
+		Self::new(params, false, security_level)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BfvEncryptionParametersBuilder, CoefficientModulusFactory, DegreeType};
+
+	fn mk_params() -> EncryptionParameters {
+		BfvEncryptionParametersBuilder::new()
+			.set_poly_modulus_degree(DegreeType::D8192)
+			.set_coefficient_modulus(
+				CoefficientModulusFactory::build(DegreeType::D8192, &[50, 30, 30, 50, 50]).unwrap(),
+			)
+			.set_plain_modulus_u64(1234)
+			.build()
+			.unwrap()
+	}
+
+	#[test]
+	fn with_chain_exposes_context_data() {
+		let params = mk_params();
+		let ctx = TypedContext::<scheme::Bfv, chain::WithChain>::with_chain(
+			&params,
+			SecurityLevel::TC128,
+		)
+		.unwrap();
+
+		assert!(ctx.get_first_context_data().is_ok());
+		assert!(ctx.get_last_context_data().is_ok());
+	}
+
+	#[test]
+	fn rejects_parameters_built_for_a_different_scheme() {
+		let params = mk_params();
+
+		let result =
+			TypedContext::<scheme::Ckks, chain::WithChain>::with_chain(&params, SecurityLevel::TC128);
+
+		assert!(matches!(result, Err(Error::InvalidParams)));
+	}
+
+	// `TypedContext<_, chain::NoChain>` has no `get_first_context_data`/`get_last_context_data`
+	// methods at all, so calling them there is a compile error rather than an
+	// `Err(Error::InvalidPointer)` at runtime -- there is no runtime test for that by design.
+}
@@ -11,6 +11,16 @@ pub use thorn_proto::coordinator_client::CoordinatorClient;
 /// The [`CoordinatorServer`] is used to implement the coordinator for the thorn protocol.
 pub use thorn_proto::coordinator_server::CoordinatorServer;
 
+/// The [`Coordinator`] trait is implemented by the aggregation service served by
+/// [`CoordinatorServer`] and accessed by [`CoordinatorClient`].
+///
+/// An implementation folds the [`Parameters`]/[`Scalar`] payload of each incoming
+/// [`ServerMessage`] into a running encrypted sum (e.g. via
+/// `sealy::SecureAggregator::accumulate`) and only returns a [`ClientMessage`] once enough
+/// clients have contributed, so the homomorphic accumulation never buffers every client's
+/// ciphertext and the coordinator never decrypts anything itself.
+pub use thorn_proto::coordinator_server::Coordinator;
+
 /// A server message to be sent to the coordinator.
 pub use thorn_proto::ServerMessage;
 
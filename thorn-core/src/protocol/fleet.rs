@@ -35,4 +35,32 @@ pub use thorn_proto::fleet_server::FleetServer;
 /// be served by the [`FleetServer`] and accessed by the [`FleetClient`].
 ///
 /// It provides a way to create, delete and ping nodes in the network.
+///
+/// # Threshold decryption
+///
+/// A distributed-trust deployment additionally wants a `PartialDecrypt` RPC alongside
+/// `CreateNode`/`DeleteNode`/`Ping`, carrying a `PartialDecryptRequest { ciphertext: Vec<u8> }`
+/// and returning a `PartialDecryptResponse { share: Vec<u8> }` per node, so no single node ever
+/// holds the full secret key. A `Fleet` implementation would run `sealy::PartialDecryptor::decrypt`
+/// against that node's secret-key share (produced by `sealy::ThresholdKeyGenerator`) and the
+/// combiner would fold the responses with `sealy::combine_partials`. Those primitives exist
+/// today (see `sealy::threshold`) but always return [`sealy::Error::UnsupportedOperation`], since
+/// producing a genuine share needs raw-coefficient access this crate's SEAL binding doesn't
+/// expose yet — so there is nothing sound for a `PartialDecrypt` RPC to carry. Adding the RPC
+/// itself is left for when that primitive lands, since this crate's `.proto` sources (and the
+/// code `tonic_build` generates from them) aren't present in this checkout to regenerate.
+///
+/// # Distributed tensor evaluation
+///
+/// A scheduler for spreading a large `sealy::Tensor<Ciphertext>` across the fleet would shard
+/// the tensor, ship each shard to a healthy [`Node`](super::node::Node) discovered via
+/// `PingRequest`, and ask that node to run one `sealy::TensorEvaluator` op (negate/add/multiply/
+/// multiply_plain/relinearize) over its shard using the `to_bytes_chunk`/`from_bytes_chunk`
+/// wire format already defined on `sealy::Tensor`, reassembling the per-shard results in
+/// order. That needs an `EvaluateShardRequest { op, shard, operands }` /
+/// `EvaluateShardResponse { shard }` RPC pair alongside `CreateNode`/`DeleteNode`/`Ping`, plus
+/// reschedule-on-failure bookkeeping in the caller when a shard's node stops answering `Ping`.
+/// None of that can be added here: this checkout has no `.proto` sources for this crate and no
+/// `tonic_build`-generated code to extend, so there is no `thorn_proto` module to add the new
+/// messages or RPC to.
 pub use thorn_proto::fleet_server::Fleet;
@@ -1,3 +1,5 @@
+use crate::error::*;
+
 /// Simple base suggestion for encoding float point numbers.
 pub mod consts {
 	#[allow(dead_code)]
@@ -6,44 +8,92 @@ pub mod consts {
 
 /// Float point numbers encoder for BFV encryption scheme.
 ///
-/// It basically uses a base to encode the float point number
-/// as an integer.
+/// Values are scaled by a fixed base and rounded to the nearest integer, then mapped onto the
+/// plaintext ring `Z_t` (`t` being the plain modulus this encoder was built with): a
+/// non-negative scaled value `m` is stored as `m mod t`, and a negative one as `t + m`, which
+/// SEAL's arithmetic respects transparently since it operates on residues mod `t`. Decoding folds
+/// residues greater than `t / 2` back to the negative branch (`value - t`), recovering the sign.
 #[derive(Debug, Clone)]
 pub struct BFVFloatEncoder {
 	base: u64,
+	plain_modulus: u64,
 }
 
 impl BFVFloatEncoder {
 	/// Creates a new instance of BFVFloatEncoder.
 	///
 	/// * `base` - The base to encode the float point number.
-	pub fn new(base: u64) -> Self {
+	/// * `plain_modulus` - The plain modulus `t` of the scheme this encoder will be used with.
+	/// Scaled values are reduced modulo this to stay within the plaintext ring.
+	pub fn new(
+		base: u64,
+		plain_modulus: u64,
+	) -> Self {
 		Self {
 			base,
+			plain_modulus,
 		}
 	}
 
-	/// Encodes a float point number as an integer.
-	///
-	/// * `value` - The float point number to encode.
+	/// Encodes a float point number as an integer residue mod the plain modulus, wrapping
+	/// silently if `value * base` falls outside `[-t/2, t/2]`. Prefer [`Self::try_encode`] when
+	/// silent precision loss is unacceptable.
 	pub fn encode(
 		&self,
 		value: f64,
 	) -> u64 {
-		(value * self.base as f64).round() as u64
+		self.encode_residue((value * self.base as f64).round() as i64)
 	}
 
-	/// Decodes an integer to a float point number.
-	///
-	/// * `value` - The integer to decode.
+	/// Encodes a float point number as an integer residue mod the plain modulus, failing rather
+	/// than wrapping if `|value * base|` exceeds `t / 2` and would lose precision. For an even
+	/// `t`, `-t/2` and `t/2` would both encode to the same residue and be indistinguishable on
+	/// decode, so the boundary is excluded entirely in that case.
+	pub fn try_encode(
+		&self,
+		value: f64,
+	) -> Result<u64> {
+		let scaled = (value * self.base as f64).round() as i64;
+		let half = (self.plain_modulus / 2) as i64;
+
+		let out_of_range = if self.plain_modulus % 2 == 0 {
+			scaled.unsigned_abs() >= half as u64
+		} else {
+			scaled.unsigned_abs() > half as u64
+		};
+
+		if out_of_range {
+			return Err(Error::InvalidParams);
+		}
+
+		Ok(self.encode_residue(scaled))
+	}
+
+	fn encode_residue(
+		&self,
+		scaled: i64,
+	) -> u64 {
+		scaled.rem_euclid(self.plain_modulus as i64) as u64
+	}
+
+	/// Decodes an integer residue mod the plain modulus back to a float point number, folding
+	/// residues greater than `t / 2` back to their negative value.
 	pub fn decode(
 		&self,
 		value: u64,
 	) -> f64 {
-		value as f64 / self.base as f64
+		let half = self.plain_modulus / 2;
+
+		let signed = if value > half {
+			value as i64 - self.plain_modulus as i64
+		} else {
+			value as i64
+		};
+
+		signed as f64 / self.base as f64
 	}
 
-	/// Encodes a slice of float point numbers as integers.
+	/// Encodes a slice of float point numbers as integer residues mod the plain modulus.
 	///
 	/// * `values` - The slice of float point numbers to encode.
 	pub fn encode_slice(
@@ -53,9 +103,18 @@ impl BFVFloatEncoder {
 		values.iter().map(|v| self.encode(*v)).collect()
 	}
 
-	/// Decodes a slice of integers to float point numbers.
+	/// Encodes a slice of float point numbers as integer residues mod the plain modulus,
+	/// failing if any value would lose precision. See [`Self::try_encode`].
+	pub fn try_encode_slice(
+		&self,
+		values: &[f64],
+	) -> Result<Vec<u64>> {
+		values.iter().map(|v| self.try_encode(*v)).collect()
+	}
+
+	/// Decodes a slice of integer residues mod the plain modulus to float point numbers.
 	///
-	/// * `values` - The slice of integers to decode.
+	/// * `values` - The slice of integer residues to decode.
 	pub fn decode_slice(
 		&self,
 		values: &[u64],
@@ -63,3 +122,40 @@ impl BFVFloatEncoder {
 		values.iter().map(|v| self.decode(*v)).collect()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trips_negative_and_positive_values() {
+		let encoder = BFVFloatEncoder::new(1_000, 65537);
+
+		for value in [-12.345, -0.5, 0.0, 0.001, 12.345] {
+			let encoded = encoder.encode(value);
+			let decoded = encoder.decode(encoded);
+			assert!((decoded - value).abs() < 1e-3);
+		}
+	}
+
+	#[test]
+	fn try_encode_rejects_values_that_would_lose_precision() {
+		let encoder = BFVFloatEncoder::new(1_000, 65537);
+
+		assert!(encoder.try_encode(100.0).is_err());
+		assert!(encoder.try_encode(-100.0).is_err());
+		assert!(encoder.try_encode(32.0).is_ok());
+	}
+
+	#[test]
+	fn try_encode_rejects_the_ambiguous_boundary_for_an_even_plain_modulus() {
+		// t = 10 is even, so half = 5: -5 and 5 would both encode to the residue 5 and be
+		// indistinguishable on decode, so both must be rejected rather than silently aliased.
+		let encoder = BFVFloatEncoder::new(1, 10);
+
+		assert!(encoder.try_encode(-5.0).is_err());
+		assert!(encoder.try_encode(5.0).is_err());
+		assert!(encoder.try_encode(-4.0).is_ok());
+		assert!(encoder.try_encode(4.0).is_ok());
+	}
+}
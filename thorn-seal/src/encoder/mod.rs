@@ -3,6 +3,9 @@ use crate::{Error, Plaintext};
 /// BFV encoder.
 pub mod bfv;
 
+/// BFV fixed-point float encoder.
+pub mod bfv_float;
+
 /// CKKS encoder.
 pub mod ckks;
 
@@ -58,6 +58,7 @@ mod modulus;
 mod parameters;
 mod plaintext;
 mod poly_array;
+mod protocol;
 
 pub use batch::Batch;
 pub use ciphertext::Ciphertext;
@@ -66,6 +67,7 @@ pub use context_data::ContextData;
 pub use decryptor::{batch::BatchDecryptor, Decryptor};
 pub use encoder::batch::BatchEncoder;
 pub use encoder::bfv::{BFVDecimalEncoder, BFVEncoder};
+pub use encoder::bfv_float::BFVFloatEncoder;
 pub use encoder::ckks::CKKSEncoder;
 pub use encoder::Encoder;
 pub use encryptor::{
@@ -83,6 +85,10 @@ pub use modulus::{CoefficientModulus, Modulus, PlainModulus, SecurityLevel};
 pub use parameters::*;
 pub use plaintext::Plaintext;
 pub use poly_array::PolynomialArray;
+pub use protocol::{
+	ciphertexts_from_record, ciphertexts_to_record, keys_to_record, to_task_ins, validate_against,
+	CIPHERTEXT_KEY_PREFIX, GALOIS_KEYS_KEY, PARAMETERS_KEY, PUBLIC_KEY_KEY, RELIN_KEYS_KEY,
+};
 
 /// A trait for converting objects into byte arrays.
 pub trait ToBytes {
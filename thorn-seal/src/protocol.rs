@@ -0,0 +1,156 @@
+use thorn_core::protocol::node::Node;
+use thorn_core::protocol::recordset::{Array, ParametersRecord, RecordSet};
+use thorn_core::protocol::task::{TaskIns, TaskRes};
+
+use crate::{Context, Ciphertext, Error, FromBytes, GaloisKeys, PublicKey, RelinearizationKeys, Result, ToBytes};
+
+/// The key under which a context's encryption parameters are stored in a [`ParametersRecord`]
+/// produced by [`keys_to_record`].
+pub const PARAMETERS_KEY: &str = "seal.parameters";
+
+/// The key under which a [`PublicKey`] is stored in a [`ParametersRecord`] produced by
+/// [`keys_to_record`].
+pub const PUBLIC_KEY_KEY: &str = "seal.public_key";
+
+/// The key under which [`RelinearizationKeys`] are stored in a [`ParametersRecord`] produced by
+/// [`keys_to_record`], when present.
+pub const RELIN_KEYS_KEY: &str = "seal.relin_keys";
+
+/// The key under which [`GaloisKeys`] are stored in a [`ParametersRecord`] produced by
+/// [`keys_to_record`], when present.
+pub const GALOIS_KEYS_KEY: &str = "seal.galois_keys";
+
+/// The prefix under which each ciphertext produced by [`ciphertexts_to_record`] is stored,
+/// suffixed with its index in the batch, e.g. `seal.ciphertext.0`.
+pub const CIPHERTEXT_KEY_PREFIX: &str = "seal.ciphertext.";
+
+fn array_of(data: Vec<u8>) -> Array {
+	Array {
+		dtype: "seal.bytes".to_owned(),
+		shape: vec![data.len() as i32],
+		stype: "seal".to_owned(),
+		data,
+	}
+}
+
+fn entry<'a>(record: &'a ParametersRecord, key: &str) -> Result<&'a Array> {
+	record
+		.data
+		.get(key)
+		.ok_or_else(|| Error::InvalidSerializedData(format!("missing `{key}` entry")))
+}
+
+/// Packs a [`Context`]'s encryption parameters, a [`PublicKey`], and the evaluation keys that
+/// were generated alongside it into a [`ParametersRecord`], ready to be attached to a
+/// [`RecordSet`] and shipped through a [`TaskIns`]/[`TaskRes`].
+///
+/// `relin_keys`/`galois_keys` are omitted from the record entirely when `None`, since a
+/// receiving node only needs them for the evaluator operations the sender actually intends to
+/// use downstream.
+pub fn keys_to_record(
+	context: &Context,
+	public_key: &PublicKey,
+	relin_keys: Option<&RelinearizationKeys>,
+	galois_keys: Option<&GaloisKeys>,
+) -> Result<ParametersRecord> {
+	let mut record = ParametersRecord::default();
+
+	record.data.insert(
+		PARAMETERS_KEY.to_owned(),
+		array_of(context.get_encryption_parameters()?.as_bytes()?),
+	);
+	record
+		.data
+		.insert(PUBLIC_KEY_KEY.to_owned(), array_of(public_key.as_bytes()?));
+
+	if let Some(relin_keys) = relin_keys {
+		record
+			.data
+			.insert(RELIN_KEYS_KEY.to_owned(), array_of(relin_keys.as_bytes()?));
+	}
+
+	if let Some(galois_keys) = galois_keys {
+		record
+			.data
+			.insert(GALOIS_KEYS_KEY.to_owned(), array_of(galois_keys.as_bytes()?));
+	}
+
+	Ok(record)
+}
+
+/// Packs a batch of encrypted tensor chunks into a [`ParametersRecord`], one [`Array`] entry
+/// per chunk, keyed by its position in `ciphertexts`.
+pub fn ciphertexts_to_record(ciphertexts: &[Ciphertext]) -> Result<ParametersRecord> {
+	let mut record = ParametersRecord::default();
+
+	for (index, ciphertext) in ciphertexts.iter().enumerate() {
+		record.data.insert(
+			format!("{CIPHERTEXT_KEY_PREFIX}{index}"),
+			array_of(ciphertext.as_bytes()?),
+		);
+	}
+
+	Ok(record)
+}
+
+/// Bundles a keys [`ParametersRecord`] and a ciphertexts [`ParametersRecord`] into a
+/// [`RecordSet`] under the conventional `"keys"`/`"ciphertexts"` names, then wraps it into a
+/// [`TaskIns`] addressed to `consumer` on behalf of `producer`.
+pub fn to_task_ins(
+	producer: Node,
+	consumer: Node,
+	keys: ParametersRecord,
+	ciphertexts: ParametersRecord,
+) -> TaskIns {
+	let mut recordset = RecordSet::default();
+	recordset.parameters.insert("keys".to_owned(), keys);
+	recordset.parameters.insert("ciphertexts".to_owned(), ciphertexts);
+
+	TaskIns {
+		task_id: String::new(),
+		group_id: String::new(),
+		run_id: String::new(),
+		task: Some(thorn_core::protocol::task::Task {
+			producer: Some(producer),
+			consumer: Some(consumer),
+			recordset: Some(recordset),
+		}),
+	}
+}
+
+/// Unpacks the ciphertext chunks previously packed by [`ciphertexts_to_record`], in the same
+/// order they were inserted.
+///
+/// Stops, without an error, at the first missing `seal.ciphertext.<n>` entry, which bounds the
+/// number of chunks read -- callers that need to know the original count up front should track
+/// it out of band (e.g. in a configs record) rather than relying on this stopping point.
+pub fn ciphertexts_from_record(context: &Context, record: &ParametersRecord) -> Result<Vec<Ciphertext>> {
+	let mut ciphertexts = Vec::new();
+	let mut index = 0;
+
+	while let Ok(array) = entry(record, &format!("{CIPHERTEXT_KEY_PREFIX}{index}")) {
+		ciphertexts.push(Ciphertext::from_bytes(context, &array.data)?);
+		index += 1;
+	}
+
+	Ok(ciphertexts)
+}
+
+/// Rebuilds the [`Context`] a [`ParametersRecord`] produced by [`keys_to_record`] was packed
+/// against, and checks that it produces the same `parms_id` chain as `local_context`, returning
+/// `Ok(())` if so and an error otherwise -- a received set of parameters that validates under the local
+/// context's security level but derives a different chain would silently desynchronize
+/// ciphertexts exchanged afterwards.
+pub fn validate_against(local_context: &Context, record: &ParametersRecord) -> Result<()> {
+	let received = entry(record, PARAMETERS_KEY)?;
+	let received_context = Context::from_bytes(local_context, &received.data)?;
+
+	if received_context.get_key_parms_id()? != local_context.get_key_parms_id()? {
+		return Err(Error::InvalidSerializedData(
+			"received parameters produce a different parms_id chain than the local context"
+				.to_owned(),
+		));
+	}
+
+	Ok(())
+}